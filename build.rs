@@ -0,0 +1,802 @@
+//! Generates parameter groups from the vendored canboat database when the
+//! `codegen` feature is enabled.
+//!
+//! Each `pgns-<group>` feature compiles in the parameter groups of a category
+//! in `GROUPS`; proprietary parameter groups and the rest of the database
+//! belong to `pgns-other`. Parameter groups with repeating field sets or field
+//! types the typed codecs do not support only get a catalogue entry. Lookups
+//! named like a lookup in `src/pgns` use the hand-written type.
+
+fn main() {
+    #[cfg(feature = "codegen")]
+    codegen::generate();
+}
+
+#[cfg(feature = "codegen")]
+mod codegen {
+    use std::collections::BTreeMap;
+    use std::env;
+    use std::fmt::Write;
+    use std::fs;
+    use std::path::Path;
+
+    use serde_json::Value;
+
+    const DATABASE: &str = "canboat/pgns.json";
+    const SOURCES: &str = "src/pgns";
+
+    /// Categories of the standard parameter groups, by the words of their
+    /// descriptions. The first category with a word in the description wins.
+    const GROUPS: &[(&str, &[&str])] = &[
+        (
+            "navigation",
+            &[
+                "Heading",
+                "Rate of Turn",
+                "Magnetic Variation",
+                "Position",
+                "COG",
+                "GNSS",
+                "GPS",
+                "GLONASS",
+                "Time & Date",
+                "Datum",
+                "Cross Track Error",
+                "Navigation",
+                "Route",
+                "Bearing",
+                "Set & Drift",
+                "Loran",
+                "AIS",
+            ],
+        ),
+        (
+            "motion",
+            &[
+                "Attitude",
+                "Heave",
+                "Speed",
+                "Distance Log",
+                "Leeway",
+                "Acceleration",
+                "Altitude Delta",
+                "Direction Data",
+            ],
+        ),
+        (
+            "environmental",
+            &[
+                "Water Depth",
+                "Wind",
+                "Environmental",
+                "Temperature",
+                "Humidity",
+                "Pressure",
+                "Tide",
+                "Salinity",
+                "Current Station",
+                "Meteorological",
+                "Moored Buoy",
+            ],
+        ),
+    ];
+
+    /// Parameter group numbers above this are gateway messages, not sent on
+    /// the bus.
+    const MAX_PGN: u32 = 131071;
+
+    /// Names the generated module imports.
+    const IMPORTS: &[&str] = &[
+        "Field",
+        "LauString",
+        "Pgn",
+        "PgnDescriptor",
+        "PgnError",
+        "Priority",
+        "Reader",
+        "Result",
+        "Transport",
+        "Writer",
+    ];
+
+    const KEYWORDS: &[&str] = &[
+        "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn",
+        "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref",
+        "return", "self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use",
+        "where", "while", "async", "await", "dyn", "abstract", "become", "box", "do", "final",
+        "macro", "override", "priv", "typeof", "unsized", "virtual", "yield", "try",
+    ];
+
+    pub fn generate() {
+        println!("cargo:rerun-if-changed={}", DATABASE);
+        println!("cargo:rerun-if-changed={}", SOURCES);
+        let json = fs::read_to_string(DATABASE).expect("failed to read the canboat database");
+        let database: Value = serde_json::from_str(&json).expect("invalid canboat database");
+
+        let mut pgns: Vec<&Value> = array(&database["PGNs"])
+            .iter()
+            .filter(|pgn| !pgn["Fallback"].as_bool().unwrap_or(false))
+            .filter(|pgn| number(&pgn["PGN"]) as u32 <= MAX_PGN && enabled(pgn))
+            .collect();
+        pgns.sort_by_key(|pgn| number(&pgn["PGN"]) as u32);
+
+        // variants of a parameter group may share a name
+        let mut names: Vec<String> = IMPORTS.iter().map(|name| name.to_string()).collect();
+        for pgn in &pgns {
+            let mut name = type_name(&text(&pgn["Id"]));
+            if names.contains(&name) {
+                name = format!("{}{}", name, number(&pgn["PGN"]) as u32);
+            }
+            names.push(name);
+        }
+        let names = &names[IMPORTS.len()..];
+
+        // the widest field decides the representation of a lookup
+        let mut widths = BTreeMap::new();
+        for field in pgns.iter().flat_map(|pgn| array(&pgn["Fields"])) {
+            if let Some(name) = field["LookupEnumeration"].as_str() {
+                let width = widths.entry(name).or_insert(0);
+                *width = bits(field).max(*width);
+            }
+        }
+
+        let handwritten = handwritten_lookups();
+        let mut lookups = Lookups::new();
+        for lookup in array(&database["LookupEnumerations"]) {
+            let canboat = text(&lookup["Name"]);
+            let width = widths.get(canboat.as_str()).copied().unwrap_or(0);
+            let mut name = type_name(&canboat.to_lowercase());
+            let entry = match handwritten.get(&name) {
+                Some(repr) if repr_bits(repr) >= width => Lookup {
+                    path: format!("crate::pgns::{}", name),
+                    repr: repr.clone(),
+                    definition: None,
+                },
+                _ => {
+                    if names.contains(&name) || IMPORTS.contains(&name.as_str()) {
+                        name.push_str("Lookup");
+                    }
+                    Lookup {
+                        path: name,
+                        repr: lookup_repr(lookup, width),
+                        definition: Some(lookup),
+                    }
+                }
+            };
+            lookups.insert(canboat, entry);
+        }
+        let flags: Flags = array(&database["LookupBitEnumerations"])
+            .iter()
+            .filter(|lookup| {
+                array(&lookup["EnumBitValues"])
+                    .iter()
+                    .all(|value| value["Bit"].as_u64().map_or(false, |bit| bit < 32))
+            })
+            .map(|lookup| (text(&lookup["Name"]), lookup))
+            .collect();
+
+        let mut used = BTreeMap::new();
+        let mut used_flags = BTreeMap::new();
+        let mut types = String::new();
+        let mut catalogue = String::new();
+        for (pgn, name) in pgns.iter().zip(names) {
+            for field in array(&pgn["Fields"]) {
+                if let Some(lookup) = field["LookupEnumeration"].as_str() {
+                    if let Some(entry) = lookups.get(lookup) {
+                        used.insert(lookup.to_string(), entry);
+                    }
+                }
+                if let Some(lookup) = field["LookupBitEnumeration"].as_str() {
+                    if let Some(entry) = flags.get(lookup) {
+                        used_flags.insert(lookup.to_string(), *entry);
+                    }
+                }
+            }
+            if typed(pgn, &lookups) {
+                write_type(&mut types, pgn, name, &lookups);
+            }
+            write_descriptor(&mut catalogue, pgn, &lookups, &flags);
+        }
+
+        let mut out = String::new();
+        for entry in used.values() {
+            if let Some(definition) = entry.definition {
+                write_lookup(&mut out, entry, definition);
+            }
+        }
+        for (name, lookup) in &used_flags {
+            write_flags(&mut out, name, lookup);
+        }
+        out.push_str(&types);
+        out.push_str("/// Descriptors of the generated parameter groups, sorted by PGN.\n");
+        out.push_str("pub(crate) static GENERATED_CATALOGUE: &[PgnDescriptor] = &[\n");
+        out.push_str(&catalogue);
+        out.push_str("];\n");
+
+        let path = Path::new(&env::var("OUT_DIR").unwrap()).join("canboat.rs");
+        fs::write(path, out).expect("failed to write the generated parameter groups");
+    }
+
+    /// A lookup of the database, either generated or hand-written.
+    struct Lookup<'a> {
+        /// Path of the Rust type from the generated module.
+        path: String,
+        repr: String,
+        /// The canboat definition, if the type is generated.
+        definition: Option<&'a Value>,
+    }
+
+    /// Lookups by canboat name.
+    type Lookups<'a> = BTreeMap<String, Lookup<'a>>;
+
+    /// Bit lookups by canboat name.
+    type Flags<'a> = BTreeMap<String, &'a Value>;
+
+    /// Representations of the hand-written lookups exported by `crate::pgns`,
+    /// by name.
+    fn handwritten_lookups() -> BTreeMap<String, String> {
+        let exports = fs::read_to_string(format!("{}/mod.rs", SOURCES)).unwrap_or_default();
+        let mut lookups = BTreeMap::new();
+        for entry in fs::read_dir(SOURCES).expect("failed to read the sources") {
+            let source = fs::read_to_string(entry.unwrap().path()).unwrap_or_default();
+            for block in source.split("lookup! {").skip(1) {
+                let declaration = match block.find("pub enum ") {
+                    Some(start) => &block[start + "pub enum ".len()..],
+                    None => continue,
+                };
+                let mut parts = declaration.split('{').next().unwrap().split(':');
+                let name = parts.next().unwrap_or_default().trim();
+                let repr = parts.next().unwrap_or_default().trim();
+                if contains_word(&exports, name) && !repr.is_empty() {
+                    lookups.insert(name.to_string(), repr.to_string());
+                }
+            }
+        }
+        lookups
+    }
+
+    fn is_proprietary(pgn: u32) -> bool {
+        pgn == 61184
+            || (65280..=65535).contains(&pgn)
+            || pgn == 126720
+            || (130816..=131071).contains(&pgn)
+    }
+
+    /// Whether `text` holds `word` between characters that are not
+    /// alphanumeric.
+    fn contains_word(text: &str, word: &str) -> bool {
+        text.match_indices(word).any(|(start, _)| {
+            let before = text[..start].chars().next_back();
+            let after = text[start + word.len()..].chars().next();
+            !before.map_or(false, |c| c.is_ascii_alphanumeric())
+                && !after.map_or(false, |c| c.is_ascii_alphanumeric())
+        })
+    }
+
+    fn enabled(pgn: &Value) -> bool {
+        let proprietary = is_proprietary(number(&pgn["PGN"]) as u32);
+        let description = text(&pgn["Description"]);
+        let group = GROUPS
+            .iter()
+            .find(|(_, words)| {
+                !proprietary && words.iter().any(|word| contains_word(&description, word))
+            })
+            .map_or("other", |(group, _)| group);
+        env::var_os(format!("CARGO_FEATURE_PGNS_{}", group.to_uppercase())).is_some()
+    }
+
+    fn array(value: &Value) -> &[Value] {
+        value.as_array().map_or(&[], |values| values.as_slice())
+    }
+
+    fn text(value: &Value) -> String {
+        value.as_str().unwrap_or_default().to_string()
+    }
+
+    fn number(value: &Value) -> f64 {
+        value.as_f64().unwrap_or_default()
+    }
+
+    fn bits(field: &Value) -> u64 {
+        field["BitLength"].as_u64().unwrap_or(0)
+    }
+
+    fn resolution(field: &Value) -> f64 {
+        field["Resolution"].as_f64().unwrap_or(1.0)
+    }
+
+    fn offset(field: &Value) -> f64 {
+        field["Offset"].as_f64().unwrap_or(0.0)
+    }
+
+    fn signed(field: &Value) -> bool {
+        field["Signed"].as_bool().unwrap_or(false)
+    }
+
+    fn kind(field: &Value) -> String {
+        text(&field["FieldType"])
+    }
+
+    fn is_number(field: &Value) -> bool {
+        matches!(
+            kind(field).as_str(),
+            "NUMBER" | "DATE" | "TIME" | "DURATION" | "MMSI" | "PGN"
+        )
+    }
+
+    /// Whether the field holds a fixed value that identifies the parameter
+    /// group, such as the manufacturer of a proprietary one.
+    fn is_match(field: &Value) -> bool {
+        field["Match"].is_u64()
+    }
+
+    fn is_skipped(field: &Value) -> bool {
+        matches!(kind(field).as_str(), "RESERVED" | "SPARE")
+    }
+
+    /// Whether the typed codecs support every field of the parameter group.
+    fn typed(pgn: &Value, lookups: &Lookups) -> bool {
+        if pgn["RepeatingFieldSet1Size"].as_u64().unwrap_or(0) > 0 {
+            return false;
+        }
+        let fields = array(&pgn["Fields"]);
+        fields.iter().enumerate().all(|(index, field)| {
+            let bits = bits(field);
+            if is_match(field) {
+                return bits > 0 && bits <= 64;
+            }
+            match kind(field).as_str() {
+                "RESERVED" | "SPARE" => bits > 0,
+                "LOOKUP" => {
+                    bits > 0
+                        && bits <= 32
+                        && field["LookupEnumeration"]
+                            .as_str()
+                            .map_or(false, |name| lookups.contains_key(name))
+                }
+                "STRING_FIX" => bits > 0 && bits % 8 == 0,
+                "STRING_LAU" | "STRING_LZ" => true,
+                // only the last field may run to the end of the payload
+                "BINARY" => bits % 8 == 0 && (bits > 0 || index == fields.len() - 1),
+                _ => is_number(field) && bits > 0 && bits <= 64 && offset(field) == 0.0,
+            }
+        })
+    }
+
+    fn camel_case(name: &str) -> String {
+        let mut out = String::new();
+        let mut upper = true;
+        for c in name.chars() {
+            if c.is_ascii_alphanumeric() {
+                if upper {
+                    out.push(c.to_ascii_uppercase());
+                } else {
+                    out.push(c);
+                }
+                upper = false;
+            } else {
+                upper = true;
+            }
+        }
+        out
+    }
+
+    fn type_name(name: &str) -> String {
+        let name = camel_case(name);
+        if name.starts_with(|c: char| c.is_ascii_digit()) {
+            format!("Pgn{}", name)
+        } else {
+            name
+        }
+    }
+
+    fn variant_name(name: &str) -> String {
+        // words in capitals such as WMM are written as Wmm
+        let words: Vec<String> = name
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(|word| {
+                let mut chars = word.chars();
+                let first = chars.next().unwrap().to_ascii_uppercase();
+                let rest: String = if word.chars().all(|c| !c.is_ascii_lowercase()) {
+                    chars.as_str().to_ascii_lowercase()
+                } else {
+                    chars.as_str().to_string()
+                };
+                format!("{}{}", first, rest)
+            })
+            .collect();
+        let name = words.concat();
+        if name.starts_with(|c: char| c.is_ascii_digit()) || name.is_empty() {
+            format!("Value{}", name)
+        } else {
+            name
+        }
+    }
+
+    fn field_name(id: &str) -> String {
+        let mut out = String::new();
+        for (i, c) in id.chars().enumerate() {
+            if c.is_ascii_uppercase() {
+                if i > 0 {
+                    out.push('_');
+                }
+                out.push(c.to_ascii_lowercase());
+            } else if c.is_ascii_alphanumeric() {
+                out.push(c);
+            } else {
+                out.push('_');
+            }
+        }
+        if out.starts_with(|c: char| c.is_ascii_digit()) {
+            out.insert_str(0, "value_");
+        }
+        match out.as_str() {
+            // not allowed as raw identifiers
+            "self" | "super" | "crate" => format!("{}_", out),
+            name if KEYWORDS.contains(&name) => format!("r#{}", out),
+            _ => out,
+        }
+    }
+
+    /// Representation of a lookup holding its largest value in fields of up
+    /// to `width` bits.
+    fn lookup_repr(lookup: &Value, width: u64) -> String {
+        let max = lookup["MaxValue"].as_u64().unwrap_or(0);
+        let repr = match max.max((1 << width.min(32)) - 1) {
+            0..=0xff => "u8",
+            0x100..=0xffff => "u16",
+            _ => "u32",
+        };
+        repr.to_string()
+    }
+
+    fn repr_bits(repr: &str) -> u64 {
+        repr.trim_start_matches(|c| c == 'u' || c == 'i')
+            .parse()
+            .unwrap_or(0)
+    }
+
+    fn unit_doc(unit: &str) -> String {
+        match unit {
+            "rad" => "Radians".to_string(),
+            "rad/s" => "Radians per second".to_string(),
+            "deg" => "Degrees".to_string(),
+            "m" => "Metres".to_string(),
+            "m/s" => "Metres per second".to_string(),
+            "s" => "Seconds".to_string(),
+            "d" => "Days since 1970-01-01".to_string(),
+            "K" => "Kelvin".to_string(),
+            "Pa" => "Pascal".to_string(),
+            "V" => "Volts".to_string(),
+            "A" => "Amperes".to_string(),
+            "W" => "Watts".to_string(),
+            "Hz" => "Hertz".to_string(),
+            "%" => "Percent".to_string(),
+            _ => format!("Unit: {}", unit),
+        }
+    }
+
+    fn write_lookup(out: &mut String, entry: &Lookup, lookup: &Value) {
+        writeln!(out, "lookup! {{").unwrap();
+        writeln!(out, "    pub enum {}: {} {{", entry.path, entry.repr).unwrap();
+        let mut seen = Vec::new();
+        let mut numbers = Vec::new();
+        for value in array(&lookup["EnumValues"]) {
+            let number = value["Value"].as_u64().unwrap_or(0);
+            // the first name of a value wins
+            if numbers.contains(&number) {
+                continue;
+            }
+            let display = text(&value["Name"]);
+            let mut variant = variant_name(&display);
+            if seen.contains(&variant) || variant == "Unknown" || variant == "Self" {
+                variant = format!("{}{}", variant, number);
+            }
+            writeln!(out, "        {} = {} => {:?},", variant, number, display).unwrap();
+            seen.push(variant);
+            numbers.push(number);
+        }
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}\n").unwrap();
+    }
+
+    fn write_flags(out: &mut String, name: &str, lookup: &Value) {
+        writeln!(out, "const {}: &[(u32, &str)] = &[", name).unwrap();
+        for value in array(&lookup["EnumBitValues"]) {
+            let bit = value["Bit"].as_u64().unwrap_or(0);
+            writeln!(out, "    (1 << {}, {:?}),", bit, text(&value["Name"])).unwrap();
+        }
+        writeln!(out, "];\n").unwrap();
+    }
+
+    /// Rust type, decoder and encoder expressions of a typed field.
+    fn codec(field: &Value, name: &str, lookups: &Lookups) -> (String, String, String) {
+        let bits = bits(field);
+        let resolution = resolution(field);
+        let unsigned_type = match bits {
+            0..=8 => "u8",
+            9..=16 => "u16",
+            17..=32 => "u32",
+            _ => "u64",
+        };
+        match kind(field).as_str() {
+            "LOOKUP" => {
+                let lookup = &lookups[&text(&field["LookupEnumeration"])];
+                if lookup.repr == "u8" {
+                    (
+                        lookup.path.clone(),
+                        format!("r.lookup({})?", bits),
+                        format!("w.lookup({}, self.{})?", bits, name),
+                    )
+                } else {
+                    (
+                        lookup.path.clone(),
+                        format!(
+                            "{}::from(r.bits({})? as {})",
+                            lookup.path, bits, lookup.repr
+                        ),
+                        format!(
+                            "w.bits({}, {}::from(self.{}) as u64)?",
+                            bits, lookup.repr, name
+                        ),
+                    )
+                }
+            }
+            "STRING_FIX" => (
+                "&'a str".to_string(),
+                format!("r.string_fix({})?", bits / 8),
+                format!("w.string_fix(self.{}, {}, b' ')?", name, bits / 8),
+            ),
+            "STRING_LAU" => (
+                "LauString<'a>".to_string(),
+                "r.string_lau()?".to_string(),
+                format!("w.string_lau(self.{})?", name),
+            ),
+            "STRING_LZ" => (
+                "&'a str".to_string(),
+                "r.string_lz()?".to_string(),
+                format!("w.string_lz(self.{})?", name),
+            ),
+            "BINARY" if bits == 0 => (
+                "&'a [u8]".to_string(),
+                "r.rest()".to_string(),
+                format!("w.bytes(self.{})?", name),
+            ),
+            "BINARY" => (
+                "&'a [u8]".to_string(),
+                format!("r.bytes({})?", bits / 8),
+                format!("w.bytes(self.{})?", name),
+            ),
+            _ if resolution != 1.0 => {
+                let (ty, method) = match (bits >= 32, signed(field)) {
+                    (false, false) => ("f32", "ufix"),
+                    (false, true) => ("f32", "sfix"),
+                    (true, false) => ("f64", "ufix64"),
+                    (true, true) => ("f64", "sfix64"),
+                };
+                (
+                    format!("Option<{}>", ty),
+                    format!("r.{}({}, {:?})?", method, bits, resolution),
+                    format!("w.{}({}, {:?}, self.{})?", method, bits, resolution, name),
+                )
+            }
+            _ if signed(field) => {
+                let ty = unsigned_type.replace('u', "i");
+                if ty == "i64" {
+                    (
+                        "Option<i64>".to_string(),
+                        format!("r.int({})?", bits),
+                        format!("w.int({}, self.{})?", bits, name),
+                    )
+                } else {
+                    (
+                        format!("Option<{}>", ty),
+                        format!("r.int({})?.map(|v| v as {})", bits, ty),
+                        format!("w.int({}, self.{}.map(|v| v as i64))?", bits, name),
+                    )
+                }
+            }
+            _ if unsigned_type == "u64" => (
+                "Option<u64>".to_string(),
+                format!("r.uint({})?", bits),
+                format!("w.uint({}, self.{})?", bits, name),
+            ),
+            _ => (
+                format!("Option<{}>", unsigned_type),
+                format!("r.uint({})?.map(|v| v as {})", bits, unsigned_type),
+                format!("w.uint({}, self.{}.map(|v| v as u64))?", bits, name),
+            ),
+        }
+    }
+
+    fn write_type(out: &mut String, pgn: &Value, name: &str, lookups: &Lookups) {
+        let number = number(&pgn["PGN"]) as u32;
+        let mut members = Vec::new();
+        for field in array(&pgn["Fields"]) {
+            if is_skipped(field) || is_match(field) {
+                members.push((field, None));
+                continue;
+            }
+            let mut member = field_name(&text(&field["Id"]));
+            if members.iter().any(|(_, m)| m.as_ref() == Some(&member)) {
+                member = format!("{}_{}", member, number_of(field));
+            }
+            members.push((field, Some(member)));
+        }
+        let codecs: Vec<_> = members
+            .iter()
+            .map(|(field, member)| {
+                member
+                    .as_ref()
+                    .map(|m| (m.clone(), codec(field, m, lookups)))
+            })
+            .collect();
+        let borrowed = codecs
+            .iter()
+            .flatten()
+            .any(|(_, (ty, _, _))| ty.contains("'a"));
+        let generics = if borrowed { "<'a>" } else { "" };
+
+        writeln!(out, "/// PGN {} - {}", number, text(&pgn["Description"])).unwrap();
+        writeln!(out, "#[derive(Clone, Debug, PartialEq)]").unwrap();
+        writeln!(out, "pub struct {}{} {{", name, generics).unwrap();
+        for ((field, _), codec) in members.iter().zip(&codecs) {
+            if let Some((member, (ty, _, _))) = codec {
+                if let Some(unit) = field["Unit"].as_str() {
+                    writeln!(out, "    /// {}", unit_doc(unit)).unwrap();
+                }
+                writeln!(out, "    pub {}: {},", member, ty).unwrap();
+            }
+        }
+        writeln!(out, "}}\n").unwrap();
+
+        writeln!(out, "impl<'a> Pgn<'a> for {}{} {{", name, generics).unwrap();
+        writeln!(out, "    const PGN: u32 = {};", number).unwrap();
+        writeln!(out, "    const PRIORITY: Priority = {};", priority(pgn)).unwrap();
+        if text(&pgn["Type"]) == "Fast" {
+            writeln!(out, "    const FAST_PACKET: bool = true;").unwrap();
+        }
+        writeln!(out).unwrap();
+        writeln!(out, "    fn decode(data: &'a [u8]) -> Result<Self> {{").unwrap();
+        writeln!(out, "        let mut r = Reader::new(data);").unwrap();
+        for ((field, _), codec) in members.iter().zip(&codecs) {
+            match codec {
+                Some((member, (_, decode, _))) => {
+                    writeln!(out, "        let {} = {};", member, decode).unwrap()
+                }
+                None if is_match(field) => writeln!(
+                    out,
+                    "        if r.bits({})? != {} {{ return Err(PgnError::UnexpectedMessage); }}",
+                    bits(field),
+                    field["Match"]
+                )
+                .unwrap(),
+                None => writeln!(out, "        r.reserved({})?;", bits(field)).unwrap(),
+            }
+        }
+        let names: Vec<&str> = codecs.iter().flatten().map(|(m, _)| m.as_str()).collect();
+        writeln!(out, "        Ok({} {{ {} }})", name, names.join(", ")).unwrap();
+        writeln!(out, "    }}\n").unwrap();
+        writeln!(
+            out,
+            "    fn encode(&self, buf: &mut [u8]) -> Result<usize> {{"
+        )
+        .unwrap();
+        writeln!(out, "        let mut w = Writer::new(buf);").unwrap();
+        for ((field, _), codec) in members.iter().zip(&codecs) {
+            match codec {
+                Some((_, (_, _, encode))) => writeln!(out, "        {};", encode).unwrap(),
+                None if is_match(field) => {
+                    writeln!(out, "        w.bits({}, {})?;", bits(field), field["Match"]).unwrap()
+                }
+                None => writeln!(out, "        w.reserved({})?;", bits(field)).unwrap(),
+            }
+        }
+        writeln!(out, "        Ok(w.len())").unwrap();
+        writeln!(out, "    }}").unwrap();
+        writeln!(out, "}}\n").unwrap();
+    }
+
+    fn number_of(field: &Value) -> u64 {
+        field["Order"].as_u64().unwrap_or(0)
+    }
+
+    fn priority(pgn: &Value) -> String {
+        let priority = pgn["Priority"].as_u64().unwrap_or(6).min(7);
+        format!("Priority::Priority{}", priority)
+    }
+
+    fn descriptor_field(field: &Value, lookups: &Lookups, flags: &Flags) -> String {
+        let name = format!("{:?}", text(&field["Name"]));
+        let unit = format!("{:?}", field["Unit"].as_str().unwrap_or_default());
+        let bits = bits(field);
+        match kind(field).as_str() {
+            "RESERVED" | "SPARE" => format!("Field::reserved({})", bits),
+            "LOOKUP" => match field["LookupEnumeration"].as_str() {
+                Some(lookup) if lookups.contains_key(lookup) => format!(
+                    "Field::lookup({}, {}, {}::VALUES)",
+                    name, bits, lookups[lookup].path
+                ),
+                _ => format!("Field::uint({}, {})", name, bits),
+            },
+            "BITLOOKUP" => match field["LookupBitEnumeration"].as_str() {
+                Some(lookup) if flags.contains_key(lookup) && bits <= 32 => {
+                    format!("Field::flags({}, {}, {})", name, bits, lookup)
+                }
+                _ => format!("Field::binary({}, {})", name, bits),
+            },
+            "FLOAT" => format!("Field::float({}, {})", name, unit),
+            "DECIMAL" => format!("Field::decimal({}, {})", name, bits),
+            "STRING_FIX" => format!("Field::string_fix({}, {})", name, bits / 8),
+            "STRING_LAU" => format!("Field::string_lau({})", name),
+            "STRING_LZ" => format!("Field::string_lz({})", name),
+            _ if is_number(field) && bits > 0 => {
+                let mut out = if signed(field) {
+                    format!(
+                        "Field::sfix({}, {}, {:?}, {})",
+                        name,
+                        bits,
+                        resolution(field),
+                        unit
+                    )
+                } else {
+                    format!(
+                        "Field::ufix({}, {}, {:?}, {})",
+                        name,
+                        bits,
+                        resolution(field),
+                        unit
+                    )
+                };
+                if offset(field) != 0.0 && !signed(field) {
+                    write!(out, ".offset({:?})", offset(field) * resolution(field)).unwrap();
+                }
+                out
+            }
+            _ => format!("Field::binary({}, {})", name, bits),
+        }
+    }
+
+    fn write_descriptor(out: &mut String, pgn: &Value, lookups: &Lookups, flags: &Flags) {
+        let transport = match text(&pgn["Type"]).as_str() {
+            "Fast" => "Fast",
+            "ISO" => "IsoTp",
+            _ => "Single",
+        };
+        writeln!(
+            out,
+            "    PgnDescriptor::new({}, {:?}, Transport::{}, {}, &[",
+            number(&pgn["PGN"]) as u32,
+            text(&pgn["Description"]),
+            transport,
+            priority(pgn)
+        )
+        .unwrap();
+        for field in array(&pgn["Fields"]) {
+            writeln!(out, "        {},", descriptor_field(field, lookups, flags)).unwrap();
+        }
+        write!(out, "    ])").unwrap();
+        if let Some(interval) = pgn["TransmissionInterval"].as_u64() {
+            if interval > 0 && interval <= u16::MAX as u64 {
+                write!(out, "\n    .with_interval({})", interval).unwrap();
+            }
+        }
+        let manufacturer = array(&pgn["Fields"])
+            .iter()
+            .find(|field| text(&field["Id"]) == "manufacturerCode")
+            .and_then(|field| field["Match"].as_u64());
+        if let Some(code) = manufacturer {
+            write!(out, "\n    .with_manufacturer({})", code).unwrap();
+        }
+        if pgn["RepeatingFieldSet1Size"].as_u64().unwrap_or(0) > 0 {
+            let start = pgn["RepeatingFieldSet1StartField"].as_u64().unwrap_or(1) - 1;
+            let count = match pgn["RepeatingFieldSet1CountField"].as_u64() {
+                Some(count) => format!("Some({})", count - 1),
+                None => "None".to_string(),
+            };
+            write!(out, "\n    .with_repeating({}, {})", count, start).unwrap();
+        }
+        writeln!(out, ",").unwrap();
+    }
+}
@@ -1,8 +1,9 @@
 use core::{convert::TryFrom, fmt::Debug};
 
 use crate::hal_can::{self, Receiver, Transmitter};
+use crate::pgns::{Pgn, PgnError, Transport};
 use crate::CanFrame;
-use crate::{Id, IdError, Message, GLOBAL_ADDRESS};
+use crate::{Id, IdError, Message, FAST_PACKET_MAX_LENGTH, GLOBAL_ADDRESS};
 
 const CB_TP_BAM: u8 = 0x40; // Control byte indicating TP_BAM
 
@@ -14,6 +15,8 @@ pub enum BusError {
     CouldNotOpenBus,
     CouldNotSendMessage,
     InvalidId(IdError),
+    InvalidPgn(PgnError),
+    MessageTooLong,
 }
 
 impl From<IdError> for BusError {
@@ -22,16 +25,27 @@ impl From<IdError> for BusError {
     }
 }
 
+impl From<PgnError> for BusError {
+    fn from(error: PgnError) -> Self {
+        BusError::InvalidPgn(error)
+    }
+}
+
 pub type Result<T> = core::result::Result<T, BusError>;
 
 pub struct Bus<T> {
     can: T,
     address: u8,
+    sequence: u8,
 }
 
 impl<T> Bus<T> {
     pub fn new(can: T) -> Self {
-        Bus { can, address: 0 }
+        Bus {
+            can,
+            address: 0,
+            sequence: 0,
+        }
     }
 }
 
@@ -44,7 +58,7 @@ where
 {
     pub fn receive(&mut self) -> nb::Result<(), E> {
         let frame = self.can.receive()?;
-        let id = Id::try_from(frame.id().extended_id().unwrap()).unwrap();
+        let _id = Id::try_from(frame.id().extended_id().unwrap()).unwrap();
 
         todo!()
     }
@@ -60,11 +74,15 @@ where
         let data = message.data();
         let length = data.len();
 
-        if length <= 8 {
-            //TODO: Make sure it's not a fast packet
+        let transport = Transport::select(id.pgn(), length);
+        if transport == Transport::Fast {
+            self.send_fast_packet(message)
+        } else if length <= 8 {
             let frame = CanFrame::new(id, data);
             self.transmit(&frame)?;
             Ok(())
+        } else if transport == Transport::Single {
+            Err(BusError::MessageTooLong)
         } else {
             //calculate number of packets that will be sent
             let packets = (length / 7) + 1;
@@ -117,6 +135,52 @@ where
         }
     }
 
+    pub fn send_fast_packet(&mut self, message: &Message) -> Result<()> {
+        let id = message.id();
+        let data = message.data();
+        let length = data.len();
+        if length > FAST_PACKET_MAX_LENGTH {
+            return Err(BusError::MessageTooLong);
+        }
+
+        // 3 bit sequence counter in the upper bits, frame counter in the lower 5
+        let sequence = self.sequence << 5;
+        self.sequence = (self.sequence + 1) & 0x07;
+
+        // first frame carries the total length and up to 6 bytes
+        let first = length.min(6);
+        let mut frame_data = [0xff; 8];
+        frame_data[0] = sequence;
+        frame_data[1] = length as u8;
+        frame_data[2..2 + first].copy_from_slice(&data[..first]);
+        self.transmit(&CanFrame::new(id, &frame_data))?;
+
+        // following frames carry up to 7 bytes each
+        for (count, chunk) in data[first..].chunks(7).enumerate() {
+            let mut frame_data = [0xff; 8];
+            frame_data[0] = sequence | (count as u8 + 1);
+            frame_data[1..1 + chunk.len()].copy_from_slice(chunk);
+            self.transmit(&CanFrame::new(id, &frame_data))?;
+        }
+
+        Ok(())
+    }
+
+    /// Encodes and sends a typed parameter group with its default priority,
+    /// using the fast packet protocol when the parameter group requires it.
+    pub fn send_pgn<'a, P: Pgn<'a>>(&mut self, pgn: &P, destination: u8) -> Result<()> {
+        let mut buf = [0xff; FAST_PACKET_MAX_LENGTH];
+        let length = pgn.encode(&mut buf)?;
+        let id = Id::new(P::PRIORITY, P::PGN, self.address, destination)?;
+        let message = Message::new(id, &buf[..length]).map_err(|_| BusError::MessageTooLong)?;
+
+        if P::FAST_PACKET {
+            self.send_fast_packet(&message)
+        } else {
+            self.send(&message)
+        }
+    }
+
     fn transmit(&mut self, frame: &CanFrame) -> Result<()> {
         // TODO: revise this as it's not looking optimal or correct
         let result = self.can.transmit(frame);
@@ -140,11 +204,14 @@ where
 #[cfg(test)]
 mod tests {
     extern crate alloc;
-    use alloc::boxed::Box;
     use alloc::vec::Vec;
 
     use crate::hal_can::{Filter, Frame, Interface, Receiver, Transmitter};
-    use crate::{Bus, Id, Message, Priority, GLOBAL_ADDRESS};
+    use crate::pgns::{
+        ChargerMode, ChargerState, ChargerStatus, EngineInstance, InverterState, InverterStatus,
+        OffOn, Pgn, TripParametersEngine,
+    };
+    use crate::{Bus, BusError, Id, Message, Priority, GLOBAL_ADDRESS};
 
     use crate::frame::*;
     struct MockCan {
@@ -205,21 +272,21 @@ mod tests {
 
     #[test]
     fn bus_send() {
-        struct TestCase {
-            message: Message,
+        struct TestCase<'a> {
+            message: Message<'a>,
         }
         let test_cases = [
             TestCase {
                 message: Message::new(
                     Id::new(Priority::Priority0, 12345, 123, GLOBAL_ADDRESS).unwrap(),
-                    Box::new([1, 2, 3, 4, 5, 6, 7]),
+                    &[1, 2, 3, 4, 5, 6, 7],
                 )
                 .unwrap(),
             },
             TestCase {
                 message: Message::new(
                     Id::new(Priority::Priority0, 12345, 123, GLOBAL_ADDRESS).unwrap(),
-                    Box::new([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17]),
+                    &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17],
                 )
                 .unwrap(),
             },
@@ -235,12 +302,138 @@ mod tests {
                 // Single packet
             } else {
                 // Multipacket
-                for b in 0..data.len() {
+                for (b, byte) in data.iter().enumerate() {
                     let frame = (b / 7) + 1;
                     let index = b - ((frame - 1) * 7) + 1;
-                    assert_eq!(bus.can.frames[frame].data().unwrap()[index], data[b])
+                    assert_eq!(bus.can.frames[frame].data().unwrap()[index], *byte)
                 }
             }
         }
     }
+
+    #[test]
+    fn bus_send_fast_packet() {
+        let can = MockCan::new();
+        let mut bus = Bus::new(can);
+
+        let data: Vec<u8> = (1..=20).collect();
+        let message = Message::new(
+            Id::new(Priority::Priority2, 127489, 0, GLOBAL_ADDRESS).unwrap(),
+            &data,
+        )
+        .unwrap();
+        bus.send_fast_packet(&message).unwrap();
+        bus.send_fast_packet(&message).unwrap();
+
+        let frames: Vec<&[u8]> = bus.can.frames.iter().map(|f| f.data().unwrap()).collect();
+        assert_eq!(frames.len(), 6);
+        assert_eq!(frames[0], &[0x00, 20, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(frames[1], &[0x01, 7, 8, 9, 10, 11, 12, 13]);
+        assert_eq!(frames[2], &[0x02, 14, 15, 16, 17, 18, 19, 20]);
+        assert_eq!(frames[3], &[0x20, 20, 1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn bus_send_selects_transport() {
+        let can = MockCan::new();
+        let mut bus = Bus::new(can);
+
+        // Trip Parameters, Engine is a fast packet even when it fits a frame
+        let message = Message::new(
+            Id::new(Priority::Priority5, 127497, 0, GLOBAL_ADDRESS).unwrap(),
+            &[1, 2, 3, 4, 5],
+        )
+        .unwrap();
+        bus.send(&message).unwrap();
+        assert_eq!(bus.can.frames.len(), 1);
+        assert_eq!(
+            bus.can.frames[0].data().unwrap(),
+            &[0x00, 5, 1, 2, 3, 4, 5, 0xff]
+        );
+
+        // Heave is a single frame
+        let message = Message::new(
+            Id::new(Priority::Priority3, 127252, 0, GLOBAL_ADDRESS).unwrap(),
+            &[0; 9],
+        )
+        .unwrap();
+        assert_eq!(bus.send(&message), Err(BusError::MessageTooLong));
+    }
+
+    #[test]
+    fn bus_send_pgn() {
+        let can = MockCan::new();
+        let mut bus = Bus::new(can);
+
+        let trip = TripParametersEngine {
+            instance: EngineInstance::SingleOrPort,
+            trip_fuel_used: Some(42),
+            fuel_rate_average: None,
+            fuel_rate_economy: None,
+            instantaneous_fuel_economy: None,
+        };
+        bus.send_pgn(&trip, GLOBAL_ADDRESS).unwrap();
+
+        assert_eq!(bus.can.frames.len(), 2);
+        assert_eq!(bus.can.frames[0].id().pgn(), TripParametersEngine::PGN);
+        assert_eq!(
+            bus.can.frames[0].data().unwrap(),
+            &[0x00, 9, 0x00, 42, 0x00, 0xff, 0x7f, 0xff]
+        );
+        assert_eq!(
+            bus.can.frames[1].data().unwrap(),
+            &[0x01, 0x7f, 0xff, 0x7f, 0xff, 0xff, 0xff, 0xff]
+        );
+    }
+
+    #[test]
+    fn bus_send_pgn_charger_inverter_status() {
+        let can = MockCan::new();
+        let mut bus = Bus::new(can);
+
+        // both fit a frame but are sent as fast packets
+        let charger = ChargerStatus {
+            instance: 0,
+            battery_instance: 1,
+            operating_state: ChargerState::Bulk,
+            charge_mode: ChargerMode::Standalone,
+            enabled: OffOn::On,
+            equalization_pending: OffOn::Off,
+            equalization_time_remaining: None,
+        };
+        bus.send_pgn(&charger, GLOBAL_ADDRESS).unwrap();
+        let inverter = InverterStatus {
+            instance: 0,
+            ac_instance: 1,
+            dc_instance: 2,
+            operating_state: InverterState::Invert,
+            enabled: OffOn::On,
+        };
+        bus.send_pgn(&inverter, GLOBAL_ADDRESS).unwrap();
+
+        assert_eq!(bus.can.frames.len(), 2);
+        assert_eq!(bus.can.frames[0].id().pgn(), ChargerStatus::PGN);
+        assert_eq!(
+            bus.can.frames[0].data().unwrap(),
+            &[0x00, 6, 0x00, 0x01, 0x01, 0xf1, 0xff, 0xff]
+        );
+        assert_eq!(bus.can.frames[1].id().pgn(), InverterStatus::PGN);
+        assert_eq!(
+            bus.can.frames[1].data().unwrap(),
+            &[0x20, 4, 0x00, 0x01, 0x02, 0xd0, 0xff, 0xff]
+        );
+
+        // the catalogue picks fast packet for untyped messages too
+        let message = Message::new(
+            Id::new(Priority::Priority6, InverterStatus::PGN, 0, GLOBAL_ADDRESS).unwrap(),
+            &[0x00, 0x01, 0x02, 0xd0],
+        )
+        .unwrap();
+        bus.send(&message).unwrap();
+        assert_eq!(bus.can.frames.len(), 3);
+        assert_eq!(
+            bus.can.frames[2].data().unwrap(),
+            &[0x40, 4, 0x00, 0x01, 0x02, 0xd0, 0xff, 0xff]
+        );
+    }
 }
@@ -1,6 +1,7 @@
 #![no_std]
 
 pub const GLOBAL_ADDRESS: u8 = 0xff;
+pub const FAST_PACKET_MAX_LENGTH: usize = 223; // 6 bytes in the first frame and 7 in up to 31 more
 
 use embedded_hal_can as hal_can;
 
@@ -21,3 +22,5 @@ pub use product::Product;
 
 mod frame;
 pub use frame::CanFrame;
+
+pub mod pgns;
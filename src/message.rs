@@ -26,6 +26,6 @@ impl<'a> Message<'a> {
     }
 
     pub fn data(&self) -> &[u8] {
-        &self.data
+        self.data
     }
 }
@@ -0,0 +1,534 @@
+use heapless::Vec;
+
+use crate::pgns::field::{Reader, Writer};
+use crate::pgns::{Pgn, PgnError, Result};
+use crate::Priority;
+
+// J1939 power fields are unsigned with an offset of -2,000,000,000.
+const POWER_OFFSET: i64 = 2_000_000_000;
+
+fn power(raw: Option<u64>) -> Option<i64> {
+    raw.map(|v| v as i64 - POWER_OFFSET)
+}
+
+fn raw_power(value: Option<i64>) -> Option<u64> {
+    value.map(|v| v.saturating_add(POWER_OFFSET).max(0) as u64)
+}
+
+lookup! {
+    pub enum AcLine: u8 {
+        Line1 = 0 => "Line 1",
+        Line2 = 1 => "Line 2",
+        Line3 = 2 => "Line 3",
+    }
+}
+
+lookup! {
+    pub enum Acceptability: u8 {
+        BadLevel = 0 => "Bad level",
+        BadFrequency = 1 => "Bad frequency",
+        BeingQualified = 2 => "Being qualified",
+        Good = 3,
+    }
+}
+
+lookup! {
+    pub enum Waveform: u8 {
+        Sine = 0 => "Sine wave",
+        ModifiedSine = 1 => "Modified sine wave",
+    }
+}
+
+lookup! {
+    pub enum PowerFactorLagging: u8 {
+        Leading = 0,
+        Lagging = 1,
+        Error = 2,
+    }
+}
+
+/// One line of an AC input status report.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AcLineStatus {
+    pub line: AcLine,
+    pub acceptability: Acceptability,
+    /// Volts
+    pub voltage: Option<f32>,
+    /// Amperes
+    pub current: Option<f32>,
+    /// Hertz
+    pub frequency: Option<f32>,
+    /// Amperes
+    pub breaker_size: Option<f32>,
+    /// Watts
+    pub real_power: Option<u32>,
+    /// Volt-amperes reactive
+    pub reactive_power: Option<u32>,
+    pub power_factor: Option<f32>,
+}
+
+/// PGN 127503 - AC Input Status
+#[derive(Clone, Debug, PartialEq)]
+pub struct AcInputStatus {
+    pub instance: u8,
+    pub lines: Vec<AcLineStatus, 3>,
+}
+
+impl<'a> Pgn<'a> for AcInputStatus {
+    const PGN: u32 = 127503;
+    const PRIORITY: Priority = Priority::Priority6;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let instance = r.u8()?;
+        let count = r.u8()?;
+
+        let mut lines = Vec::new();
+        for _ in 0..count {
+            let line = r.lookup(2)?;
+            let acceptability = r.lookup(2)?;
+            r.reserved(4)?;
+            let status = AcLineStatus {
+                line,
+                acceptability,
+                voltage: r.ufix(16, 0.01)?,
+                current: r.ufix(16, 0.1)?,
+                frequency: r.ufix(16, 0.01)?,
+                breaker_size: r.ufix(16, 0.1)?,
+                real_power: r.uint(32)?.map(|v| v as u32),
+                reactive_power: r.uint(32)?.map(|v| v as u32),
+                power_factor: r.sfix(8, 0.01)?,
+            };
+            lines.push(status).map_err(|_| PgnError::TooManyEntries)?;
+        }
+        Ok(AcInputStatus { instance, lines })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.instance)?;
+        w.u8(self.lines.len() as u8)?;
+        for line in &self.lines {
+            w.lookup(2, line.line)?;
+            w.lookup(2, line.acceptability)?;
+            w.reserved(4)?;
+            w.ufix(16, 0.01, line.voltage)?;
+            w.ufix(16, 0.1, line.current)?;
+            w.ufix(16, 0.01, line.frequency)?;
+            w.ufix(16, 0.1, line.breaker_size)?;
+            w.uint(32, line.real_power.map(|v| v as u64))?;
+            w.uint(32, line.reactive_power.map(|v| v as u64))?;
+            w.sfix(8, 0.01, line.power_factor)?;
+        }
+        Ok(w.len())
+    }
+}
+
+/// One line of an AC output status report.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AcOutputLineStatus {
+    pub line: AcLine,
+    pub waveform: Waveform,
+    /// Volts
+    pub voltage: Option<f32>,
+    /// Amperes
+    pub current: Option<f32>,
+    /// Hertz
+    pub frequency: Option<f32>,
+    /// Amperes
+    pub breaker_size: Option<f32>,
+    /// Watts
+    pub real_power: Option<u32>,
+    /// Volt-amperes reactive
+    pub reactive_power: Option<u32>,
+    pub power_factor: Option<f32>,
+}
+
+/// PGN 127504 - AC Output Status
+#[derive(Clone, Debug, PartialEq)]
+pub struct AcOutputStatus {
+    pub instance: u8,
+    pub lines: Vec<AcOutputLineStatus, 3>,
+}
+
+impl<'a> Pgn<'a> for AcOutputStatus {
+    const PGN: u32 = 127504;
+    const PRIORITY: Priority = Priority::Priority6;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let instance = r.u8()?;
+        let count = r.u8()?;
+
+        let mut lines = Vec::new();
+        for _ in 0..count {
+            let line = r.lookup(2)?;
+            let waveform = r.lookup(3)?;
+            r.reserved(3)?;
+            let status = AcOutputLineStatus {
+                line,
+                waveform,
+                voltage: r.ufix(16, 0.01)?,
+                current: r.ufix(16, 0.1)?,
+                frequency: r.ufix(16, 0.01)?,
+                breaker_size: r.ufix(16, 0.1)?,
+                real_power: r.uint(32)?.map(|v| v as u32),
+                reactive_power: r.uint(32)?.map(|v| v as u32),
+                power_factor: r.sfix(8, 0.01)?,
+            };
+            lines.push(status).map_err(|_| PgnError::TooManyEntries)?;
+        }
+        Ok(AcOutputStatus { instance, lines })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.instance)?;
+        w.u8(self.lines.len() as u8)?;
+        for line in &self.lines {
+            w.lookup(2, line.line)?;
+            w.lookup(3, line.waveform)?;
+            w.reserved(3)?;
+            w.ufix(16, 0.01, line.voltage)?;
+            w.ufix(16, 0.1, line.current)?;
+            w.ufix(16, 0.01, line.frequency)?;
+            w.ufix(16, 0.1, line.breaker_size)?;
+            w.uint(32, line.real_power.map(|v| v as u64))?;
+            w.uint(32, line.reactive_power.map(|v| v as u64))?;
+            w.sfix(8, 0.01, line.power_factor)?;
+        }
+        Ok(w.len())
+    }
+}
+
+/// J1939 Basic AC Quantities of a bus, average or per phase.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AcBusBasicQuantities<const PGN: u32> {
+    /// Volts
+    pub line_line_voltage: Option<u16>,
+    /// Volts
+    pub line_neutral_voltage: Option<u16>,
+    /// Hertz
+    pub frequency: Option<f32>,
+}
+
+pub type BusPhaseCBasicAcQuantities = AcBusBasicQuantities<65001>;
+pub type BusPhaseBBasicAcQuantities = AcBusBasicQuantities<65002>;
+pub type BusPhaseABasicAcQuantities = AcBusBasicQuantities<65003>;
+pub type BusAverageBasicAcQuantities = AcBusBasicQuantities<65004>;
+
+impl<'a, const PGN: u32> Pgn<'a> for AcBusBasicQuantities<PGN> {
+    const PGN: u32 = PGN;
+    const PRIORITY: Priority = Priority::Priority3;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(AcBusBasicQuantities {
+            line_line_voltage: r.uint(16)?.map(|v| v as u16),
+            line_neutral_voltage: r.uint(16)?.map(|v| v as u16),
+            frequency: r.ufix(16, 1.0 / 128.0)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.uint(16, self.line_line_voltage.map(|v| v as u64))?;
+        w.uint(16, self.line_neutral_voltage.map(|v| v as u64))?;
+        w.ufix(16, 1.0 / 128.0, self.frequency)?;
+        w.reserved(16)?;
+        Ok(w.len())
+    }
+}
+
+/// J1939 Basic AC Quantities, shared by the utility and generator average
+/// and per phase parameter groups.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AcBasicQuantities<const PGN: u32> {
+    /// Volts
+    pub line_line_voltage: Option<u16>,
+    /// Volts
+    pub line_neutral_voltage: Option<u16>,
+    /// Hertz
+    pub frequency: Option<f32>,
+    /// Amperes
+    pub current: Option<u16>,
+}
+
+pub type UtilityPhaseCBasicAcQuantities = AcBasicQuantities<65008>;
+pub type UtilityPhaseBBasicAcQuantities = AcBasicQuantities<65011>;
+pub type UtilityPhaseABasicAcQuantities = AcBasicQuantities<65014>;
+pub type UtilityAverageBasicAcQuantities = AcBasicQuantities<65017>;
+pub type GeneratorPhaseCBasicAcQuantities = AcBasicQuantities<65021>;
+pub type GeneratorPhaseBBasicAcQuantities = AcBasicQuantities<65024>;
+pub type GeneratorPhaseABasicAcQuantities = AcBasicQuantities<65027>;
+pub type GeneratorAverageBasicAcQuantities = AcBasicQuantities<65030>;
+
+impl<'a, const PGN: u32> Pgn<'a> for AcBasicQuantities<PGN> {
+    const PGN: u32 = PGN;
+    const PRIORITY: Priority = Priority::Priority3;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(AcBasicQuantities {
+            line_line_voltage: r.uint(16)?.map(|v| v as u16),
+            line_neutral_voltage: r.uint(16)?.map(|v| v as u16),
+            frequency: r.ufix(16, 1.0 / 128.0)?,
+            current: r.uint(16)?.map(|v| v as u16),
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.uint(16, self.line_line_voltage.map(|v| v as u64))?;
+        w.uint(16, self.line_neutral_voltage.map(|v| v as u64))?;
+        w.ufix(16, 1.0 / 128.0, self.frequency)?;
+        w.uint(16, self.current.map(|v| v as u64))?;
+        Ok(w.len())
+    }
+}
+
+/// J1939 AC Power, total or per phase.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AcPower<const PGN: u32> {
+    /// Watts
+    pub real_power: Option<i64>,
+    /// Volt-amperes
+    pub apparent_power: Option<i64>,
+}
+
+pub type UtilityPhaseCAcPower = AcPower<65007>;
+pub type UtilityPhaseBAcPower = AcPower<65010>;
+pub type UtilityPhaseAAcPower = AcPower<65013>;
+pub type UtilityTotalAcPower = AcPower<65016>;
+pub type GeneratorPhaseCAcPower = AcPower<65020>;
+pub type GeneratorPhaseBAcPower = AcPower<65023>;
+pub type GeneratorPhaseAAcPower = AcPower<65026>;
+pub type GeneratorTotalAcPower = AcPower<65029>;
+
+impl<'a, const PGN: u32> Pgn<'a> for AcPower<PGN> {
+    const PGN: u32 = PGN;
+    const PRIORITY: Priority = Priority::Priority3;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(AcPower {
+            real_power: power(r.uint(32)?),
+            apparent_power: power(r.uint(32)?),
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.uint(32, raw_power(self.real_power))?;
+        w.uint(32, raw_power(self.apparent_power))?;
+        Ok(w.len())
+    }
+}
+
+/// J1939 AC Reactive Power, total or per phase.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AcReactivePower<const PGN: u32> {
+    /// Volt-amperes reactive
+    pub reactive_power: Option<i64>,
+    pub power_factor: Option<f32>,
+    pub power_factor_lagging: PowerFactorLagging,
+}
+
+pub type UtilityPhaseCAcReactivePower = AcReactivePower<65006>;
+pub type UtilityPhaseBAcReactivePower = AcReactivePower<65009>;
+pub type UtilityPhaseAAcReactivePower = AcReactivePower<65012>;
+pub type UtilityTotalAcReactivePower = AcReactivePower<65015>;
+pub type GeneratorPhaseCAcReactivePower = AcReactivePower<65019>;
+pub type GeneratorPhaseBAcReactivePower = AcReactivePower<65022>;
+pub type GeneratorPhaseAAcReactivePower = AcReactivePower<65025>;
+pub type GeneratorTotalAcReactivePower = AcReactivePower<65028>;
+
+impl<'a, const PGN: u32> Pgn<'a> for AcReactivePower<PGN> {
+    const PGN: u32 = PGN;
+    const PRIORITY: Priority = Priority::Priority3;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(AcReactivePower {
+            reactive_power: power(r.uint(32)?),
+            // transmitted with an offset of -1, covering -1.0 to 1.0
+            power_factor: r.ufix(16, 1.0 / 16384.0)?.map(|v| v - 1.0),
+            power_factor_lagging: r.lookup(2)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.uint(32, raw_power(self.reactive_power))?;
+        w.ufix(16, 1.0 / 16384.0, self.power_factor.map(|v| v + 1.0))?;
+        w.lookup(2, self.power_factor_lagging)?;
+        w.reserved(14)?;
+        Ok(w.len())
+    }
+}
+
+/// J1939 Total AC Energy.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AcTotalEnergy<const PGN: u32> {
+    /// Kilowatt hours
+    pub export: Option<u32>,
+    /// Kilowatt hours
+    pub import: Option<u32>,
+}
+
+pub type UtilityTotalAcEnergy = AcTotalEnergy<65005>;
+pub type GeneratorTotalAcEnergy = AcTotalEnergy<65018>;
+
+impl<'a, const PGN: u32> Pgn<'a> for AcTotalEnergy<PGN> {
+    const PGN: u32 = PGN;
+    const PRIORITY: Priority = Priority::Priority3;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(AcTotalEnergy {
+            export: r.uint(32)?.map(|v| v as u32),
+            import: r.uint(32)?.map(|v| v as u32),
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.uint(32, self.export.map(|v| v as u64))?;
+        w.uint(32, self.import.map(|v| v as u64))?;
+        Ok(w.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use heapless::Vec;
+
+    use crate::pgns::{
+        AcInputStatus, AcLine, AcLineStatus, AcOutputLineStatus, AcOutputStatus, Acceptability,
+        BusAverageBasicAcQuantities, GeneratorAverageBasicAcQuantities, GeneratorTotalAcPower,
+        GeneratorTotalAcReactivePower, Pgn, PgnError, PowerFactorLagging, UtilityTotalAcPower,
+        Waveform,
+    };
+    use crate::Priority;
+
+    #[test]
+    fn ac_input_status_roundtrip() {
+        let mut lines = Vec::new();
+        for (line, voltage) in [(AcLine::Line1, 230.0), (AcLine::Line2, 231.5)].iter() {
+            let status = AcLineStatus {
+                line: *line,
+                acceptability: Acceptability::Good,
+                voltage: Some(*voltage),
+                current: Some(12.5),
+                frequency: Some(50.0),
+                breaker_size: Some(16.0),
+                real_power: Some(2800),
+                reactive_power: None,
+                power_factor: Some(-0.95),
+            };
+            lines.push(status).unwrap();
+        }
+        let status = AcInputStatus { instance: 0, lines };
+
+        let mut buf = [0; 64];
+        let len = status.encode(&mut buf).unwrap();
+        assert_eq!(len, 2 + 2 * 18);
+        assert_eq!(buf[1], 2);
+        assert_eq!(buf[2], 0xf0 | 0x0c);
+        assert_eq!(buf[19], (-95i8) as u8);
+        assert_eq!(buf[20], 0xf0 | 0x0c | 0x01);
+
+        let decoded = AcInputStatus::decode(&buf[..len]).unwrap();
+        assert_eq!(decoded.lines.len(), 2);
+        assert_eq!(decoded.lines[1].line, AcLine::Line2);
+        assert_eq!(decoded.lines[1].voltage, Some(231.5));
+        assert_eq!(decoded.lines[0].real_power, Some(2800));
+        assert_eq!(decoded.lines[0].reactive_power, None);
+        assert_eq!(decoded.lines[0].power_factor, Some(-0.95));
+
+        // a fourth line does not fit
+        let mut data = [0xff; 2 + 4 * 18];
+        data[1] = 4;
+        assert_eq!(AcInputStatus::decode(&data), Err(PgnError::TooManyEntries));
+    }
+
+    #[test]
+    fn ac_output_status_roundtrip() {
+        let mut lines = Vec::new();
+        let status = AcOutputLineStatus {
+            line: AcLine::Line3,
+            waveform: Waveform::ModifiedSine,
+            voltage: Some(120.0),
+            current: Some(4.5),
+            frequency: Some(60.0),
+            breaker_size: None,
+            real_power: Some(540),
+            reactive_power: Some(20),
+            power_factor: Some(0.98),
+        };
+        lines.push(status).unwrap();
+        let status = AcOutputStatus { instance: 1, lines };
+
+        let mut buf = [0; 32];
+        let len = status.encode(&mut buf).unwrap();
+        assert_eq!(len, 2 + 18);
+        assert_eq!(&buf[..3], &[0x01, 1, 0xe0 | 0x04 | 0x02]);
+        assert_eq!(AcOutputStatus::decode(&buf[..len]).unwrap(), status);
+    }
+
+    #[test]
+    fn generator_ac_decode() {
+        let data = [0x9a, 0x01, 0xe6, 0x00, 0x00, 0x19, 0x0c, 0x00];
+        let basic = GeneratorAverageBasicAcQuantities::decode(&data).unwrap();
+        assert_eq!(basic.line_line_voltage, Some(410));
+        assert_eq!(basic.line_neutral_voltage, Some(230));
+        assert_eq!(basic.frequency, Some(50.0));
+        assert_eq!(basic.current, Some(12));
+        assert_eq!(GeneratorAverageBasicAcQuantities::PGN, 65030);
+        assert_eq!(
+            GeneratorAverageBasicAcQuantities::PRIORITY,
+            Priority::Priority3
+        );
+
+        // the bus has no current, the last two bytes are reserved
+        let basic = BusAverageBasicAcQuantities::decode(&data).unwrap();
+        assert_eq!(basic.frequency, Some(50.0));
+        let mut buf = [0; 8];
+        basic.encode(&mut buf).unwrap();
+        assert_eq!(&buf[6..], &[0xff, 0xff]);
+
+        let power = GeneratorTotalAcPower {
+            real_power: Some(-1500),
+            apparent_power: None,
+        };
+        power.encode(&mut buf).unwrap();
+        assert_eq!(buf, [0x24, 0x8e, 0x35, 0x77, 0xff, 0xff, 0xff, 0xff]);
+        assert_eq!(GeneratorTotalAcPower::decode(&buf).unwrap(), power);
+
+        // the full unsigned range does not fit an i32 once the offset is removed
+        let data = [0xfe, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x00];
+        let power = UtilityTotalAcPower::decode(&data).unwrap();
+        assert_eq!(power.real_power, Some(2_294_967_294));
+        assert_eq!(power.apparent_power, Some(-2_000_000_000));
+        let power = UtilityTotalAcPower {
+            real_power: Some(-3_000_000_000),
+            apparent_power: Some(3_000_000_000),
+        };
+        power.encode(&mut buf).unwrap();
+        assert_eq!(buf, [0x00, 0x00, 0x00, 0x00, 0xfe, 0xff, 0xff, 0xff]);
+
+        let reactive = GeneratorTotalAcReactivePower {
+            reactive_power: Some(250),
+            power_factor: Some(0.5),
+            power_factor_lagging: PowerFactorLagging::Lagging,
+        };
+        assert_eq!(reactive.encode(&mut buf), Ok(8));
+        assert_eq!(buf, [0xfa, 0x94, 0x35, 0x77, 0x00, 0x60, 0xfd, 0xff]);
+        assert_eq!(
+            GeneratorTotalAcReactivePower::decode(&buf).unwrap(),
+            reactive
+        );
+    }
+}
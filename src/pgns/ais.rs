@@ -0,0 +1,1489 @@
+use crate::pgns::field::{Reader, Writer};
+use crate::pgns::{LauString, Pgn, Result, YesNo};
+use crate::Priority;
+
+lookup! {
+    pub enum RepeatIndicator: u8 {
+        Initial = 0,
+        FirstRetransmission = 1 => "First retransmission",
+        SecondRetransmission = 2 => "Second retransmission",
+        FinalRetransmission = 3 => "Final retransmission",
+    }
+}
+
+lookup! {
+    pub enum PositionAccuracy: u8 {
+        Low = 0,
+        High = 1,
+    }
+}
+
+lookup! {
+    pub enum RaimFlag: u8 {
+        NotInUse = 0 => "not in use",
+        InUse = 1 => "in use",
+    }
+}
+
+lookup! {
+    pub enum AisTransceiver: u8 {
+        ChannelAVdlReception = 0 => "Channel A VDL reception",
+        ChannelBVdlReception = 1 => "Channel B VDL reception",
+        ChannelAVdlTransmission = 2 => "Channel A VDL transmission",
+        ChannelBVdlTransmission = 3 => "Channel B VDL transmission",
+        OwnInformationNotBroadcast = 4 => "Own information not broadcast",
+    }
+}
+
+lookup! {
+    pub enum NavStatus: u8 {
+        UnderWayUsingEngine = 0 => "Under way using engine",
+        AtAnchor = 1 => "At anchor",
+        NotUnderCommand = 2 => "Not under command",
+        RestrictedManoeuverability = 3 => "Restricted maneuverability",
+        ConstrainedByDraught = 4 => "Constrained by her draught",
+        Moored = 5,
+        Aground = 6,
+        EngagedInFishing = 7 => "Engaged in Fishing",
+        UnderWaySailing = 8 => "Under way sailing",
+        HazardousMaterialHighSpeed = 9 => "Hazardous material - High Speed",
+        HazardousMaterialWingInGround = 10 => "Hazardous material - Wing in Ground",
+        TowingAstern = 11 => "Power-driven vessel towing astern",
+        PushingAhead = 12 => "Power-driven vessel pushing ahead or towing alongside",
+        AisSart = 14 => "AIS-SART",
+        Undefined = 15,
+    }
+}
+
+lookup! {
+    pub enum SpecialManeuver: u8 {
+        NotAvailable = 0 => "Not available",
+        NotEngaged = 1 => "Not engaged in special maneuver",
+        Engaged = 2 => "Engaged in special maneuver",
+    }
+}
+
+lookup! {
+    pub enum AisUnitType: u8 {
+        Sotdma = 0 => "SOTDMA",
+        Cs = 1 => "CS",
+    }
+}
+
+lookup! {
+    pub enum AisBand: u8 {
+        Top525KhzOfMarineBand = 0 => "Top 525 kHz of marine band",
+        EntireMarineBand = 1 => "Entire marine band",
+    }
+}
+
+lookup! {
+    pub enum AisMode: u8 {
+        Autonomous = 0,
+        Assigned = 1,
+    }
+}
+
+lookup! {
+    pub enum AisCommunicationState: u8 {
+        Sotdma = 0 => "SOTDMA",
+        Itdma = 1 => "ITDMA",
+    }
+}
+
+lookup! {
+    pub enum GnssType: u8 {
+        Undefined = 0 => "Default: undefined",
+        Gps = 1 => "GPS",
+        Glonass = 2 => "GLONASS",
+        GpsGlonass = 3 => "Combined GPS/GLONASS",
+        LoranC = 4 => "Loran-C",
+        Chayka = 5,
+        Integrated = 6 => "Integrated navigation system",
+        Surveyed = 7,
+        Galileo = 8,
+    }
+}
+
+lookup! {
+    pub enum ShipType: u8 {
+        Unavailable = 0,
+        WingInGround = 20 => "Wing In Ground",
+        WingInGroundNoOtherInformation = 29 => "Wing In Ground (no additional information)",
+        Fishing = 30,
+        Towing = 31,
+        TowingLarge = 32 => "Towing exceeds 200m or wider than 25m",
+        Dredging = 33 => "Engaged in dredging or underwater operations",
+        Diving = 34 => "Engaged in diving operations",
+        Military = 35 => "Engaged in military operations",
+        Sailing = 36,
+        Pleasure = 37,
+        HighSpeedCraft = 40 => "High speed craft",
+        HighSpeedCraftDangerousGoods = 41 => "High speed craft (hazard cat X)",
+        HighSpeedCraftHazardCatB = 42 => "High speed craft (hazard cat Y)",
+        HighSpeedCraftHazardCatC = 43 => "High speed craft (hazard cat Z)",
+        HighSpeedCraftHazardCatD = 44 => "High speed craft (hazard cat OS)",
+        HighSpeedCraftNoAdditionalInformation = 49
+            => "High speed craft (no additional information)",
+        PilotVessel = 50 => "Pilot vessel",
+        Sar = 51 => "SAR",
+        Tug = 52,
+        PortTender = 53 => "Port tender",
+        AntiPollution = 54 => "Anti-pollution",
+        LawEnforcement = 55 => "Law enforcement",
+        Spare = 56,
+        Spare2 = 57 => "Spare #2",
+        Medical = 58,
+        RrResolutionNo18 = 59 => "Ships and aircraft of States not parties to an armed conflict",
+        Passenger = 60 => "Passenger ship",
+        PassengerNoAdditionalInformation = 69 => "Passenger ship (no additional information)",
+        Cargo = 70 => "Cargo ship",
+        CargoDangerousGoods = 71 => "Cargo ship (hazard cat X)",
+        CargoHazardCatB = 72 => "Cargo ship (hazard cat Y)",
+        CargoHazardCatC = 73 => "Cargo ship (hazard cat Z)",
+        CargoHazardCatD = 74 => "Cargo ship (hazard cat OS)",
+        CargoNoAdditionalInformation = 79 => "Cargo ship (no additional information)",
+        Tanker = 80,
+        TankerDangerousGoods = 81 => "Tanker (hazard cat X)",
+        TankerHazardCatB = 82 => "Tanker (hazard cat Y)",
+        TankerHazardCatC = 83 => "Tanker (hazard cat Z)",
+        TankerHazardCatD = 84 => "Tanker (hazard cat OS)",
+        TankerNoAdditionalInformation = 89 => "Tanker (no additional information)",
+        Other = 90,
+        OtherDangerousGoods = 91 => "Other (hazard cat X)",
+        OtherHazardCatB = 92 => "Other (hazard cat Y)",
+        OtherHazardCatC = 93 => "Other (hazard cat Z)",
+        OtherHazardCatD = 94 => "Other (hazard cat OS)",
+        OtherNoAdditionalInformation = 99 => "Other (no additional information)",
+    }
+}
+
+lookup! {
+    pub enum AisVersion: u8 {
+        ItuRM1371_1 = 0 => "ITU-R M.1371-1",
+        ItuRM1371_3 = 1 => "ITU-R M.1371-3",
+        ItuRM1371_5 = 2 => "ITU-R M.1371-5",
+        ItuRM1371Future = 3 => "ITU-R M.1371 future edition",
+    }
+}
+
+lookup! {
+    pub enum AtonType: u8 {
+        NotSpecified = 0 => "Default: Type of AtoN not specified",
+        ReferencePoint = 1 => "Reference point",
+        Racon = 2 => "RACON",
+        FixedStructureOffShore = 3 => "Fixed structure off-shore",
+        FixedLightWithoutSectors = 5 => "Fixed light: without sectors",
+        FixedLightWithSectors = 6 => "Fixed light: with sectors",
+        FixedLeadingLightFront = 7 => "Fixed leading light front",
+        FixedLeadingLightRear = 8 => "Fixed leading light rear",
+        FixedBeaconCardinalN = 9 => "Fixed beacon: cardinal N",
+        FixedBeaconCardinalE = 10 => "Fixed beacon: cardinal E",
+        FixedBeaconCardinalS = 11 => "Fixed beacon: cardinal S",
+        FixedBeaconCardinalW = 12 => "Fixed beacon: cardinal W",
+        FixedBeaconPortHand = 13 => "Fixed beacon: port hand",
+        FixedBeaconStarboardHand = 14 => "Fixed beacon: starboard hand",
+        FixedBeaconPreferredChannelPortHand = 15 => "Fixed beacon: preferred channel port hand",
+        FixedBeaconPreferredChannelStarboardHand = 16
+            => "Fixed beacon: preferred channel starboard hand",
+        FixedBeaconIsolatedDanger = 17 => "Fixed beacon: isolated danger",
+        FixedBeaconSafeWater = 18 => "Fixed beacon: safe water",
+        FixedBeaconSpecialMark = 19 => "Fixed beacon: special mark",
+        FloatingCardinalN = 20 => "Floating AtoN: cardinal N",
+        FloatingCardinalE = 21 => "Floating AtoN: cardinal E",
+        FloatingCardinalS = 22 => "Floating AtoN: cardinal S",
+        FloatingCardinalW = 23 => "Floating AtoN: cardinal W",
+        FloatingPortHandMark = 24 => "Floating AtoN: port hand mark",
+        FloatingStarboardHandMark = 25 => "Floating AtoN: starboard hand mark",
+        FloatingPreferredChannelPortHand = 26 => "Floating AtoN: preferred channel port hand",
+        FloatingPreferredChannelStarboardHand = 27
+            => "Floating AtoN: preferred channel starboard hand",
+        FloatingIsolatedDanger = 28 => "Floating AtoN: isolated danger",
+        FloatingSafeWater = 29 => "Floating AtoN: safe water",
+        FloatingSpecialMark = 30 => "Floating AtoN: special mark",
+        FloatingLightVessel = 31 => "Floating AtoN: light vessel/LANBY/rigs",
+    }
+}
+
+/// The header shared by the AIS position reports.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AisPosition {
+    pub message_id: u8,
+    pub repeat_indicator: RepeatIndicator,
+    pub mmsi: u32,
+    /// Degrees
+    pub longitude: Option<f64>,
+    /// Degrees
+    pub latitude: Option<f64>,
+    pub position_accuracy: PositionAccuracy,
+    pub raim: RaimFlag,
+    /// UTC second of the report; 60 to 63 flag why it is not available
+    pub time_stamp: u8,
+}
+
+impl AisPosition {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        Ok(AisPosition {
+            message_id: r.bits(6)? as u8,
+            repeat_indicator: r.lookup(2)?,
+            mmsi: r.bits(32)? as u32,
+            longitude: r.sfix64(32, 1e-7)?,
+            latitude: r.sfix64(32, 1e-7)?,
+            position_accuracy: r.lookup(1)?,
+            raim: r.lookup(1)?,
+            time_stamp: r.bits(6)? as u8,
+        })
+    }
+
+    fn encode(&self, w: &mut Writer) -> Result<()> {
+        w.bits(6, self.message_id as u64)?;
+        w.lookup(2, self.repeat_indicator)?;
+        w.bits(32, self.mmsi as u64)?;
+        w.sfix64(32, 1e-7, self.longitude)?;
+        w.sfix64(32, 1e-7, self.latitude)?;
+        w.lookup(1, self.position_accuracy)?;
+        w.lookup(1, self.raim)?;
+        w.bits(6, self.time_stamp as u64)
+    }
+}
+
+/// PGN 129038 - AIS Class A Position Report
+#[derive(Clone, Debug, PartialEq)]
+pub struct AisClassAPositionReport {
+    pub position: AisPosition,
+    /// Radians
+    pub cog: Option<f32>,
+    /// Metres per second
+    pub sog: Option<f32>,
+    pub communication_state: u32,
+    pub transceiver: AisTransceiver,
+    /// Radians
+    pub heading: Option<f32>,
+    /// Radians per second
+    pub rate_of_turn: Option<f32>,
+    pub nav_status: NavStatus,
+    pub special_maneuver: SpecialManeuver,
+    pub sequence_id: u8,
+}
+
+impl<'a> Pgn<'a> for AisClassAPositionReport {
+    const PGN: u32 = 129038;
+    const PRIORITY: Priority = Priority::Priority4;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let position = AisPosition::decode(&mut r)?;
+        let cog = r.ufix(16, 0.0001)?;
+        let sog = r.ufix(16, 0.01)?;
+        let communication_state = r.bits(19)? as u32;
+        let transceiver = r.lookup(5)?;
+        let heading = r.ufix(16, 0.0001)?;
+        let rate_of_turn = r.sfix(16, 3.125e-5)?;
+        let nav_status = r.lookup(4)?;
+        let special_maneuver = r.lookup(2)?;
+        r.reserved(2)?;
+        r.reserved(3)?; // AIS spare
+        r.reserved(5)?;
+        Ok(AisClassAPositionReport {
+            position,
+            cog,
+            sog,
+            communication_state,
+            transceiver,
+            heading,
+            rate_of_turn,
+            nav_status,
+            special_maneuver,
+            sequence_id: r.u8()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        self.position.encode(&mut w)?;
+        w.ufix(16, 0.0001, self.cog)?;
+        w.ufix(16, 0.01, self.sog)?;
+        w.bits(19, self.communication_state as u64)?;
+        w.lookup(5, self.transceiver)?;
+        w.ufix(16, 0.0001, self.heading)?;
+        w.sfix(16, 3.125e-5, self.rate_of_turn)?;
+        w.lookup(4, self.nav_status)?;
+        w.lookup(2, self.special_maneuver)?;
+        w.reserved(2)?;
+        w.bits(3, 0)?; // AIS spare
+        w.reserved(5)?;
+        w.u8(self.sequence_id)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 129039 - AIS Class B Position Report
+#[derive(Clone, Debug, PartialEq)]
+pub struct AisClassBPositionReport {
+    pub position: AisPosition,
+    /// Radians
+    pub cog: Option<f32>,
+    /// Metres per second
+    pub sog: Option<f32>,
+    pub communication_state: u32,
+    pub transceiver: AisTransceiver,
+    /// Radians
+    pub heading: Option<f32>,
+    pub regional_application: u8,
+    pub regional_application_b: u8,
+    pub unit_type: AisUnitType,
+    pub integrated_display: YesNo,
+    pub dsc: YesNo,
+    pub band: AisBand,
+    pub can_handle_msg_22: YesNo,
+    pub ais_mode: AisMode,
+    pub ais_communication_state: AisCommunicationState,
+    pub sequence_id: u8,
+}
+
+impl<'a> Pgn<'a> for AisClassBPositionReport {
+    const PGN: u32 = 129039;
+    const PRIORITY: Priority = Priority::Priority4;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(AisClassBPositionReport {
+            position: AisPosition::decode(&mut r)?,
+            cog: r.ufix(16, 0.0001)?,
+            sog: r.ufix(16, 0.01)?,
+            communication_state: r.bits(19)? as u32,
+            transceiver: r.lookup(5)?,
+            heading: r.ufix(16, 0.0001)?,
+            regional_application: r.u8()?,
+            regional_application_b: r.bits(2)? as u8,
+            unit_type: r.lookup(1)?,
+            integrated_display: r.lookup(1)?,
+            dsc: r.lookup(1)?,
+            band: r.lookup(1)?,
+            can_handle_msg_22: r.lookup(1)?,
+            ais_mode: r.lookup(1)?,
+            ais_communication_state: {
+                let state = r.lookup(1)?;
+                r.reserved(7)?;
+                state
+            },
+            sequence_id: r.u8()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        self.position.encode(&mut w)?;
+        w.ufix(16, 0.0001, self.cog)?;
+        w.ufix(16, 0.01, self.sog)?;
+        w.bits(19, self.communication_state as u64)?;
+        w.lookup(5, self.transceiver)?;
+        w.ufix(16, 0.0001, self.heading)?;
+        w.u8(self.regional_application)?;
+        w.bits(2, self.regional_application_b as u64)?;
+        w.lookup(1, self.unit_type)?;
+        w.lookup(1, self.integrated_display)?;
+        w.lookup(1, self.dsc)?;
+        w.lookup(1, self.band)?;
+        w.lookup(1, self.can_handle_msg_22)?;
+        w.lookup(1, self.ais_mode)?;
+        w.lookup(1, self.ais_communication_state)?;
+        w.reserved(7)?;
+        w.u8(self.sequence_id)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 129040 - AIS Class B Extended Position Report
+#[derive(Clone, Debug, PartialEq)]
+pub struct AisClassBExtendedPositionReport<'a> {
+    pub position: AisPosition,
+    /// Radians
+    pub cog: Option<f32>,
+    /// Metres per second
+    pub sog: Option<f32>,
+    pub regional_application: u8,
+    pub regional_application_b: u8,
+    pub ship_type: ShipType,
+    /// Radians
+    pub true_heading: Option<f32>,
+    pub gnss_type: GnssType,
+    /// Metres
+    pub length: Option<f32>,
+    /// Metres
+    pub beam: Option<f32>,
+    /// Metres
+    pub position_reference_from_starboard: Option<f32>,
+    /// Metres
+    pub position_reference_from_bow: Option<f32>,
+    pub name: &'a str,
+    pub dte_available: bool,
+    pub ais_mode: AisMode,
+    pub transceiver: AisTransceiver,
+}
+
+impl<'a> Pgn<'a> for AisClassBExtendedPositionReport<'a> {
+    const PGN: u32 = 129040;
+    const PRIORITY: Priority = Priority::Priority4;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let position = AisPosition::decode(&mut r)?;
+        let cog = r.ufix(16, 0.0001)?;
+        let sog = r.ufix(16, 0.01)?;
+        let regional_application = r.u8()?;
+        let regional_application_b = r.bits(4)? as u8;
+        r.reserved(4)?;
+        let ship_type = r.lookup(8)?;
+        let true_heading = r.ufix(16, 0.0001)?;
+        r.reserved(4)?;
+        let gnss_type = r.lookup(4)?;
+        Ok(AisClassBExtendedPositionReport {
+            position,
+            cog,
+            sog,
+            regional_application,
+            regional_application_b,
+            ship_type,
+            true_heading,
+            gnss_type,
+            length: r.ufix(16, 0.1)?,
+            beam: r.ufix(16, 0.1)?,
+            position_reference_from_starboard: r.ufix(16, 0.1)?,
+            position_reference_from_bow: r.ufix(16, 0.1)?,
+            name: r.string_fix(20)?,
+            // the DTE bit is set when the terminal is not available
+            dte_available: r.bits(1)? == 0,
+            ais_mode: {
+                let mode = r.lookup(1)?;
+                r.reserved(4)?; // AIS spare
+                mode
+            },
+            transceiver: r.lookup(5)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        self.position.encode(&mut w)?;
+        w.ufix(16, 0.0001, self.cog)?;
+        w.ufix(16, 0.01, self.sog)?;
+        w.u8(self.regional_application)?;
+        w.bits(4, self.regional_application_b as u64)?;
+        w.reserved(4)?;
+        w.lookup(8, self.ship_type)?;
+        w.ufix(16, 0.0001, self.true_heading)?;
+        w.reserved(4)?;
+        w.lookup(4, self.gnss_type)?;
+        w.ufix(16, 0.1, self.length)?;
+        w.ufix(16, 0.1, self.beam)?;
+        w.ufix(16, 0.1, self.position_reference_from_starboard)?;
+        w.ufix(16, 0.1, self.position_reference_from_bow)?;
+        w.string_fix(self.name, 20, b'@')?;
+        w.bits(1, !self.dte_available as u64)?;
+        w.lookup(1, self.ais_mode)?;
+        w.bits(4, 0)?; // AIS spare
+        w.lookup(5, self.transceiver)?;
+        w.reserved(5)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 129794 - AIS Class A Static and Voyage Related Data
+#[derive(Clone, Debug, PartialEq)]
+pub struct AisClassAStaticData<'a> {
+    pub message_id: u8,
+    pub repeat_indicator: RepeatIndicator,
+    pub mmsi: u32,
+    pub imo_number: Option<u32>,
+    pub callsign: &'a str,
+    pub name: &'a str,
+    pub ship_type: ShipType,
+    /// Metres
+    pub length: Option<f32>,
+    /// Metres
+    pub beam: Option<f32>,
+    /// Metres
+    pub position_reference_from_starboard: Option<f32>,
+    /// Metres
+    pub position_reference_from_bow: Option<f32>,
+    /// Days since 1970-01-01
+    pub eta_date: Option<u16>,
+    /// Seconds since midnight
+    pub eta_time: Option<f64>,
+    /// Metres
+    pub draft: Option<f32>,
+    pub destination: &'a str,
+    pub ais_version: AisVersion,
+    pub gnss_type: GnssType,
+    pub dte_available: bool,
+    pub transceiver: AisTransceiver,
+    pub sequence_id: u8,
+}
+
+impl<'a> Pgn<'a> for AisClassAStaticData<'a> {
+    const PGN: u32 = 129794;
+    const PRIORITY: Priority = Priority::Priority6;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(AisClassAStaticData {
+            message_id: r.bits(6)? as u8,
+            repeat_indicator: r.lookup(2)?,
+            mmsi: r.bits(32)? as u32,
+            imo_number: r.uint(32)?.map(|v| v as u32),
+            callsign: r.string_fix(7)?,
+            name: r.string_fix(20)?,
+            ship_type: r.lookup(8)?,
+            length: r.ufix(16, 0.1)?,
+            beam: r.ufix(16, 0.1)?,
+            position_reference_from_starboard: r.ufix(16, 0.1)?,
+            position_reference_from_bow: r.ufix(16, 0.1)?,
+            eta_date: r.uint(16)?.map(|v| v as u16),
+            eta_time: r.ufix64(32, 0.0001)?,
+            draft: r.ufix(16, 0.01)?,
+            destination: r.string_fix(20)?,
+            ais_version: r.lookup(2)?,
+            gnss_type: r.lookup(4)?,
+            dte_available: {
+                let dte = r.bits(1)? == 0;
+                r.reserved(1)?;
+                dte
+            },
+            transceiver: {
+                let transceiver = r.lookup(5)?;
+                r.reserved(3)?;
+                transceiver
+            },
+            sequence_id: r.u8()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.bits(6, self.message_id as u64)?;
+        w.lookup(2, self.repeat_indicator)?;
+        w.bits(32, self.mmsi as u64)?;
+        w.uint(32, self.imo_number.map(|v| v as u64))?;
+        w.string_fix(self.callsign, 7, b'@')?;
+        w.string_fix(self.name, 20, b'@')?;
+        w.lookup(8, self.ship_type)?;
+        w.ufix(16, 0.1, self.length)?;
+        w.ufix(16, 0.1, self.beam)?;
+        w.ufix(16, 0.1, self.position_reference_from_starboard)?;
+        w.ufix(16, 0.1, self.position_reference_from_bow)?;
+        w.uint(16, self.eta_date.map(|v| v as u64))?;
+        w.ufix64(32, 0.0001, self.eta_time)?;
+        w.ufix(16, 0.01, self.draft)?;
+        w.string_fix(self.destination, 20, b'@')?;
+        w.lookup(2, self.ais_version)?;
+        w.lookup(4, self.gnss_type)?;
+        w.bits(1, !self.dte_available as u64)?;
+        w.reserved(1)?;
+        w.lookup(5, self.transceiver)?;
+        w.reserved(3)?;
+        w.u8(self.sequence_id)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 129809 - AIS Class B "CS" Static Data Report, Part A
+#[derive(Clone, Debug, PartialEq)]
+pub struct AisClassBStaticDataPartA<'a> {
+    pub message_id: u8,
+    pub repeat_indicator: RepeatIndicator,
+    pub mmsi: u32,
+    pub name: &'a str,
+    pub transceiver: AisTransceiver,
+    pub sequence_id: u8,
+}
+
+impl<'a> Pgn<'a> for AisClassBStaticDataPartA<'a> {
+    const PGN: u32 = 129809;
+    const PRIORITY: Priority = Priority::Priority6;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(AisClassBStaticDataPartA {
+            message_id: r.bits(6)? as u8,
+            repeat_indicator: r.lookup(2)?,
+            mmsi: r.bits(32)? as u32,
+            name: r.string_fix(20)?,
+            transceiver: {
+                let transceiver = r.lookup(5)?;
+                r.reserved(3)?;
+                transceiver
+            },
+            sequence_id: r.u8()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.bits(6, self.message_id as u64)?;
+        w.lookup(2, self.repeat_indicator)?;
+        w.bits(32, self.mmsi as u64)?;
+        w.string_fix(self.name, 20, b'@')?;
+        w.lookup(5, self.transceiver)?;
+        w.reserved(3)?;
+        w.u8(self.sequence_id)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 129810 - AIS Class B "CS" Static Data Report, Part B
+#[derive(Clone, Debug, PartialEq)]
+pub struct AisClassBStaticDataPartB<'a> {
+    pub message_id: u8,
+    pub repeat_indicator: RepeatIndicator,
+    pub mmsi: u32,
+    pub ship_type: ShipType,
+    pub vendor_id: &'a str,
+    pub callsign: &'a str,
+    /// Metres
+    pub length: Option<f32>,
+    /// Metres
+    pub beam: Option<f32>,
+    /// Metres
+    pub position_reference_from_starboard: Option<f32>,
+    /// Metres
+    pub position_reference_from_bow: Option<f32>,
+    pub mothership_mmsi: u32,
+    pub gnss_type: GnssType,
+    pub transceiver: AisTransceiver,
+    pub sequence_id: u8,
+}
+
+impl<'a> Pgn<'a> for AisClassBStaticDataPartB<'a> {
+    const PGN: u32 = 129810;
+    const PRIORITY: Priority = Priority::Priority6;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(AisClassBStaticDataPartB {
+            message_id: r.bits(6)? as u8,
+            repeat_indicator: r.lookup(2)?,
+            mmsi: r.bits(32)? as u32,
+            ship_type: r.lookup(8)?,
+            vendor_id: r.string_fix(7)?,
+            callsign: r.string_fix(7)?,
+            length: r.ufix(16, 0.1)?,
+            beam: r.ufix(16, 0.1)?,
+            position_reference_from_starboard: r.ufix(16, 0.1)?,
+            position_reference_from_bow: r.ufix(16, 0.1)?,
+            mothership_mmsi: r.bits(32)? as u32,
+            gnss_type: {
+                r.reserved(2)?;
+                r.reserved(2)?; // AIS spare
+                r.lookup(4)?
+            },
+            transceiver: {
+                let transceiver = r.lookup(5)?;
+                r.reserved(3)?;
+                transceiver
+            },
+            sequence_id: r.u8()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.bits(6, self.message_id as u64)?;
+        w.lookup(2, self.repeat_indicator)?;
+        w.bits(32, self.mmsi as u64)?;
+        w.lookup(8, self.ship_type)?;
+        w.string_fix(self.vendor_id, 7, b'@')?;
+        w.string_fix(self.callsign, 7, b'@')?;
+        w.ufix(16, 0.1, self.length)?;
+        w.ufix(16, 0.1, self.beam)?;
+        w.ufix(16, 0.1, self.position_reference_from_starboard)?;
+        w.ufix(16, 0.1, self.position_reference_from_bow)?;
+        w.bits(32, self.mothership_mmsi as u64)?;
+        w.reserved(2)?;
+        w.bits(2, 0)?; // AIS spare
+        w.lookup(4, self.gnss_type)?;
+        w.lookup(5, self.transceiver)?;
+        w.reserved(3)?;
+        w.u8(self.sequence_id)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 129041 - AIS Aids to Navigation (AtoN) Report
+#[derive(Clone, Debug, PartialEq)]
+pub struct AisAtonReport<'a> {
+    pub position: AisPosition,
+    /// Metres
+    pub length: Option<f32>,
+    /// Metres
+    pub beam: Option<f32>,
+    /// Metres
+    pub position_reference_from_starboard: Option<f32>,
+    /// Metres
+    pub position_reference_from_true_north: Option<f32>,
+    pub aton_type: AtonType,
+    pub off_position: bool,
+    pub virtual_aton: bool,
+    pub assigned_mode: bool,
+    pub position_fixing_device: GnssType,
+    pub aton_status: u8,
+    pub transceiver: AisTransceiver,
+    pub name: LauString<'a>,
+}
+
+impl<'a> Pgn<'a> for AisAtonReport<'a> {
+    const PGN: u32 = 129041;
+    const PRIORITY: Priority = Priority::Priority4;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let position = AisPosition::decode(&mut r)?;
+        let length = r.ufix(16, 0.1)?;
+        let beam = r.ufix(16, 0.1)?;
+        let position_reference_from_starboard = r.ufix(16, 0.1)?;
+        let position_reference_from_true_north = r.ufix(16, 0.1)?;
+        let aton_type = r.lookup(5)?;
+        let off_position = r.bits(1)? == 1;
+        let virtual_aton = r.bits(1)? == 1;
+        let assigned_mode = r.bits(1)? == 1;
+        r.reserved(1)?; // AIS spare
+        let position_fixing_device = r.lookup(4)?;
+        r.reserved(3)?;
+        let aton_status = r.u8()?;
+        let transceiver = r.lookup(5)?;
+        r.reserved(3)?;
+        Ok(AisAtonReport {
+            position,
+            length,
+            beam,
+            position_reference_from_starboard,
+            position_reference_from_true_north,
+            aton_type,
+            off_position,
+            virtual_aton,
+            assigned_mode,
+            position_fixing_device,
+            aton_status,
+            transceiver,
+            name: r.string_lau()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        self.position.encode(&mut w)?;
+        w.ufix(16, 0.1, self.length)?;
+        w.ufix(16, 0.1, self.beam)?;
+        w.ufix(16, 0.1, self.position_reference_from_starboard)?;
+        w.ufix(16, 0.1, self.position_reference_from_true_north)?;
+        w.lookup(5, self.aton_type)?;
+        w.bits(1, self.off_position as u64)?;
+        w.bits(1, self.virtual_aton as u64)?;
+        w.bits(1, self.assigned_mode as u64)?;
+        w.bits(1, 0)?; // AIS spare
+        w.lookup(4, self.position_fixing_device)?;
+        w.reserved(3)?;
+        w.u8(self.aton_status)?;
+        w.lookup(5, self.transceiver)?;
+        w.reserved(3)?;
+        w.string_lau(self.name)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 129793 - AIS UTC and Date Report
+#[derive(Clone, Debug, PartialEq)]
+pub struct AisUtcDateReport {
+    pub message_id: u8,
+    pub repeat_indicator: RepeatIndicator,
+    pub mmsi: u32,
+    /// Degrees
+    pub longitude: Option<f64>,
+    /// Degrees
+    pub latitude: Option<f64>,
+    pub position_accuracy: PositionAccuracy,
+    pub raim: RaimFlag,
+    /// Seconds since midnight
+    pub time: Option<f64>,
+    pub communication_state: u32,
+    pub transceiver: AisTransceiver,
+    /// Days since 1970-01-01
+    pub date: Option<u16>,
+    pub gnss_type: GnssType,
+    pub sequence_id: u8,
+}
+
+impl<'a> Pgn<'a> for AisUtcDateReport {
+    const PGN: u32 = 129793;
+    const PRIORITY: Priority = Priority::Priority7;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let message_id = r.bits(6)? as u8;
+        let repeat_indicator = r.lookup(2)?;
+        let mmsi = r.bits(32)? as u32;
+        let longitude = r.sfix64(32, 1e-7)?;
+        let latitude = r.sfix64(32, 1e-7)?;
+        let position_accuracy = r.lookup(1)?;
+        let raim = r.lookup(1)?;
+        r.reserved(6)?;
+        Ok(AisUtcDateReport {
+            message_id,
+            repeat_indicator,
+            mmsi,
+            longitude,
+            latitude,
+            position_accuracy,
+            raim,
+            time: r.ufix64(32, 0.0001)?,
+            communication_state: r.bits(19)? as u32,
+            transceiver: r.lookup(5)?,
+            date: r.uint(16)?.map(|v| v as u16),
+            gnss_type: {
+                r.reserved(4)?;
+                let gnss_type = r.lookup(4)?;
+                r.reserved(10)?; // AIS spare
+                r.reserved(6)?;
+                gnss_type
+            },
+            sequence_id: r.u8()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.bits(6, self.message_id as u64)?;
+        w.lookup(2, self.repeat_indicator)?;
+        w.bits(32, self.mmsi as u64)?;
+        w.sfix64(32, 1e-7, self.longitude)?;
+        w.sfix64(32, 1e-7, self.latitude)?;
+        w.lookup(1, self.position_accuracy)?;
+        w.lookup(1, self.raim)?;
+        w.reserved(6)?;
+        w.ufix64(32, 0.0001, self.time)?;
+        w.bits(19, self.communication_state as u64)?;
+        w.lookup(5, self.transceiver)?;
+        w.uint(16, self.date.map(|v| v as u64))?;
+        w.reserved(4)?;
+        w.lookup(4, self.gnss_type)?;
+        w.bits(10, 0)?; // AIS spare
+        w.reserved(6)?;
+        w.u8(self.sequence_id)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 129798 - AIS SAR Aircraft Position Report
+#[derive(Clone, Debug, PartialEq)]
+pub struct AisSarAircraftPositionReport {
+    pub position: AisPosition,
+    /// Radians
+    pub cog: Option<f32>,
+    /// Metres per second
+    pub sog: Option<f32>,
+    pub communication_state: u32,
+    pub transceiver: AisTransceiver,
+    /// Metres
+    pub altitude: Option<f64>,
+    pub regional_application: u8,
+    pub dte_available: bool,
+}
+
+impl<'a> Pgn<'a> for AisSarAircraftPositionReport {
+    const PGN: u32 = 129798;
+    const PRIORITY: Priority = Priority::Priority4;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(AisSarAircraftPositionReport {
+            position: AisPosition::decode(&mut r)?,
+            cog: r.ufix(16, 0.0001)?,
+            sog: r.ufix(16, 0.1)?,
+            communication_state: r.bits(19)? as u32,
+            transceiver: r.lookup(5)?,
+            altitude: r.sfix64(32, 0.01)?,
+            regional_application: r.u8()?,
+            dte_available: {
+                let dte = r.bits(1)? == 0;
+                r.reserved(5)?; // AIS spare
+                r.reserved(2)?;
+                dte
+            },
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        self.position.encode(&mut w)?;
+        w.ufix(16, 0.0001, self.cog)?;
+        w.ufix(16, 0.1, self.sog)?;
+        w.bits(19, self.communication_state as u64)?;
+        w.lookup(5, self.transceiver)?;
+        w.sfix64(32, 0.01, self.altitude)?;
+        w.u8(self.regional_application)?;
+        w.bits(1, !self.dte_available as u64)?;
+        w.bits(5, 0)?; // AIS spare
+        w.reserved(2)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 129801 - AIS Addressed Safety Related Message
+#[derive(Clone, Debug, PartialEq)]
+pub struct AisAddressedSafetyMessage<'a> {
+    pub message_id: u8,
+    pub repeat_indicator: RepeatIndicator,
+    pub source_mmsi: u32,
+    pub transceiver: AisTransceiver,
+    pub sequence_number: u8,
+    pub destination_mmsi: u32,
+    pub retransmitted: bool,
+    pub text: LauString<'a>,
+}
+
+impl<'a> Pgn<'a> for AisAddressedSafetyMessage<'a> {
+    const PGN: u32 = 129801;
+    const PRIORITY: Priority = Priority::Priority5;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let message_id = r.bits(6)? as u8;
+        let repeat_indicator = r.lookup(2)?;
+        let source_mmsi = r.bits(32)? as u32;
+        r.reserved(1)?;
+        let transceiver = r.lookup(5)?;
+        let sequence_number = r.bits(2)? as u8;
+        let destination_mmsi = r.bits(32)? as u32;
+        r.reserved(6)?;
+        let retransmitted = r.bits(1)? == 1;
+        r.reserved(1)?; // AIS spare
+        Ok(AisAddressedSafetyMessage {
+            message_id,
+            repeat_indicator,
+            source_mmsi,
+            transceiver,
+            sequence_number,
+            destination_mmsi,
+            retransmitted,
+            text: r.string_lau()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.bits(6, self.message_id as u64)?;
+        w.lookup(2, self.repeat_indicator)?;
+        w.bits(32, self.source_mmsi as u64)?;
+        w.reserved(1)?;
+        w.lookup(5, self.transceiver)?;
+        w.bits(2, self.sequence_number as u64)?;
+        w.bits(32, self.destination_mmsi as u64)?;
+        w.reserved(6)?;
+        w.bits(1, self.retransmitted as u64)?;
+        w.bits(1, 0)?; // AIS spare
+        w.string_lau(self.text)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 129802 - AIS Safety Related Broadcast Message
+#[derive(Clone, Debug, PartialEq)]
+pub struct AisSafetyBroadcastMessage<'a> {
+    pub message_id: u8,
+    pub repeat_indicator: RepeatIndicator,
+    pub source_mmsi: u32,
+    pub transceiver: AisTransceiver,
+    pub text: LauString<'a>,
+}
+
+impl<'a> Pgn<'a> for AisSafetyBroadcastMessage<'a> {
+    const PGN: u32 = 129802;
+    const PRIORITY: Priority = Priority::Priority5;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let message_id = r.bits(6)? as u8;
+        let repeat_indicator = r.lookup(2)?;
+        let source_mmsi = r.bits(32)? as u32;
+        r.reserved(1)?;
+        let transceiver = r.lookup(5)?;
+        r.reserved(2)?; // AIS spare
+        Ok(AisSafetyBroadcastMessage {
+            message_id,
+            repeat_indicator,
+            source_mmsi,
+            transceiver,
+            text: r.string_lau()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.bits(6, self.message_id as u64)?;
+        w.lookup(2, self.repeat_indicator)?;
+        w.bits(32, self.source_mmsi as u64)?;
+        w.reserved(1)?;
+        w.lookup(5, self.transceiver)?;
+        w.bits(2, 0)?; // AIS spare
+        w.string_lau(self.text)?;
+        Ok(w.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pgns::{
+        AisAddressedSafetyMessage, AisAtonReport, AisBand, AisClassAPositionReport,
+        AisClassAStaticData, AisClassBExtendedPositionReport, AisClassBPositionReport,
+        AisClassBStaticDataPartA, AisClassBStaticDataPartB, AisCommunicationState, AisMode,
+        AisPosition, AisSafetyBroadcastMessage, AisSarAircraftPositionReport, AisTransceiver,
+        AisUnitType, AisUtcDateReport, AisVersion, AtonType, GnssType, NavStatus, Pgn, PgnError,
+        PositionAccuracy, RaimFlag, RepeatIndicator, ShipType, SpecialManeuver, YesNo,
+    };
+
+    #[test]
+    fn ais_class_a_position_report_decode() {
+        let data = [
+            0x01, // message id 1, initial
+            0x15, 0xcd, 0x5b, 0x07, // mmsi 123456789
+            0x00, 0xb4, 0xc4, 0x04, // longitude 8.0
+            0x80, 0x1d, 0x2c, 0x04, // latitude 7.0
+            0x7f, // high accuracy, raim in use, time stamp 31
+            0x10, 0x27, // cog 1.0
+            0xf4, 0x01, // sog 5.0
+            0x00, 0x00, 0x08, // communication state 0, channel B reception
+            0x20, 0x4e, // heading 2.0
+            0x40, 0xff, // rate of turn -0.006
+            0xc8, // under way sailing
+            0xf8, 0x00,
+        ];
+        let report = AisClassAPositionReport::decode(&data).unwrap();
+        assert_eq!(report.position.message_id, 1);
+        assert_eq!(report.position.repeat_indicator, RepeatIndicator::Initial);
+        assert_eq!(report.position.mmsi, 123456789);
+        assert_eq!(report.position.longitude, Some(8.0));
+        assert_eq!(report.position.latitude, Some(7.0));
+        assert_eq!(report.position.position_accuracy, PositionAccuracy::High);
+        assert_eq!(report.position.raim, RaimFlag::InUse);
+        assert_eq!(report.position.time_stamp, 31);
+        assert_eq!(report.cog, Some(1.0));
+        assert_eq!(report.sog, Some(5.0));
+        assert_eq!(report.transceiver, AisTransceiver::ChannelBVdlReception);
+        assert_eq!(report.heading, Some(2.0));
+        assert!((report.rate_of_turn.unwrap() + 0.006).abs() < 1e-6);
+        assert_eq!(report.nav_status, NavStatus::UnderWaySailing);
+
+        let mut buf = [0; 28];
+        assert_eq!(report.encode(&mut buf).unwrap(), 28);
+        assert_eq!(buf, data);
+    }
+
+    #[test]
+    fn ais_class_b_position_reports_roundtrip() {
+        let data = [
+            0x12, 0x15, 0xcd, 0x5b, 0x07, 0xff, 0xff, 0xff, 0x7f, 0xff, 0xff, 0xff, 0x7f, 0xf0,
+            0xff, 0xff, 0xff, 0xff, 0x00, 0x00, 0x10, 0xff, 0xff, 0x00, 0x7c, 0xff, 0xff,
+        ];
+        let report = AisClassBPositionReport::decode(&data).unwrap();
+        assert_eq!(report.position.message_id, 18);
+        assert_eq!(report.position.longitude, None);
+        assert_eq!(report.position.time_stamp, 60);
+        assert_eq!(report.transceiver, AisTransceiver::ChannelAVdlTransmission);
+        let mut buf = [0; 27];
+        assert_eq!(report.encode(&mut buf).unwrap(), 27);
+        assert_eq!(buf, data);
+
+        let extended = AisClassBExtendedPositionReport {
+            position: report.position.clone(),
+            cog: None,
+            sog: Some(2.5),
+            regional_application: 0,
+            regional_application_b: 0,
+            ship_type: ShipType::Sailing,
+            true_heading: None,
+            gnss_type: GnssType::Gps,
+            length: Some(12.5),
+            beam: Some(4.2),
+            position_reference_from_starboard: Some(2.1),
+            position_reference_from_bow: Some(6.0),
+            name: "SEVEN SEAS",
+            dte_available: true,
+            ais_mode: AisMode::Autonomous,
+            transceiver: AisTransceiver::ChannelAVdlReception,
+        };
+        let mut buf = [0; 64];
+        let len = extended.encode(&mut buf).unwrap();
+        assert_eq!(len, 54);
+        assert_eq!(&buf[32..42], b"SEVEN SEAS");
+        assert_eq!(
+            AisClassBExtendedPositionReport::decode(&buf[..len]).unwrap(),
+            extended
+        );
+    }
+
+    // The layouts of 129038 and 129040 below are written field by field
+    // from canboat's definitions rather than by the encoder under test.
+    #[test]
+    fn ais_class_a_position_report_canboat_layout() {
+        let data = [
+            0x01, 0x20, 0x37, 0x95, 0x0e, 0xf0, 0x7b, 0x3b, 0x03, 0xe8, 0xc8, 0xb1, 0x1f, 0xa9,
+            0x85, 0x3b, 0x01, 0x01, 0xc1, 0xa4, 0x02, 0xc5, 0x40, 0x00, 0x00, 0xd0, 0xf8, 0x00,
+        ];
+        let report = AisClassAPositionReport::decode(&data).unwrap();
+        assert_eq!(report.position.message_id, 1);
+        assert_eq!(report.position.repeat_indicator, RepeatIndicator::Initial);
+        assert_eq!(report.position.mmsi, 244660000);
+        assert!((report.position.longitude.unwrap() - 5.423).abs() < 1e-7);
+        assert!((report.position.latitude.unwrap() - 53.1745).abs() < 1e-7);
+        assert_eq!(report.position.position_accuracy, PositionAccuracy::High);
+        assert_eq!(report.position.raim, RaimFlag::NotInUse);
+        assert_eq!(report.position.time_stamp, 42);
+        assert!((report.cog.unwrap() - 1.5237).abs() < 1e-6);
+        assert!((report.sog.unwrap() - 2.57).abs() < 1e-6);
+        assert_eq!(report.communication_state, 0x2a4c1);
+        assert_eq!(report.transceiver, AisTransceiver::ChannelAVdlReception);
+        assert!((report.heading.unwrap() - 1.6581).abs() < 1e-6);
+        assert_eq!(report.rate_of_turn, Some(0.0));
+        assert_eq!(report.nav_status, NavStatus::UnderWayUsingEngine);
+        assert_eq!(report.special_maneuver, SpecialManeuver::NotEngaged);
+        assert_eq!(report.sequence_id, 0);
+
+        let mut buf = [0; 28];
+        assert_eq!(report.encode(&mut buf).unwrap(), 28);
+        assert_eq!(buf, data);
+    }
+
+    #[test]
+    fn ais_class_b_position_report_canboat_frame() {
+        // from canboat's decoder tests; the sender left off the sequence ID
+        let frame = [
+            0x12, 0x10, 0xad, 0x8c, 0x0e, 0xb2, 0x33, 0x2c, 0x03, 0xe6, 0x96, 0x6d, 0x1f, 0x3e,
+            0xb8, 0x65, 0x71, 0x00, 0xc0, 0x00, 0xc1, 0xff, 0x7f, 0x00, 0xe2, 0xff,
+        ];
+        assert_eq!(
+            AisClassBPositionReport::decode(&frame),
+            Err(PgnError::DataTooShort)
+        );
+        let mut data = [0x05; 27];
+        data[..26].copy_from_slice(&frame);
+        let report = AisClassBPositionReport::decode(&data).unwrap();
+        assert_eq!(report.position.message_id, 18);
+        assert_eq!(report.position.repeat_indicator, RepeatIndicator::Initial);
+        assert_eq!(report.position.mmsi, 244100368);
+        assert!((report.position.longitude.unwrap() - 5.3228466).abs() < 1e-7);
+        assert!((report.position.latitude.unwrap() - 52.727575).abs() < 1e-7);
+        assert_eq!(report.position.position_accuracy, PositionAccuracy::Low);
+        assert_eq!(report.position.raim, RaimFlag::InUse);
+        assert_eq!(report.position.time_stamp, 15);
+        assert!((report.cog.unwrap() - 2.604).abs() < 1e-6);
+        assert!((report.sog.unwrap() - 1.13).abs() < 1e-6);
+        assert_eq!(report.communication_state, 0x100c0);
+        assert_eq!(report.transceiver, AisTransceiver::Unknown(24));
+        // 0x7fff, beyond a full turn
+        assert!((report.heading.unwrap() - 3.2767).abs() < 1e-6);
+        assert_eq!(report.regional_application, 0);
+        assert_eq!(report.regional_application_b, 2);
+        assert_eq!(report.unit_type, AisUnitType::Sotdma);
+        assert_eq!(report.integrated_display, YesNo::No);
+        assert_eq!(report.dsc, YesNo::No);
+        assert_eq!(report.band, AisBand::EntireMarineBand);
+        assert_eq!(report.can_handle_msg_22, YesNo::Yes);
+        assert_eq!(report.ais_mode, AisMode::Assigned);
+        assert_eq!(report.ais_communication_state, AisCommunicationState::Itdma);
+        assert_eq!(report.sequence_id, 5);
+
+        let mut buf = [0; 27];
+        assert_eq!(report.encode(&mut buf).unwrap(), 27);
+        assert_eq!(buf, data);
+    }
+
+    #[test]
+    fn ais_class_b_extended_position_report_canboat_layout() {
+        let data = [
+            0x13, 0x40, 0x07, 0x8d, 0x0e, 0x00, 0x18, 0xea, 0x02, 0x98, 0x6a, 0x38, 0x1f, 0x44,
+            0x0f, 0x90, 0x9f, 0x00, 0x00, 0xf0, 0x24, 0x7d, 0x8e, 0x1f, 0x7d, 0x00, 0x2a, 0x00,
+            0x15, 0x00, 0x3c, 0x00, b'S', b'E', b'V', b'E', b'N', b' ', b'S', b'E', b'A', b'S',
+            b'@', b'@', b'@', b'@', b'@', b'@', b'@', b'@', b'@', b'@', 0x41, 0xf8,
+        ];
+        let report = AisClassBExtendedPositionReport::decode(&data).unwrap();
+        assert_eq!(report.position.message_id, 19);
+        assert_eq!(report.position.repeat_indicator, RepeatIndicator::Initial);
+        assert_eq!(report.position.mmsi, 244123456);
+        assert!((report.position.longitude.unwrap() - 4.8896).abs() < 1e-7);
+        assert!((report.position.latitude.unwrap() - 52.3791).abs() < 1e-7);
+        assert_eq!(report.position.position_accuracy, PositionAccuracy::Low);
+        assert_eq!(report.position.raim, RaimFlag::NotInUse);
+        assert_eq!(report.position.time_stamp, 17);
+        assert!((report.cog.unwrap() - 3.6879).abs() < 1e-6);
+        assert!((report.sog.unwrap() - 1.59).abs() < 1e-6);
+        assert_eq!(report.regional_application, 0);
+        assert_eq!(report.regional_application_b, 0);
+        assert_eq!(report.ship_type, ShipType::Sailing);
+        assert!((report.true_heading.unwrap() - 3.6477).abs() < 1e-6);
+        assert_eq!(report.gnss_type, GnssType::Gps);
+        assert!((report.length.unwrap() - 12.5).abs() < 1e-6);
+        assert!((report.beam.unwrap() - 4.2).abs() < 1e-6);
+        assert!((report.position_reference_from_starboard.unwrap() - 2.1).abs() < 1e-6);
+        assert!((report.position_reference_from_bow.unwrap() - 6.0).abs() < 1e-6);
+        assert_eq!(report.name, "SEVEN SEAS");
+        assert!(!report.dte_available);
+        assert_eq!(report.ais_mode, AisMode::Autonomous);
+        assert_eq!(report.transceiver, AisTransceiver::ChannelBVdlReception);
+
+        let mut buf = [0; 54];
+        assert_eq!(report.encode(&mut buf).unwrap(), 54);
+        assert_eq!(&buf[..], &data[..]);
+    }
+
+    #[test]
+    fn ais_class_a_static_data_decode() {
+        let mut data = [0u8; 76];
+        data[0] = 0x05; // message id 5, initial
+        data[1..5].copy_from_slice(&123456789u32.to_le_bytes());
+        data[5..9].copy_from_slice(&9074729u32.to_le_bytes());
+        data[9..16].copy_from_slice(b"PBCD@@@");
+        data[16..36].copy_from_slice(b"NORTHERN STAR@@@@@@@");
+        data[36] = 70; // cargo
+        data[37..39].copy_from_slice(&1825u16.to_le_bytes());
+        data[39..41].copy_from_slice(&300u16.to_le_bytes());
+        data[41..43].copy_from_slice(&120u16.to_le_bytes());
+        data[43..45].copy_from_slice(&1500u16.to_le_bytes());
+        data[45..47].copy_from_slice(&19500u16.to_le_bytes());
+        data[47..51].copy_from_slice(&432_000_000u32.to_le_bytes());
+        data[51..53].copy_from_slice(&1150u16.to_le_bytes());
+        data[53..73].copy_from_slice(b"ROTTERDAM           ");
+        data[73] = 0x02 | 0x02 << 2 | 0xc0; // ITU-R M.1371-5, GLONASS, no DTE
+        data[74] = 0xe0;
+        data[75] = 7;
+
+        let report = AisClassAStaticData::decode(&data).unwrap();
+        assert_eq!(report.message_id, 5);
+        assert_eq!(report.mmsi, 123456789);
+        assert_eq!(report.imo_number, Some(9074729));
+        assert_eq!(report.callsign, "PBCD");
+        assert_eq!(report.name, "NORTHERN STAR");
+        assert_eq!(report.ship_type, ShipType::Cargo);
+        assert_eq!(report.length, Some(182.5));
+        assert_eq!(report.beam, Some(30.0));
+        assert_eq!(report.eta_date, Some(19500));
+        assert_eq!(report.eta_time, Some(43200.0));
+        assert_eq!(report.draft, Some(11.5));
+        assert_eq!(report.destination, "ROTTERDAM");
+        assert_eq!(report.ais_version, AisVersion::ItuRM1371_5);
+        assert_eq!(report.gnss_type, GnssType::Glonass);
+        assert!(!report.dte_available);
+        assert_eq!(report.transceiver, AisTransceiver::ChannelAVdlReception);
+        assert_eq!(report.sequence_id, 7);
+
+        let mut buf = [0; 76];
+        assert_eq!(report.encode(&mut buf).unwrap(), 76);
+        assert_eq!(&buf[..53], &data[..53]);
+        assert_eq!(&buf[53..62], b"ROTTERDAM");
+        assert_eq!(AisClassAStaticData::decode(&buf).unwrap(), report);
+    }
+
+    #[test]
+    fn ais_class_b_static_data_roundtrip() {
+        let part_a = AisClassBStaticDataPartA {
+            message_id: 24,
+            repeat_indicator: RepeatIndicator::Initial,
+            mmsi: 244123456,
+            name: "SEVEN SEAS",
+            transceiver: AisTransceiver::ChannelBVdlReception,
+            sequence_id: 3,
+        };
+        let mut buf = [0; 27];
+        assert_eq!(part_a.encode(&mut buf).unwrap(), 27);
+        assert_eq!(&buf[5..25], b"SEVEN SEAS@@@@@@@@@@");
+        assert_eq!(buf[25], 0xe1);
+        assert_eq!(AisClassBStaticDataPartA::decode(&buf).unwrap(), part_a);
+
+        let part_b = AisClassBStaticDataPartB {
+            message_id: 24,
+            repeat_indicator: RepeatIndicator::Initial,
+            mmsi: 244123456,
+            ship_type: ShipType::Sailing,
+            vendor_id: "ACME",
+            callsign: "PD1234",
+            length: Some(12.5),
+            beam: Some(4.0),
+            position_reference_from_starboard: Some(2.0),
+            position_reference_from_bow: Some(5.0),
+            mothership_mmsi: 0,
+            gnss_type: GnssType::Gps,
+            transceiver: AisTransceiver::ChannelBVdlReception,
+            sequence_id: 3,
+        };
+        let mut buf = [0; 35];
+        assert_eq!(part_b.encode(&mut buf).unwrap(), 35);
+        assert_eq!(buf[5], 36);
+        assert_eq!(&buf[6..20], b"ACME@@@PD1234@");
+        assert_eq!(buf[32], 0x13);
+        assert_eq!(AisClassBStaticDataPartB::decode(&buf).unwrap(), part_b);
+    }
+
+    #[test]
+    fn ais_aton_report_decode() {
+        let mut data = [0xffu8; 35];
+        data[0] = 0x15; // message id 21, initial
+        data[1..5].copy_from_slice(&992441234u32.to_le_bytes());
+        data[5..9].copy_from_slice(&43_000_000i32.to_le_bytes());
+        data[9..13].copy_from_slice(&519_000_000i32.to_le_bytes());
+        data[13] = 0x7d; // high accuracy, raim not in use, time stamp 31
+        data[22] = 24 | 0x40; // floating port hand mark, virtual
+        data[23] = 0xe2; // gps
+        data[24] = 0x00;
+        data[25] = 0xe0;
+        data[26] = 9;
+        data[27] = 1;
+        data[28..35].copy_from_slice(b"BUOY 12");
+
+        let report = AisAtonReport::decode(&data).unwrap();
+        assert_eq!(report.position.message_id, 21);
+        assert_eq!(report.position.mmsi, 992441234);
+        assert_eq!(report.position.longitude, Some(4.3));
+        assert_eq!(report.position.latitude, Some(51.9));
+        assert_eq!(report.position.raim, RaimFlag::NotInUse);
+        assert_eq!(report.length, None);
+        assert_eq!(report.aton_type, AtonType::FloatingPortHandMark);
+        assert!(!report.off_position);
+        assert!(report.virtual_aton);
+        assert!(!report.assigned_mode);
+        assert_eq!(report.position_fixing_device, GnssType::Gps);
+        assert_eq!(report.aton_status, 0);
+        assert_eq!(report.transceiver, AisTransceiver::ChannelAVdlReception);
+        assert_eq!(report.name, "BUOY 12");
+
+        let mut buf = [0; 64];
+        assert_eq!(report.encode(&mut buf).unwrap(), 35);
+        assert_eq!(&buf[..35], &data);
+    }
+
+    #[test]
+    fn ais_special_reports_roundtrip() {
+        let utc = AisUtcDateReport {
+            message_id: 4,
+            repeat_indicator: RepeatIndicator::Initial,
+            mmsi: 2442000,
+            longitude: Some(4.5),
+            latitude: Some(52.0),
+            position_accuracy: PositionAccuracy::High,
+            raim: RaimFlag::NotInUse,
+            time: Some(45296.0),
+            communication_state: 0,
+            transceiver: AisTransceiver::ChannelBVdlReception,
+            date: Some(19500),
+            gnss_type: GnssType::Surveyed,
+            sequence_id: 2,
+        };
+        let mut buf = [0; 32];
+        assert_eq!(utc.encode(&mut buf).unwrap(), 27);
+        assert_eq!(
+            &buf[..27],
+            &[
+                0x04, 0x10, 0x43, 0x25, 0x00, 0x40, 0xa5, 0xae, 0x02, 0x00, 0x92, 0xfe, 0x1e, 0xfd,
+                0x00, 0x9f, 0xff, 0x1a, 0x00, 0x00, 0x08, 0x2c, 0x4c, 0x7f, 0x00, 0xfc, 0x02,
+            ]
+        );
+        assert_eq!(AisUtcDateReport::decode(&buf[..27]).unwrap(), utc);
+
+        let sar = AisSarAircraftPositionReport {
+            position: AisPosition {
+                message_id: 9,
+                repeat_indicator: RepeatIndicator::Initial,
+                mmsi: 111244001,
+                longitude: Some(4.5),
+                latitude: Some(52.0),
+                position_accuracy: PositionAccuracy::Low,
+                raim: RaimFlag::NotInUse,
+                time_stamp: 12,
+            },
+            cog: Some(1.5),
+            sog: Some(60.0),
+            communication_state: 0,
+            transceiver: AisTransceiver::ChannelAVdlReception,
+            altitude: Some(300.0),
+            regional_application: 0,
+            dte_available: true,
+        };
+        let mut buf = [0; 32];
+        assert_eq!(sar.encode(&mut buf).unwrap(), 27);
+        assert_eq!(&buf[21..25], &30_000i32.to_le_bytes());
+        assert_eq!(
+            AisSarAircraftPositionReport::decode(&buf[..27]).unwrap(),
+            sar
+        );
+    }
+
+    #[test]
+    fn ais_sar_aircraft_position_report_canboat_layout() {
+        let data = [
+            0x09, 0xe1, 0x72, 0xa1, 0x06, 0x40, 0xa5, 0xae, 0x02, 0x00, 0x92, 0xfe, 0x1e, 0x30,
+            0x98, 0x3a, 0x58, 0x02, 0x00, 0x00, 0x00, 0x30, 0x75, 0x00, 0x00, 0x00, 0xc0,
+        ];
+        let report = AisSarAircraftPositionReport::decode(&data).unwrap();
+        assert_eq!(report.position.message_id, 9);
+        assert_eq!(report.position.mmsi, 111244001);
+        assert_eq!(report.position.longitude, Some(4.5));
+        assert_eq!(report.position.latitude, Some(52.0));
+        assert_eq!(report.position.time_stamp, 12);
+        assert!((report.cog.unwrap() - 1.5).abs() < 1e-6);
+        assert!((report.sog.unwrap() - 60.0).abs() < 1e-6);
+        assert_eq!(report.transceiver, AisTransceiver::ChannelAVdlReception);
+        assert_eq!(report.altitude, Some(300.0));
+        assert_eq!(report.regional_application, 0);
+        assert!(report.dte_available);
+
+        let mut buf = [0; 27];
+        assert_eq!(report.encode(&mut buf).unwrap(), 27);
+        assert_eq!(buf, data);
+    }
+
+    #[test]
+    fn ais_safety_messages_roundtrip() {
+        let addressed = AisAddressedSafetyMessage {
+            message_id: 12,
+            repeat_indicator: RepeatIndicator::Initial,
+            source_mmsi: 244123456,
+            transceiver: AisTransceiver::ChannelAVdlTransmission,
+            sequence_number: 1,
+            destination_mmsi: 244654321,
+            retransmitted: false,
+            text: "KEEP CLEAR".into(),
+        };
+        let mut buf = [0; 64];
+        let len = addressed.encode(&mut buf).unwrap();
+        assert_eq!(len, 23);
+        assert_eq!(buf[5], 0x45);
+        assert_eq!(&buf[13..23], b"KEEP CLEAR");
+        assert_eq!(
+            AisAddressedSafetyMessage::decode(&buf[..len]).unwrap(),
+            addressed
+        );
+
+        let broadcast = AisSafetyBroadcastMessage {
+            message_id: 14,
+            repeat_indicator: RepeatIndicator::Initial,
+            source_mmsi: 244123456,
+            transceiver: AisTransceiver::ChannelBVdlReception,
+            text: "SART ACTIVE".into(),
+        };
+        let len = broadcast.encode(&mut buf).unwrap();
+        assert_eq!(len, 19);
+        assert_eq!(
+            AisSafetyBroadcastMessage::decode(&buf[..len]).unwrap(),
+            broadcast
+        );
+    }
+}
@@ -0,0 +1,692 @@
+use heapless::Vec;
+
+use crate::pgns::field::{Reader, Writer};
+use crate::pgns::{LauString, Pgn, PgnError, Result, YesNo};
+use crate::Priority;
+
+lookup! {
+    pub enum AlertType: u8 {
+        EmergencyAlarm = 1 => "Emergency Alarm",
+        Alarm = 2,
+        Warning = 5,
+        Caution = 8,
+    }
+}
+
+lookup! {
+    pub enum AlertCategory: u8 {
+        Navigational = 0,
+        Technical = 1,
+    }
+}
+
+lookup! {
+    pub enum AlertTriggerCondition: u8 {
+        Manual = 0,
+        Auto = 1,
+        Test = 2,
+        Disabled = 3,
+    }
+}
+
+lookup! {
+    pub enum AlertThresholdStatus: u8 {
+        Normal = 0,
+        ThresholdExceeded = 1 => "Threshold Exceeded",
+        ExtremeThresholdExceeded = 2 => "Extreme Threshold Exceeded",
+        LowThresholdExceeded = 3 => "Low Threshold Exceeded",
+        Acknowledged = 4,
+        AwaitingAcknowledge = 5 => "Awaiting Acknowledge",
+    }
+}
+
+lookup! {
+    pub enum AlertState: u8 {
+        Disabled = 0,
+        Normal = 1,
+        Active = 2,
+        Silenced = 3,
+        Acknowledged = 4,
+        AwaitingAcknowledge = 5 => "Awaiting Acknowledge",
+    }
+}
+
+lookup! {
+    pub enum AlertResponseCommand: u8 {
+        Acknowledge = 0,
+        TemporarySilence = 1 => "Temporary Silence",
+        TestCommandOff = 2 => "Test Command off",
+        TestCommandOn = 3 => "Test Command on",
+    }
+}
+
+lookup! {
+    pub enum AlertLanguage: u8 {
+        EnglishUs = 0 => "English (US)",
+        EnglishUk = 1 => "English (UK)",
+        Arabic = 2,
+        ChineseSimplified = 3 => "Chinese (simplified)",
+        Croatian = 4,
+        Danish = 5,
+        Dutch = 6,
+        Finnish = 7,
+        French = 8,
+        German = 9,
+        Greek = 10,
+        Italian = 11,
+        Japanese = 12,
+        Korean = 13,
+        Norwegian = 14,
+        Polish = 15,
+        Portuguese = 16,
+        Russian = 17,
+        Spanish = 18,
+        Swedish = 19,
+    }
+}
+
+/// The fields identifying an alert, shared by the alert parameter groups.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlertHeader {
+    pub alert_type: AlertType,
+    pub category: AlertCategory,
+    pub system: u8,
+    pub sub_system: u8,
+    pub id: u16,
+    /// NAME of the device that raised the alert
+    pub data_source_name: u64,
+    pub data_source_instance: u8,
+    pub data_source_index: u8,
+    pub occurrence: u8,
+}
+
+impl AlertHeader {
+    fn decode(r: &mut Reader) -> Result<Self> {
+        Ok(AlertHeader {
+            alert_type: r.lookup(4)?,
+            category: r.lookup(4)?,
+            system: r.u8()?,
+            sub_system: r.u8()?,
+            id: r.bits(16)? as u16,
+            data_source_name: r.bits(64)?,
+            data_source_instance: r.u8()?,
+            data_source_index: r.u8()?,
+            occurrence: r.u8()?,
+        })
+    }
+
+    fn encode(&self, w: &mut Writer) -> Result<()> {
+        w.lookup(4, self.alert_type)?;
+        w.lookup(4, self.category)?;
+        w.u8(self.system)?;
+        w.u8(self.sub_system)?;
+        w.bits(16, self.id as u64)?;
+        w.bits(64, self.data_source_name)?;
+        w.u8(self.data_source_instance)?;
+        w.u8(self.data_source_index)?;
+        w.u8(self.occurrence)
+    }
+}
+
+/// PGN 126983 - Alert
+#[derive(Clone, Debug, PartialEq)]
+pub struct Alert {
+    pub header: AlertHeader,
+    pub temporary_silence: YesNo,
+    pub acknowledged: YesNo,
+    pub escalated: YesNo,
+    pub temporary_silence_support: YesNo,
+    pub acknowledge_support: YesNo,
+    pub escalation_support: YesNo,
+    /// NAME of the device that acknowledged the alert
+    pub acknowledge_source_name: u64,
+    pub trigger_condition: AlertTriggerCondition,
+    pub threshold_status: AlertThresholdStatus,
+    pub priority: u8,
+    pub state: AlertState,
+}
+
+impl<'a> Pgn<'a> for Alert {
+    const PGN: u32 = 126983;
+    const PRIORITY: Priority = Priority::Priority2;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let header = AlertHeader::decode(&mut r)?;
+        let temporary_silence = r.lookup(1)?;
+        let acknowledged = r.lookup(1)?;
+        let escalated = r.lookup(1)?;
+        let temporary_silence_support = r.lookup(1)?;
+        let acknowledge_support = r.lookup(1)?;
+        let escalation_support = r.lookup(1)?;
+        r.reserved(2)?;
+        Ok(Alert {
+            header,
+            temporary_silence,
+            acknowledged,
+            escalated,
+            temporary_silence_support,
+            acknowledge_support,
+            escalation_support,
+            acknowledge_source_name: r.bits(64)?,
+            trigger_condition: r.lookup(4)?,
+            threshold_status: r.lookup(4)?,
+            priority: r.u8()?,
+            state: r.lookup(8)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        self.header.encode(&mut w)?;
+        w.lookup(1, self.temporary_silence)?;
+        w.lookup(1, self.acknowledged)?;
+        w.lookup(1, self.escalated)?;
+        w.lookup(1, self.temporary_silence_support)?;
+        w.lookup(1, self.acknowledge_support)?;
+        w.lookup(1, self.escalation_support)?;
+        w.reserved(2)?;
+        w.bits(64, self.acknowledge_source_name)?;
+        w.lookup(4, self.trigger_condition)?;
+        w.lookup(4, self.threshold_status)?;
+        w.u8(self.priority)?;
+        w.lookup(8, self.state)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 126984 - Alert Response
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlertResponse {
+    pub header: AlertHeader,
+    /// NAME of the device sending the response
+    pub acknowledge_source_name: u64,
+    pub command: AlertResponseCommand,
+}
+
+impl<'a> Pgn<'a> for AlertResponse {
+    const PGN: u32 = 126984;
+    const PRIORITY: Priority = Priority::Priority2;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(AlertResponse {
+            header: AlertHeader::decode(&mut r)?,
+            acknowledge_source_name: r.bits(64)?,
+            command: r.lookup(2)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        self.header.encode(&mut w)?;
+        w.bits(64, self.acknowledge_source_name)?;
+        w.lookup(2, self.command)?;
+        w.reserved(6)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 126985 - Alert Text
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlertText<'a> {
+    pub header: AlertHeader,
+    pub language: AlertLanguage,
+    pub description: LauString<'a>,
+    pub location: LauString<'a>,
+}
+
+impl<'a> Pgn<'a> for AlertText<'a> {
+    const PGN: u32 = 126985;
+    const PRIORITY: Priority = Priority::Priority2;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(AlertText {
+            header: AlertHeader::decode(&mut r)?,
+            language: r.lookup(8)?,
+            description: r.string_lau()?,
+            location: r.string_lau()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        self.header.encode(&mut w)?;
+        w.lookup(8, self.language)?;
+        w.string_lau(self.description)?;
+        w.string_lau(self.location)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 126986 - Alert Configuration
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlertConfiguration {
+    pub header: AlertHeader,
+    pub alert_control: u8,
+    pub user_defined_alert_assignment: u8,
+    pub reactivation_period: Option<u8>,
+    pub temporary_silence_period: Option<u8>,
+    pub escalation_period: Option<u8>,
+}
+
+impl<'a> Pgn<'a> for AlertConfiguration {
+    const PGN: u32 = 126986;
+    const PRIORITY: Priority = Priority::Priority2;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let header = AlertHeader::decode(&mut r)?;
+        let alert_control = r.bits(2)? as u8;
+        let user_defined_alert_assignment = r.bits(2)? as u8;
+        r.reserved(4)?;
+        Ok(AlertConfiguration {
+            header,
+            alert_control,
+            user_defined_alert_assignment,
+            reactivation_period: r.uint(8)?.map(|v| v as u8),
+            temporary_silence_period: r.uint(8)?.map(|v| v as u8),
+            escalation_period: r.uint(8)?.map(|v| v as u8),
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        self.header.encode(&mut w)?;
+        w.bits(2, self.alert_control as u64)?;
+        w.bits(2, self.user_defined_alert_assignment as u64)?;
+        w.reserved(4)?;
+        w.uint(8, self.reactivation_period.map(|v| v as u64))?;
+        w.uint(8, self.temporary_silence_period.map(|v| v as u64))?;
+        w.uint(8, self.escalation_period.map(|v| v as u64))?;
+        Ok(w.len())
+    }
+}
+
+/// One threshold of an alert threshold report.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlertThresholdParameter {
+    pub parameter: u8,
+    pub trigger_method: u8,
+    pub data_format: u8,
+    pub level: u64,
+}
+
+/// PGN 126987 - Alert Threshold
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlertThreshold {
+    pub header: AlertHeader,
+    pub parameters: Vec<AlertThresholdParameter, 18>,
+}
+
+impl<'a> Pgn<'a> for AlertThreshold {
+    const PGN: u32 = 126987;
+    const PRIORITY: Priority = Priority::Priority2;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let header = AlertHeader::decode(&mut r)?;
+        let count = r.u8()?;
+        let mut parameters = Vec::new();
+        for _ in 0..count {
+            let parameter = AlertThresholdParameter {
+                parameter: r.u8()?,
+                trigger_method: r.u8()?,
+                data_format: r.u8()?,
+                level: r.bits(64)?,
+            };
+            parameters
+                .push(parameter)
+                .map_err(|_| PgnError::TooManyEntries)?;
+        }
+        Ok(AlertThreshold { header, parameters })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        self.header.encode(&mut w)?;
+        w.u8(self.parameters.len() as u8)?;
+        for parameter in &self.parameters {
+            w.u8(parameter.parameter)?;
+            w.u8(parameter.trigger_method)?;
+            w.u8(parameter.data_format)?;
+            w.bits(64, parameter.level)?;
+        }
+        Ok(w.len())
+    }
+}
+
+/// One value of an alert value report.
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlertValueParameter {
+    pub parameter: u8,
+    pub data_format: u8,
+    pub value: u64,
+}
+
+/// PGN 126988 - Alert Value
+#[derive(Clone, Debug, PartialEq)]
+pub struct AlertValue {
+    pub header: AlertHeader,
+    pub parameters: Vec<AlertValueParameter, 20>,
+}
+
+impl<'a> Pgn<'a> for AlertValue {
+    const PGN: u32 = 126988;
+    const PRIORITY: Priority = Priority::Priority2;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let header = AlertHeader::decode(&mut r)?;
+        let count = r.u8()?;
+        let mut parameters = Vec::new();
+        for _ in 0..count {
+            let parameter = AlertValueParameter {
+                parameter: r.u8()?,
+                data_format: r.u8()?,
+                value: r.bits(64)?,
+            };
+            parameters
+                .push(parameter)
+                .map_err(|_| PgnError::TooManyEntries)?;
+        }
+        Ok(AlertValue { header, parameters })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        self.header.encode(&mut w)?;
+        w.u8(self.parameters.len() as u8)?;
+        for parameter in &self.parameters {
+            w.u8(parameter.parameter)?;
+            w.u8(parameter.data_format)?;
+            w.bits(64, parameter.value)?;
+        }
+        Ok(w.len())
+    }
+}
+
+/// An alert raised by a device on the bus.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TrackedAlert {
+    /// Source address of the device that raised the alert
+    pub source: u8,
+    pub header: AlertHeader,
+    pub priority: u8,
+    pub state: AlertState,
+}
+
+/// Tracks the alerts raised by the devices on the bus, up to `N` at a time.
+///
+/// The alerting device owns the state of an alert, so its PGN 126983 reports
+/// always win. Responses only move an alert ahead until the device confirms:
+/// acknowledging takes it to `Acknowledged` and silencing an unacknowledged
+/// alert takes it to `Silenced`. Alerts reported back to `Normal` or
+/// `Disabled` are forgotten.
+pub struct Alerts<const N: usize> {
+    alerts: Vec<TrackedAlert, N>,
+}
+
+impl<const N: usize> Alerts<N> {
+    pub fn new() -> Self {
+        Alerts { alerts: Vec::new() }
+    }
+
+    /// Records an alert report, returning false if a new alert could not be
+    /// tracked because `N` alerts are already active.
+    pub fn update(&mut self, source: u8, alert: &Alert) -> bool {
+        let index = self.find(source, &alert.header);
+        if matches!(alert.state, AlertState::Normal | AlertState::Disabled) {
+            if let Some(index) = index {
+                self.alerts.swap_remove(index);
+            }
+            return true;
+        }
+
+        let tracked = TrackedAlert {
+            source,
+            header: alert.header.clone(),
+            priority: alert.priority,
+            state: alert.state,
+        };
+        match index {
+            Some(index) => {
+                self.alerts[index] = tracked;
+                true
+            }
+            None => self.alerts.push(tracked).is_ok(),
+        }
+    }
+
+    /// Applies a response sent to the device at `source`, returning false if
+    /// the alert is not known.
+    pub fn respond(&mut self, source: u8, response: &AlertResponse) -> bool {
+        let index = match self.find(source, &response.header) {
+            Some(index) => index,
+            None => return false,
+        };
+        let alert = &mut self.alerts[index];
+        match response.command {
+            AlertResponseCommand::Acknowledge => alert.state = AlertState::Acknowledged,
+            AlertResponseCommand::TemporarySilence => {
+                if matches!(
+                    alert.state,
+                    AlertState::Active | AlertState::AwaitingAcknowledge
+                ) {
+                    alert.state = AlertState::Silenced;
+                }
+            }
+            _ => {}
+        }
+        true
+    }
+
+    /// Forgets all alerts of the device at `source`, for instance once it
+    /// has left the bus.
+    pub fn remove_source(&mut self, source: u8) {
+        self.alerts.retain(|a| a.source != source);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &TrackedAlert> {
+        self.alerts.iter()
+    }
+
+    /// Number of tracked alerts in `state`.
+    pub fn count(&self, state: AlertState) -> usize {
+        self.alerts.iter().filter(|a| a.state == state).count()
+    }
+
+    pub fn len(&self) -> usize {
+        self.alerts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.alerts.is_empty()
+    }
+
+    fn find(&self, source: u8, header: &AlertHeader) -> Option<usize> {
+        self.alerts
+            .iter()
+            .position(|a| a.source == source && a.header == *header)
+    }
+}
+
+impl<const N: usize> Default for Alerts<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use heapless::Vec;
+
+    use crate::pgns::{
+        Alert, AlertCategory, AlertHeader, AlertLanguage, AlertResponse, AlertResponseCommand,
+        AlertState, AlertText, AlertThreshold, AlertThresholdParameter, AlertThresholdStatus,
+        AlertTriggerCondition, AlertType, AlertValue, AlertValueParameter, Alerts, Pgn, PgnError,
+        YesNo,
+    };
+
+    fn header(id: u16) -> AlertHeader {
+        AlertHeader {
+            alert_type: AlertType::Alarm,
+            category: AlertCategory::Technical,
+            system: 5,
+            sub_system: 1,
+            id,
+            data_source_name: 0x0123_4567_89ab_cdef,
+            data_source_instance: 0,
+            data_source_index: 1,
+            occurrence: 3,
+        }
+    }
+
+    fn alert(id: u16, state: AlertState) -> Alert {
+        Alert {
+            header: header(id),
+            temporary_silence: YesNo::No,
+            acknowledged: YesNo::No,
+            escalated: YesNo::No,
+            temporary_silence_support: YesNo::Yes,
+            acknowledge_support: YesNo::Yes,
+            escalation_support: YesNo::No,
+            acknowledge_source_name: u64::MAX,
+            trigger_condition: AlertTriggerCondition::Auto,
+            threshold_status: AlertThresholdStatus::ThresholdExceeded,
+            priority: 10,
+            state,
+        }
+    }
+
+    #[test]
+    fn alert_decode() {
+        let data = [
+            0x12, // alarm, technical
+            0x05, 0x01, // system, sub-system
+            0x2a, 0x00, // alert id 42
+            0xef, 0xcd, 0xab, 0x89, 0x67, 0x45, 0x23, 0x01, // data source NAME
+            0x00, 0x01, 0x03, // instance, index, occurrence
+            0xd8, // silence and acknowledge supported
+            0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, // acknowledge source NAME
+            0x11, // auto, threshold exceeded
+            0x0a, // priority
+            0x02, // active
+        ];
+        let decoded = Alert::decode(&data).unwrap();
+        assert_eq!(decoded, alert(42, AlertState::Active));
+
+        let mut buf = [0; 28];
+        assert_eq!(decoded.encode(&mut buf).unwrap(), 28);
+        assert_eq!(buf, data);
+    }
+
+    #[test]
+    fn alert_messages_roundtrip() {
+        let mut buf = [0; 223];
+
+        let response = AlertResponse {
+            header: header(42),
+            acknowledge_source_name: 0x1122_3344_5566_7788,
+            command: AlertResponseCommand::TemporarySilence,
+        };
+        let len = response.encode(&mut buf).unwrap();
+        assert_eq!(len, 25);
+        assert_eq!(buf[24], 0xfd);
+        assert_eq!(AlertResponse::decode(&buf[..len]).unwrap(), response);
+
+        let text = AlertText {
+            header: header(42),
+            language: AlertLanguage::EnglishUk,
+            description: "High bilge level".into(),
+            location: "Engine room".into(),
+        };
+        let len = text.encode(&mut buf).unwrap();
+        assert_eq!(len, 16 + 1 + 18 + 13);
+        assert_eq!(AlertText::decode(&buf[..len]).unwrap(), text);
+
+        let mut parameters = Vec::new();
+        parameters
+            .push(AlertThresholdParameter {
+                parameter: 1,
+                trigger_method: 2,
+                data_format: 3,
+                level: 4000,
+            })
+            .unwrap();
+        let threshold = AlertThreshold {
+            header: header(42),
+            parameters,
+        };
+        let len = threshold.encode(&mut buf).unwrap();
+        assert_eq!(len, 28);
+        assert_eq!(AlertThreshold::decode(&buf[..len]).unwrap(), threshold);
+
+        // more parameters than fit in a fast packet are refused, not dropped
+        let mut data = [0; 16 + 1 + 19 * 11];
+        data[..16].copy_from_slice(&buf[..16]);
+        data[16] = 19;
+        assert_eq!(AlertThreshold::decode(&data), Err(PgnError::TooManyEntries));
+
+        let mut parameters = Vec::new();
+        for parameter in 1..=2 {
+            parameters
+                .push(AlertValueParameter {
+                    parameter,
+                    data_format: 0,
+                    value: 12,
+                })
+                .unwrap();
+        }
+        let value = AlertValue {
+            header: header(42),
+            parameters,
+        };
+        let len = value.encode(&mut buf).unwrap();
+        assert_eq!(len, 37);
+        assert_eq!(AlertValue::decode(&buf[..len]).unwrap(), value);
+    }
+
+    #[test]
+    fn alerts_track_state() {
+        let mut alerts: Alerts<2> = Alerts::new();
+        assert!(alerts.update(20, &alert(1, AlertState::Active)));
+        assert!(alerts.update(21, &alert(1, AlertState::Active)));
+        assert!(!alerts.update(20, &alert(2, AlertState::Active)));
+        assert_eq!(alerts.count(AlertState::Active), 2);
+
+        let silence = AlertResponse {
+            header: header(1),
+            acknowledge_source_name: 0,
+            command: AlertResponseCommand::TemporarySilence,
+        };
+        assert!(alerts.respond(20, &silence));
+        assert!(!alerts.respond(22, &silence));
+        assert_eq!(alerts.count(AlertState::Silenced), 1);
+
+        let acknowledge = AlertResponse {
+            command: AlertResponseCommand::Acknowledge,
+            ..silence.clone()
+        };
+        assert!(alerts.respond(21, &acknowledge));
+        assert_eq!(alerts.count(AlertState::Acknowledged), 1);
+        assert!(alerts.respond(21, &silence));
+        assert_eq!(alerts.count(AlertState::Acknowledged), 1);
+
+        // the device silenced by the response times the silence out
+        assert!(alerts.update(20, &alert(1, AlertState::Active)));
+        assert_eq!(alerts.count(AlertState::Active), 1);
+
+        assert!(alerts.update(21, &alert(1, AlertState::Normal)));
+        assert_eq!(alerts.len(), 1);
+        alerts.remove_source(20);
+        assert!(alerts.is_empty());
+    }
+}
@@ -0,0 +1,2570 @@
+use crate::pgns::{
+    is_proprietary, is_proprietary_fast_packet, AcLine, Acceptability, AisBand,
+    AisCommunicationState, AisMode, AisTransceiver, AisUnitType, AisVersion, AlertCategory,
+    AlertLanguage, AlertResponseCommand, AlertState, AlertThresholdStatus, AlertTriggerCondition,
+    AlertType, AtonType, BatteryChemistry, BatteryLevel, BatteryType, BatteryVoltage,
+    CalculationType, ChargerMode, ChargerState, ConverterState, DcType, DirectionReference,
+    DockingStatus, DscCategory, DscFormat, EngineDiscreteStatus1, EngineDiscreteStatus2,
+    EngineInstance, EntertainmentSource, EntertainmentType, EntertainmentZone, FloodState,
+    FluidType, GearStatus, GnssType, GoodWarningError, HumiditySource, IndustryGroup,
+    InverterState, LikeStatus, MarkType, MobPositionSource, MobStatus, NavStatus, OffOn, PgnError,
+    PilotMode, PlayStatus, PositionAccuracy, PowerFactorLagging, PressureSource, ProprietaryHeader,
+    RaimFlag, RepeatIndicator, RepeatStatus, ResidualMode, Result, RodeType, RouteDirection,
+    RudderDirection, SeatalkKey, ShipType, ShuffleStatus, SimnetApEvent, SimnetDeviceModel,
+    SimnetDeviceReport, SimnetKeyOperation, SimnetNetworkGroup, SpecialManeuver, SteeringMode,
+    SwitchState, TelephoneMode, TemperatureSource, ThrusterControlEvents, ThrusterDirection,
+    ThrusterMotorEvents, ThrusterMotorType, ThrusterRetract, TideTendency,
+    TransmissionDiscreteStatus, TurnMode, Waveform, WindReference, WindlassControlEvents,
+    WindlassDirection, WindlassMonitoringEvents, WindlassMotion, WindlassOperatingEvents,
+    WindlassSpeedType, YesNo, GARMIN_MANUFACTURER_CODE, RAYMARINE_MANUFACTURER_CODE,
+    SIMRAD_MANUFACTURER_CODE,
+};
+use crate::Priority;
+
+#[cfg(feature = "codegen")]
+use crate::pgns::generated::GENERATED_CATALOGUE;
+#[cfg(not(feature = "codegen"))]
+static GENERATED_CATALOGUE: &[PgnDescriptor] = &[];
+
+/// How a parameter group is split into CAN frames.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Transport {
+    /// A single frame of up to 8 bytes.
+    Single,
+    /// The NMEA 2000 fast packet protocol, up to 223 bytes.
+    Fast,
+    /// The ISO 11783 transport protocol, up to 1785 bytes.
+    IsoTp,
+}
+
+impl Transport {
+    /// Selects the transport for a `length` byte payload of parameter group
+    /// `pgn`.
+    ///
+    /// Catalogued parameter groups use their own transport. Unknown ones use
+    /// fast packet in the proprietary fast packet ranges, and otherwise a
+    /// single frame if the payload fits or ISO transport if it does not.
+    pub fn select(pgn: u32, length: usize) -> Self {
+        match PgnDescriptor::find(pgn) {
+            Some(descriptor) => descriptor.transport,
+            None if is_proprietary_fast_packet(pgn) => Transport::Fast,
+            None if length <= 8 => Transport::Single,
+            None => Transport::IsoTp,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FieldKind {
+    /// Unsigned number, scaled by `resolution` and then shifted by `offset`.
+    Unsigned {
+        resolution: f64,
+        offset: f64,
+    },
+    /// Two's complement number scaled by `resolution`.
+    Signed {
+        resolution: f64,
+    },
+    /// Enumerated value with the names of the known values.
+    Lookup(&'static [(u32, &'static str)]),
+    /// Bit field with the names of the known bits.
+    Flags(&'static [(u32, &'static str)]),
+    /// IEEE 754 single precision number.
+    Float,
+    /// Two decimal digits per byte, as in a DSC address.
+    Decimal,
+    /// Raw bytes; a length of 0 runs to the end of the payload.
+    Binary,
+    /// Fixed length string padded with spaces, `@` or NUL.
+    StringFix,
+    /// String prefixed by its length and encoding.
+    StringLau,
+    /// String prefixed by its length and terminated by NUL.
+    StringLz,
+    Reserved,
+}
+
+/// A field of a parameter group layout.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Field {
+    pub name: &'static str,
+    /// Length in bits, 0 for variable length fields.
+    pub bits: u16,
+    pub kind: FieldKind,
+    pub unit: Option<&'static str>,
+}
+
+impl Field {
+    const fn new(name: &'static str, bits: u16, kind: FieldKind, unit: &'static str) -> Self {
+        Field {
+            name,
+            bits,
+            kind,
+            unit: if unit.is_empty() { None } else { Some(unit) },
+        }
+    }
+
+    pub const fn uint(name: &'static str, bits: u16) -> Self {
+        Self::ufix(name, bits, 1.0, "")
+    }
+
+    pub const fn int(name: &'static str, bits: u16) -> Self {
+        Self::sfix(name, bits, 1.0, "")
+    }
+
+    pub const fn ufix(name: &'static str, bits: u16, resolution: f64, unit: &'static str) -> Self {
+        let kind = FieldKind::Unsigned {
+            resolution,
+            offset: 0.0,
+        };
+        Self::new(name, bits, kind, unit)
+    }
+
+    pub const fn sfix(name: &'static str, bits: u16, resolution: f64, unit: &'static str) -> Self {
+        Self::new(name, bits, FieldKind::Signed { resolution }, unit)
+    }
+
+    pub const fn lookup(
+        name: &'static str,
+        bits: u16,
+        values: &'static [(u32, &'static str)],
+    ) -> Self {
+        Self::new(name, bits, FieldKind::Lookup(values), "")
+    }
+
+    pub const fn flags(
+        name: &'static str,
+        bits: u16,
+        flags: &'static [(u32, &'static str)],
+    ) -> Self {
+        Self::new(name, bits, FieldKind::Flags(flags), "")
+    }
+
+    pub const fn float(name: &'static str, unit: &'static str) -> Self {
+        Self::new(name, 32, FieldKind::Float, unit)
+    }
+
+    pub const fn decimal(name: &'static str, bits: u16) -> Self {
+        Self::new(name, bits, FieldKind::Decimal, "")
+    }
+
+    pub const fn binary(name: &'static str, bits: u16) -> Self {
+        Self::new(name, bits, FieldKind::Binary, "")
+    }
+
+    pub const fn string_fix(name: &'static str, bytes: u16) -> Self {
+        Self::new(name, 8 * bytes, FieldKind::StringFix, "")
+    }
+
+    pub const fn string_lau(name: &'static str) -> Self {
+        Self::new(name, 0, FieldKind::StringLau, "")
+    }
+
+    pub const fn string_lz(name: &'static str) -> Self {
+        Self::new(name, 0, FieldKind::StringLz, "")
+    }
+
+    pub const fn reserved(bits: u16) -> Self {
+        Self::new("Reserved", bits, FieldKind::Reserved, "")
+    }
+
+    /// Shifts an unsigned field by `offset` after scaling.
+    pub const fn offset(mut self, offset: f64) -> Self {
+        if let FieldKind::Unsigned { resolution, .. } = self.kind {
+            self.kind = FieldKind::Unsigned { resolution, offset };
+        }
+        self
+    }
+
+    /// Whether the length of the field depends on its value.
+    pub fn is_variable(&self) -> bool {
+        self.bits == 0
+    }
+}
+
+/// A set of fields at the end of a layout that repeats.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Repeating {
+    /// Index of the field holding the number of repetitions, `None` if the
+    /// set repeats until the end of the payload.
+    pub count: Option<usize>,
+    /// Index of the first field of the set.
+    pub start: usize,
+}
+
+/// Static metadata of a parameter group.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PgnDescriptor {
+    pub pgn: u32,
+    pub name: &'static str,
+    pub transport: Transport,
+    /// Default transmit priority.
+    pub priority: Priority,
+    /// Default transmit interval in milliseconds, `None` if the parameter
+    /// group is sent on demand.
+    pub interval: Option<u16>,
+    /// Manufacturer of a proprietary parameter group.
+    pub manufacturer_code: Option<u16>,
+    pub fields: &'static [Field],
+    pub repeating: Option<Repeating>,
+}
+
+impl PgnDescriptor {
+    pub(crate) const fn new(
+        pgn: u32,
+        name: &'static str,
+        transport: Transport,
+        priority: Priority,
+        fields: &'static [Field],
+    ) -> Self {
+        PgnDescriptor {
+            pgn,
+            name,
+            transport,
+            priority,
+            interval: None,
+            manufacturer_code: None,
+            fields,
+            repeating: None,
+        }
+    }
+
+    pub(crate) const fn with_interval(mut self, interval: u16) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    pub(crate) const fn with_manufacturer(mut self, manufacturer_code: u16) -> Self {
+        self.manufacturer_code = Some(manufacturer_code);
+        self
+    }
+
+    pub(crate) const fn with_repeating(mut self, count: Option<usize>, start: usize) -> Self {
+        self.repeating = Some(Repeating { count, start });
+        self
+    }
+
+    /// Iterates over the catalogue, followed by the parameter groups generated
+    /// from the canboat database if the `codegen` feature is enabled.
+    pub fn all() -> impl Iterator<Item = &'static PgnDescriptor> {
+        CATALOGUE.iter().chain(GENERATED_CATALOGUE)
+    }
+
+    /// Looks up parameter group `pgn`. Proprietary parameter groups shared by
+    /// several manufacturers return the first one; use `for_message` to
+    /// select one by the payload.
+    pub fn find(pgn: u32) -> Option<&'static PgnDescriptor> {
+        Self::matching(pgn).next()
+    }
+
+    /// Looks up the descriptor of a message, matching the manufacturer code of
+    /// proprietary payloads.
+    pub fn for_message(pgn: u32, data: &[u8]) -> Option<&'static PgnDescriptor> {
+        if !is_proprietary(pgn) {
+            return Self::find(pgn);
+        }
+        let code = ProprietaryHeader::parse(data).ok()?.manufacturer_code;
+        Self::matching(pgn)
+            .find(|descriptor| descriptor.manufacturer_code.map_or(true, |c| c == code))
+    }
+
+    /// Descriptors of parameter group `pgn`, in the order of `all`.
+    fn matching(pgn: u32) -> impl Iterator<Item = &'static PgnDescriptor> {
+        entries(CATALOGUE, pgn)
+            .iter()
+            .chain(entries(GENERATED_CATALOGUE, pgn))
+    }
+
+    /// Fields before the repeating set, if any.
+    pub fn fixed_fields(&self) -> &'static [Field] {
+        match self.repeating {
+            Some(repeating) => &self.fields[..repeating.start],
+            None => self.fields,
+        }
+    }
+
+    /// Fields of the repeating set.
+    pub fn repeating_fields(&self) -> &'static [Field] {
+        match self.repeating {
+            Some(repeating) => &self.fields[repeating.start..],
+            None => &[],
+        }
+    }
+
+    /// Shortest payload holding every field up to the first variable length
+    /// field or repeating set.
+    pub fn min_length(&self) -> usize {
+        let bits: usize = self
+            .fixed_fields()
+            .iter()
+            .take_while(|field| !field.is_variable())
+            .map(|field| field.bits as usize)
+            .sum();
+        (bits + 7) / 8
+    }
+
+    /// Fails with `PgnError::DataTooShort` if `data` cannot hold the parameter
+    /// group.
+    pub fn validate(&self, data: &[u8]) -> Result<()> {
+        if data.len() < self.min_length() {
+            return Err(PgnError::DataTooShort);
+        }
+        Ok(())
+    }
+}
+
+/// The run of descriptors of parameter group `pgn` in `table`, which is
+/// sorted by parameter group number.
+fn entries(table: &'static [PgnDescriptor], pgn: u32) -> &'static [PgnDescriptor] {
+    let index = match table.binary_search_by_key(&pgn, |descriptor| descriptor.pgn) {
+        Ok(index) => index,
+        Err(_) => return &[],
+    };
+    let same = |descriptor: &&PgnDescriptor| descriptor.pgn == pgn;
+    let start = index - table[..index].iter().rev().take_while(same).count();
+    let end = index + table[index..].iter().take_while(same).count();
+    &table[start..end]
+}
+
+const ISO_ACKNOWLEDGEMENT: &[Field] = &[
+    Field::uint("Control", 8),
+    Field::uint("Group Function", 8),
+    Field::reserved(24),
+    Field::uint("PGN", 24),
+];
+
+const ISO_REQUEST: &[Field] = &[Field::uint("PGN", 24)];
+
+const ISO_TRANSPORT_DATA: &[Field] = &[Field::uint("SID", 8), Field::binary("Data", 56)];
+
+const ISO_TRANSPORT_CONNECTION: &[Field] = &[
+    Field::uint("Group Function Code", 8),
+    Field::uint("Message Size", 16),
+    Field::uint("Packets", 8),
+    Field::uint("Packets Reply", 8),
+    Field::uint("PGN", 24),
+];
+
+const ISO_ADDRESS_CLAIM: &[Field] = &[
+    Field::uint("Unique Number", 21),
+    Field::uint("Manufacturer Code", 11),
+    Field::uint("Device Instance Lower", 3),
+    Field::uint("Device Instance Upper", 5),
+    Field::uint("Device Function", 8),
+    Field::reserved(1),
+    Field::uint("Device Class", 7),
+    Field::uint("System Instance", 4),
+    Field::lookup("Industry Group", 3, IndustryGroup::VALUES),
+    Field::uint("Arbitrary Address Capable", 1),
+];
+
+const GROUP_FUNCTION: &[Field] = &[
+    Field::uint("Function Code", 8),
+    Field::uint("PGN", 24),
+    Field::binary("Data", 0),
+];
+
+const PGN_LIST: &[Field] = &[Field::uint("Function Code", 8), Field::uint("PGN", 24)];
+
+const SYSTEM_TIME: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::uint("Source", 4),
+    Field::reserved(4),
+    Field::ufix("Date", 16, 1.0, "d"),
+    Field::ufix("Time", 32, 0.0001, "s"),
+];
+
+const HEARTBEAT: &[Field] = &[
+    Field::ufix("Data Transmit Offset", 16, 0.01, "s"),
+    Field::uint("Sequence Counter", 8),
+    Field::uint("Controller 1 State", 2),
+    Field::uint("Controller 2 State", 2),
+    Field::uint("Equipment Status", 2),
+    Field::reserved(34),
+];
+
+const PRODUCT_INFORMATION: &[Field] = &[
+    Field::uint("NMEA 2000 Version", 16),
+    Field::uint("Product Code", 16),
+    Field::string_fix("Model ID", 32),
+    Field::string_fix("Software Version Code", 32),
+    Field::string_fix("Model Version", 32),
+    Field::string_fix("Model Serial Code", 32),
+    Field::uint("Certification Level", 8),
+    Field::uint("Load Equivalency", 8),
+];
+
+const CONFIGURATION_INFORMATION: &[Field] = &[
+    Field::string_lau("Installation Description 1"),
+    Field::string_lau("Installation Description 2"),
+    Field::string_lau("Manufacturer Information"),
+];
+
+const AC_INPUT_STATUS: &[Field] = &[
+    Field::uint("Instance", 8),
+    Field::uint("Number of Lines", 8),
+    Field::lookup("Line", 2, AcLine::VALUES),
+    Field::lookup("Acceptability", 2, Acceptability::VALUES),
+    Field::reserved(4),
+    Field::ufix("Voltage", 16, 0.01, "V"),
+    Field::ufix("Current", 16, 0.1, "A"),
+    Field::ufix("Frequency", 16, 0.01, "Hz"),
+    Field::ufix("Breaker Size", 16, 0.1, "A"),
+    Field::ufix("Real Power", 32, 1.0, "W"),
+    Field::ufix("Reactive Power", 32, 1.0, "VAR"),
+    Field::sfix("Power Factor", 8, 0.01, "Cos Phi"),
+];
+
+const AC_OUTPUT_STATUS: &[Field] = &[
+    Field::uint("Instance", 8),
+    Field::uint("Number of Lines", 8),
+    Field::lookup("Line", 2, AcLine::VALUES),
+    Field::lookup("Waveform", 3, Waveform::VALUES),
+    Field::reserved(3),
+    Field::ufix("Voltage", 16, 0.01, "V"),
+    Field::ufix("Current", 16, 0.1, "A"),
+    Field::ufix("Frequency", 16, 0.01, "Hz"),
+    Field::ufix("Breaker Size", 16, 0.1, "A"),
+    Field::ufix("Real Power", 32, 1.0, "W"),
+    Field::ufix("Reactive Power", 32, 1.0, "VAR"),
+    Field::sfix("Power Factor", 8, 0.01, "Cos Phi"),
+];
+
+const AC_BUS_BASIC_QUANTITIES: &[Field] = &[
+    Field::ufix("Line Line Voltage", 16, 1.0, "V"),
+    Field::ufix("Line Neutral Voltage", 16, 1.0, "V"),
+    Field::ufix("Frequency", 16, 1.0 / 128.0, "Hz"),
+    Field::reserved(16),
+];
+
+const AC_BASIC_QUANTITIES: &[Field] = &[
+    Field::ufix("Line Line Voltage", 16, 1.0, "V"),
+    Field::ufix("Line Neutral Voltage", 16, 1.0, "V"),
+    Field::ufix("Frequency", 16, 1.0 / 128.0, "Hz"),
+    Field::ufix("Current", 16, 1.0, "A"),
+];
+
+const AC_POWER: &[Field] = &[
+    Field::ufix("Real Power", 32, 1.0, "W").offset(-2e9),
+    Field::ufix("Apparent Power", 32, 1.0, "VA").offset(-2e9),
+];
+
+const AC_REACTIVE_POWER: &[Field] = &[
+    Field::ufix("Reactive Power", 32, 1.0, "VAR").offset(-2e9),
+    Field::ufix("Power Factor", 16, 1.0 / 16384.0, "").offset(-1.0),
+    Field::lookup("Power Factor Lagging", 2, PowerFactorLagging::VALUES),
+    Field::reserved(14),
+];
+
+const AC_TOTAL_ENERGY: &[Field] = &[
+    Field::ufix("Export", 32, 1.0, "kWh"),
+    Field::ufix("Import", 32, 1.0, "kWh"),
+];
+
+/// Values of a bit that is clear when a feature is available.
+const AVAILABLE: &[(u32, &str)] = &[(0, "Available"), (1, "Not available")];
+
+const AIS_CLASS_A_POSITION_REPORT: &[Field] = &[
+    Field::uint("Message ID", 6),
+    Field::lookup("Repeat Indicator", 2, RepeatIndicator::VALUES),
+    Field::uint("User ID", 32),
+    Field::sfix("Longitude", 32, 1e-7, "deg"),
+    Field::sfix("Latitude", 32, 1e-7, "deg"),
+    Field::lookup("Position Accuracy", 1, PositionAccuracy::VALUES),
+    Field::lookup("RAIM", 1, RaimFlag::VALUES),
+    Field::uint("Time Stamp", 6),
+    Field::ufix("COG", 16, 0.0001, "rad"),
+    Field::ufix("SOG", 16, 0.01, "m/s"),
+    Field::uint("Communication State", 19),
+    Field::lookup("Transceiver", 5, AisTransceiver::VALUES),
+    Field::ufix("Heading", 16, 0.0001, "rad"),
+    Field::sfix("Rate Of Turn", 16, 3.125e-5, "rad/s"),
+    Field::lookup("Nav Status", 4, NavStatus::VALUES),
+    Field::lookup("Special Maneuver", 2, SpecialManeuver::VALUES),
+    Field::reserved(10),
+    Field::uint("Sequence ID", 8),
+];
+
+const AIS_CLASS_B_POSITION_REPORT: &[Field] = &[
+    Field::uint("Message ID", 6),
+    Field::lookup("Repeat Indicator", 2, RepeatIndicator::VALUES),
+    Field::uint("User ID", 32),
+    Field::sfix("Longitude", 32, 1e-7, "deg"),
+    Field::sfix("Latitude", 32, 1e-7, "deg"),
+    Field::lookup("Position Accuracy", 1, PositionAccuracy::VALUES),
+    Field::lookup("RAIM", 1, RaimFlag::VALUES),
+    Field::uint("Time Stamp", 6),
+    Field::ufix("COG", 16, 0.0001, "rad"),
+    Field::ufix("SOG", 16, 0.01, "m/s"),
+    Field::uint("Communication State", 19),
+    Field::lookup("Transceiver", 5, AisTransceiver::VALUES),
+    Field::ufix("Heading", 16, 0.0001, "rad"),
+    Field::reserved(8), // regional application
+    Field::reserved(2), // regional application B
+    Field::lookup("Unit Type", 1, AisUnitType::VALUES),
+    Field::lookup("Integrated Display", 1, YesNo::VALUES),
+    Field::lookup("DSC", 1, YesNo::VALUES),
+    Field::lookup("Band", 1, AisBand::VALUES),
+    Field::lookup("Can Handle Msg 22", 1, YesNo::VALUES),
+    Field::lookup("AIS Mode", 1, AisMode::VALUES),
+    Field::lookup("AIS Communication State", 1, AisCommunicationState::VALUES),
+    Field::reserved(7),
+    Field::uint("Sequence ID", 8),
+];
+
+const AIS_CLASS_B_EXTENDED_POSITION_REPORT: &[Field] = &[
+    Field::uint("Message ID", 6),
+    Field::lookup("Repeat Indicator", 2, RepeatIndicator::VALUES),
+    Field::uint("User ID", 32),
+    Field::sfix("Longitude", 32, 1e-7, "deg"),
+    Field::sfix("Latitude", 32, 1e-7, "deg"),
+    Field::lookup("Position Accuracy", 1, PositionAccuracy::VALUES),
+    Field::lookup("RAIM", 1, RaimFlag::VALUES),
+    Field::uint("Time Stamp", 6),
+    Field::ufix("COG", 16, 0.0001, "rad"),
+    Field::ufix("SOG", 16, 0.01, "m/s"),
+    Field::reserved(8), // regional application
+    Field::reserved(4), // regional application B
+    Field::reserved(4),
+    Field::lookup("Ship Type", 8, ShipType::VALUES),
+    Field::ufix("True Heading", 16, 0.0001, "rad"),
+    Field::reserved(4),
+    Field::lookup("GNSS Type", 4, GnssType::VALUES),
+    Field::ufix("Length", 16, 0.1, "m"),
+    Field::ufix("Beam", 16, 0.1, "m"),
+    Field::ufix("Position Reference From Starboard", 16, 0.1, "m"),
+    Field::ufix("Position Reference From Bow", 16, 0.1, "m"),
+    Field::string_fix("Name", 20),
+    Field::lookup("DTE Available", 1, AVAILABLE),
+    Field::lookup("AIS Mode", 1, AisMode::VALUES),
+    Field::reserved(4),
+    Field::lookup("Transceiver", 5, AisTransceiver::VALUES),
+    Field::reserved(5),
+];
+
+const AIS_CLASS_A_STATIC_DATA: &[Field] = &[
+    Field::uint("Message ID", 6),
+    Field::lookup("Repeat Indicator", 2, RepeatIndicator::VALUES),
+    Field::uint("User ID", 32),
+    Field::uint("IMO Number", 32),
+    Field::string_fix("Callsign", 7),
+    Field::string_fix("Name", 20),
+    Field::lookup("Ship Type", 8, ShipType::VALUES),
+    Field::ufix("Length", 16, 0.1, "m"),
+    Field::ufix("Beam", 16, 0.1, "m"),
+    Field::ufix("Position Reference From Starboard", 16, 0.1, "m"),
+    Field::ufix("Position Reference From Bow", 16, 0.1, "m"),
+    Field::ufix("ETA Date", 16, 1.0, "d"),
+    Field::ufix("ETA Time", 32, 0.0001, "s"),
+    Field::ufix("Draft", 16, 0.01, "m"),
+    Field::string_fix("Destination", 20),
+    Field::lookup("AIS Version", 2, AisVersion::VALUES),
+    Field::lookup("GNSS Type", 4, GnssType::VALUES),
+    Field::lookup("DTE Available", 1, AVAILABLE),
+    Field::reserved(1),
+    Field::lookup("Transceiver", 5, AisTransceiver::VALUES),
+    Field::reserved(3),
+    Field::uint("Sequence ID", 8),
+];
+
+const AIS_CLASS_B_STATIC_DATA_PART_A: &[Field] = &[
+    Field::uint("Message ID", 6),
+    Field::lookup("Repeat Indicator", 2, RepeatIndicator::VALUES),
+    Field::uint("User ID", 32),
+    Field::string_fix("Name", 20),
+    Field::lookup("Transceiver", 5, AisTransceiver::VALUES),
+    Field::reserved(3),
+    Field::uint("Sequence ID", 8),
+];
+
+const AIS_CLASS_B_STATIC_DATA_PART_B: &[Field] = &[
+    Field::uint("Message ID", 6),
+    Field::lookup("Repeat Indicator", 2, RepeatIndicator::VALUES),
+    Field::uint("User ID", 32),
+    Field::lookup("Ship Type", 8, ShipType::VALUES),
+    Field::string_fix("Vendor ID", 7),
+    Field::string_fix("Callsign", 7),
+    Field::ufix("Length", 16, 0.1, "m"),
+    Field::ufix("Beam", 16, 0.1, "m"),
+    Field::ufix("Position Reference From Starboard", 16, 0.1, "m"),
+    Field::ufix("Position Reference From Bow", 16, 0.1, "m"),
+    Field::uint("Mothership User ID", 32),
+    Field::reserved(4),
+    Field::lookup("GNSS Type", 4, GnssType::VALUES),
+    Field::lookup("Transceiver", 5, AisTransceiver::VALUES),
+    Field::reserved(3),
+    Field::uint("Sequence ID", 8),
+];
+
+const AIS_ATON_REPORT: &[Field] = &[
+    Field::uint("Message ID", 6),
+    Field::lookup("Repeat Indicator", 2, RepeatIndicator::VALUES),
+    Field::uint("User ID", 32),
+    Field::sfix("Longitude", 32, 1e-7, "deg"),
+    Field::sfix("Latitude", 32, 1e-7, "deg"),
+    Field::lookup("Position Accuracy", 1, PositionAccuracy::VALUES),
+    Field::lookup("RAIM", 1, RaimFlag::VALUES),
+    Field::uint("Time Stamp", 6),
+    Field::ufix("Length", 16, 0.1, "m"),
+    Field::ufix("Beam", 16, 0.1, "m"),
+    Field::ufix("Position Reference From Starboard", 16, 0.1, "m"),
+    Field::ufix("Position Reference From True North", 16, 0.1, "m"),
+    Field::lookup("AtoN Type", 5, AtonType::VALUES),
+    Field::uint("Off Position", 1),
+    Field::uint("Virtual AtoN", 1),
+    Field::uint("Assigned Mode", 1),
+    Field::reserved(1),
+    Field::lookup("Position Fixing Device", 4, GnssType::VALUES),
+    Field::reserved(3),
+    Field::uint("AtoN Status", 8),
+    Field::lookup("Transceiver", 5, AisTransceiver::VALUES),
+    Field::reserved(3),
+    Field::string_lau("Name"),
+];
+
+const AIS_UTC_DATE_REPORT: &[Field] = &[
+    Field::uint("Message ID", 6),
+    Field::lookup("Repeat Indicator", 2, RepeatIndicator::VALUES),
+    Field::uint("User ID", 32),
+    Field::sfix("Longitude", 32, 1e-7, "deg"),
+    Field::sfix("Latitude", 32, 1e-7, "deg"),
+    Field::lookup("Position Accuracy", 1, PositionAccuracy::VALUES),
+    Field::lookup("RAIM", 1, RaimFlag::VALUES),
+    Field::reserved(6),
+    Field::ufix("Time", 32, 0.0001, "s"),
+    Field::uint("Communication State", 19),
+    Field::lookup("Transceiver", 5, AisTransceiver::VALUES),
+    Field::ufix("Date", 16, 1.0, "d"),
+    Field::reserved(4),
+    Field::lookup("GNSS Type", 4, GnssType::VALUES),
+    Field::reserved(16),
+    Field::uint("Sequence ID", 8),
+];
+
+const AIS_SAR_AIRCRAFT_POSITION_REPORT: &[Field] = &[
+    Field::uint("Message ID", 6),
+    Field::lookup("Repeat Indicator", 2, RepeatIndicator::VALUES),
+    Field::uint("User ID", 32),
+    Field::sfix("Longitude", 32, 1e-7, "deg"),
+    Field::sfix("Latitude", 32, 1e-7, "deg"),
+    Field::lookup("Position Accuracy", 1, PositionAccuracy::VALUES),
+    Field::lookup("RAIM", 1, RaimFlag::VALUES),
+    Field::uint("Time Stamp", 6),
+    Field::ufix("COG", 16, 0.0001, "rad"),
+    Field::ufix("SOG", 16, 0.1, "m/s"),
+    Field::uint("Communication State", 19),
+    Field::lookup("Transceiver", 5, AisTransceiver::VALUES),
+    Field::sfix("Altitude", 32, 0.01, "m"),
+    Field::uint("Regional Application", 8),
+    Field::lookup("DTE Available", 1, AVAILABLE),
+    Field::reserved(7),
+];
+
+const AIS_ADDRESSED_SAFETY_MESSAGE: &[Field] = &[
+    Field::uint("Message ID", 6),
+    Field::lookup("Repeat Indicator", 2, RepeatIndicator::VALUES),
+    Field::uint("Source User ID", 32),
+    Field::reserved(1),
+    Field::lookup("Transceiver", 5, AisTransceiver::VALUES),
+    Field::uint("Sequence Number", 2),
+    Field::uint("Destination User ID", 32),
+    Field::reserved(6),
+    Field::uint("Retransmitted", 1),
+    Field::reserved(1),
+    Field::string_lau("Text"),
+];
+
+const AIS_SAFETY_BROADCAST_MESSAGE: &[Field] = &[
+    Field::uint("Message ID", 6),
+    Field::lookup("Repeat Indicator", 2, RepeatIndicator::VALUES),
+    Field::uint("Source User ID", 32),
+    Field::reserved(1),
+    Field::lookup("Transceiver", 5, AisTransceiver::VALUES),
+    Field::reserved(2),
+    Field::string_lau("Text"),
+];
+
+const ALERT: &[Field] = &[
+    Field::lookup("Alert Type", 4, AlertType::VALUES),
+    Field::lookup("Category", 4, AlertCategory::VALUES),
+    Field::uint("System", 8),
+    Field::uint("Sub System", 8),
+    Field::uint("ID", 16),
+    Field::binary("Data Source Name", 64),
+    Field::uint("Data Source Instance", 8),
+    Field::uint("Data Source Index", 8),
+    Field::uint("Occurrence", 8),
+    Field::lookup("Temporary Silence", 1, YesNo::VALUES),
+    Field::lookup("Acknowledged", 1, YesNo::VALUES),
+    Field::lookup("Escalated", 1, YesNo::VALUES),
+    Field::lookup("Temporary Silence Support", 1, YesNo::VALUES),
+    Field::lookup("Acknowledge Support", 1, YesNo::VALUES),
+    Field::lookup("Escalation Support", 1, YesNo::VALUES),
+    Field::reserved(2),
+    Field::binary("Acknowledge Source Name", 64),
+    Field::lookup("Trigger Condition", 4, AlertTriggerCondition::VALUES),
+    Field::lookup("Threshold Status", 4, AlertThresholdStatus::VALUES),
+    Field::uint("Priority", 8),
+    Field::lookup("State", 8, AlertState::VALUES),
+];
+
+const ALERT_RESPONSE: &[Field] = &[
+    Field::lookup("Alert Type", 4, AlertType::VALUES),
+    Field::lookup("Category", 4, AlertCategory::VALUES),
+    Field::uint("System", 8),
+    Field::uint("Sub System", 8),
+    Field::uint("ID", 16),
+    Field::binary("Data Source Name", 64),
+    Field::uint("Data Source Instance", 8),
+    Field::uint("Data Source Index", 8),
+    Field::uint("Occurrence", 8),
+    Field::binary("Acknowledge Source Name", 64),
+    Field::lookup("Command", 2, AlertResponseCommand::VALUES),
+    Field::reserved(6),
+];
+
+const ALERT_TEXT: &[Field] = &[
+    Field::lookup("Alert Type", 4, AlertType::VALUES),
+    Field::lookup("Category", 4, AlertCategory::VALUES),
+    Field::uint("System", 8),
+    Field::uint("Sub System", 8),
+    Field::uint("ID", 16),
+    Field::binary("Data Source Name", 64),
+    Field::uint("Data Source Instance", 8),
+    Field::uint("Data Source Index", 8),
+    Field::uint("Occurrence", 8),
+    Field::lookup("Language", 8, AlertLanguage::VALUES),
+    Field::string_lau("Description"),
+    Field::string_lau("Location"),
+];
+
+const ALERT_CONFIGURATION: &[Field] = &[
+    Field::lookup("Alert Type", 4, AlertType::VALUES),
+    Field::lookup("Category", 4, AlertCategory::VALUES),
+    Field::uint("System", 8),
+    Field::uint("Sub System", 8),
+    Field::uint("ID", 16),
+    Field::binary("Data Source Name", 64),
+    Field::uint("Data Source Instance", 8),
+    Field::uint("Data Source Index", 8),
+    Field::uint("Occurrence", 8),
+    Field::uint("Alert Control", 2),
+    Field::uint("User Defined Alert Assignment", 2),
+    Field::reserved(4),
+    Field::uint("Reactivation Period", 8),
+    Field::uint("Temporary Silence Period", 8),
+    Field::uint("Escalation Period", 8),
+];
+
+const ALERT_THRESHOLD: &[Field] = &[
+    Field::lookup("Alert Type", 4, AlertType::VALUES),
+    Field::lookup("Category", 4, AlertCategory::VALUES),
+    Field::uint("System", 8),
+    Field::uint("Sub System", 8),
+    Field::uint("ID", 16),
+    Field::binary("Data Source Name", 64),
+    Field::uint("Data Source Instance", 8),
+    Field::uint("Data Source Index", 8),
+    Field::uint("Occurrence", 8),
+    Field::uint("Parameters", 8),
+    Field::uint("Parameter", 8),
+    Field::uint("Trigger Method", 8),
+    Field::uint("Data Format", 8),
+    Field::uint("Level", 64),
+];
+
+const ALERT_VALUE: &[Field] = &[
+    Field::lookup("Alert Type", 4, AlertType::VALUES),
+    Field::lookup("Category", 4, AlertCategory::VALUES),
+    Field::uint("System", 8),
+    Field::uint("Sub System", 8),
+    Field::uint("ID", 16),
+    Field::binary("Data Source Name", 64),
+    Field::uint("Data Source Instance", 8),
+    Field::uint("Data Source Index", 8),
+    Field::uint("Occurrence", 8),
+    Field::uint("Parameters", 8),
+    Field::uint("Parameter", 8),
+    Field::uint("Data Format", 8),
+    Field::uint("Value", 64),
+];
+
+const CHARGER_STATUS: &[Field] = &[
+    Field::uint("Instance", 8),
+    Field::uint("Battery Instance", 8),
+    Field::lookup("Operating State", 4, ChargerState::VALUES),
+    Field::lookup("Charge Mode", 4, ChargerMode::VALUES),
+    Field::lookup("Enabled", 2, OffOn::VALUES),
+    Field::lookup("Equalization Pending", 2, OffOn::VALUES),
+    Field::reserved(4),
+    Field::ufix("Equalization Time Remaining", 16, 60.0, "s"),
+];
+
+const INVERTER_STATUS: &[Field] = &[
+    Field::uint("Instance", 8),
+    Field::uint("AC Instance", 8),
+    Field::uint("DC Instance", 8),
+    Field::lookup("Operating State", 4, InverterState::VALUES),
+    Field::lookup("Enabled", 2, OffOn::VALUES),
+    Field::reserved(2),
+];
+
+const CONVERTER_STATUS: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::uint("Connection Number", 8),
+    Field::lookup("Operating State", 8, ConverterState::VALUES),
+    Field::lookup("Temperature State", 2, GoodWarningError::VALUES),
+    Field::lookup("Overload State", 2, GoodWarningError::VALUES),
+    Field::lookup("Low DC Voltage State", 2, GoodWarningError::VALUES),
+    Field::lookup("Ripple State", 2, GoodWarningError::VALUES),
+    Field::reserved(32),
+];
+
+const DC_VOLTAGE_CURRENT: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::uint("Connection Number", 8),
+    Field::ufix("Voltage", 16, 0.1, "V"),
+    Field::sfix("Current", 24, 0.01, "A"),
+    Field::reserved(8),
+];
+
+const DC_DETAILED_STATUS: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::uint("Instance", 8),
+    Field::lookup("DC Type", 8, DcType::VALUES),
+    Field::ufix("State Of Charge", 8, 1.0, "%"),
+    Field::ufix("State Of Health", 8, 1.0, "%"),
+    Field::ufix("Time Remaining", 16, 60.0, "s"),
+    Field::ufix("Ripple Voltage", 16, 0.001, "V"),
+    Field::ufix("Remaining Capacity", 16, 1.0, "Ah"),
+];
+
+const BATTERY_STATUS: &[Field] = &[
+    Field::uint("Instance", 8),
+    Field::sfix("Voltage", 16, 0.01, "V"),
+    Field::sfix("Current", 16, 0.1, "A"),
+    Field::ufix("Temperature", 16, 0.01, "K"),
+    Field::uint("SID", 8),
+];
+
+const BATTERY_CONFIGURATION_STATUS: &[Field] = &[
+    Field::uint("Instance", 8),
+    Field::lookup("Battery Type", 4, BatteryType::VALUES),
+    Field::lookup("Supports Equalization", 2, YesNo::VALUES),
+    Field::reserved(2),
+    Field::lookup("Nominal Voltage", 4, BatteryVoltage::VALUES),
+    Field::lookup("Chemistry", 4, BatteryChemistry::VALUES),
+    Field::ufix("Capacity", 16, 1.0, "Ah"),
+    Field::sfix("Temperature Coefficient", 8, 1.0, "%"),
+    Field::ufix("Peukert Exponent", 8, 0.002, "").offset(1.0),
+    Field::sfix("Charge Efficiency Factor", 8, 1.0, "%"),
+];
+
+const DSC_CALL_INFORMATION: &[Field] = &[
+    Field::lookup("Format", 8, DscFormat::VALUES),
+    Field::lookup("Category", 8, DscCategory::VALUES),
+    Field::decimal("Address", 40),
+    Field::uint("First Telecommand", 8),
+    Field::uint("Second Telecommand", 8),
+    Field::string_fix("Proposed Rx Channel", 6),
+    Field::string_fix("Proposed Tx Channel", 6),
+    Field::string_lau("Telephone Number"),
+    Field::sfix("Latitude", 32, 1e-7, "deg"),
+    Field::sfix("Longitude", 32, 1e-7, "deg"),
+    Field::ufix("Time Of Position", 32, 0.0001, "s"),
+    Field::decimal("Vessel In Distress", 40),
+    Field::uint("End Of Sequence", 8),
+    Field::lookup("Expansion Enabled", 2, YesNo::VALUES),
+    Field::reserved(6),
+    Field::string_fix("Calling Rx Channel", 6),
+    Field::string_fix("Calling Tx Channel", 6),
+    Field::ufix("Time Of Receipt", 32, 0.0001, "s"),
+    Field::ufix("Date Of Receipt", 16, 1.0, "d"),
+    Field::uint("Equipment Message ID", 16),
+    Field::uint("Symbol", 8),
+    Field::string_lau("Data"),
+];
+
+const RADIO_FREQUENCY_MODE_POWER: &[Field] = &[
+    Field::ufix("Rx Frequency", 32, 10.0, "Hz"),
+    Field::ufix("Tx Frequency", 32, 10.0, "Hz"),
+    Field::string_fix("Channel", 6),
+    Field::ufix("Tx Power", 16, 1.0, "W"),
+    Field::lookup("Mode", 8, TelephoneMode::VALUES),
+    Field::ufix("Channel Bandwidth", 16, 1.0, "Hz"),
+];
+
+const ENGINE_RAPID_UPDATE: &[Field] = &[
+    Field::lookup("Instance", 8, EngineInstance::VALUES),
+    Field::ufix("Speed", 16, 0.25, "rpm"),
+    Field::ufix("Boost Pressure", 16, 100.0, "Pa"),
+    Field::sfix("Tilt Trim", 8, 1.0, "%"),
+    Field::reserved(16),
+];
+
+const ENGINE_DYNAMIC_PARAMETERS: &[Field] = &[
+    Field::lookup("Instance", 8, EngineInstance::VALUES),
+    Field::ufix("Oil Pressure", 16, 100.0, "Pa"),
+    Field::ufix("Oil Temperature", 16, 0.1, "K"),
+    Field::ufix("Temperature", 16, 0.01, "K"),
+    Field::sfix("Alternator Potential", 16, 0.01, "V"),
+    Field::sfix("Fuel Rate", 16, 0.1, "L/h"),
+    Field::ufix("Total Engine Hours", 32, 1.0, "s"),
+    Field::ufix("Coolant Pressure", 16, 100.0, "Pa"),
+    Field::ufix("Fuel Pressure", 16, 1000.0, "Pa"),
+    Field::reserved(8),
+    Field::flags("Discrete Status 1", 16, EngineDiscreteStatus1::FLAGS),
+    Field::flags("Discrete Status 2", 16, EngineDiscreteStatus2::FLAGS),
+    Field::sfix("Engine Load", 8, 1.0, "%"),
+    Field::sfix("Engine Torque", 8, 1.0, "%"),
+];
+
+const TRANSMISSION_PARAMETERS: &[Field] = &[
+    Field::lookup("Instance", 8, EngineInstance::VALUES),
+    Field::lookup("Gear", 2, GearStatus::VALUES),
+    Field::reserved(6),
+    Field::ufix("Oil Pressure", 16, 100.0, "Pa"),
+    Field::ufix("Oil Temperature", 16, 0.1, "K"),
+    Field::flags("Discrete Status", 8, TransmissionDiscreteStatus::FLAGS),
+    Field::reserved(8),
+];
+
+const TRIP_PARAMETERS_VESSEL: &[Field] = &[
+    Field::ufix("Time To Empty", 32, 0.001, "s"),
+    Field::ufix("Distance To Empty", 32, 0.01, "m"),
+    Field::ufix("Estimated Fuel Remaining", 16, 1.0, "L"),
+    Field::ufix("Trip Run Time", 32, 0.001, "s"),
+];
+
+const TRIP_PARAMETERS_ENGINE: &[Field] = &[
+    Field::lookup("Instance", 8, EngineInstance::VALUES),
+    Field::ufix("Trip Fuel Used", 16, 1.0, "L"),
+    Field::sfix("Fuel Rate Average", 16, 0.1, "L/h"),
+    Field::sfix("Fuel Rate Economy", 16, 0.1, "L/h"),
+    Field::sfix("Instantaneous Fuel Economy", 16, 0.1, "L/h"),
+];
+
+const ENTERTAINMENT_CURRENT_FILE_STATUS: &[Field] = &[
+    Field::lookup("Zone", 8, EntertainmentZone::VALUES),
+    Field::lookup("Source", 8, EntertainmentSource::VALUES),
+    Field::uint("Number", 8),
+    Field::uint("ID", 32),
+    Field::lookup("Play Status", 8, PlayStatus::VALUES),
+    Field::ufix("Elapsed Track Time", 16, 1.0, "s"),
+    Field::ufix("Track Time", 16, 1.0, "s"),
+    Field::lookup("Repeat", 4, RepeatStatus::VALUES),
+    Field::lookup("Shuffle", 4, ShuffleStatus::VALUES),
+    Field::uint("Save Favourite", 8),
+    Field::uint("Play Favourite", 16),
+    Field::lookup("Like", 8, LikeStatus::VALUES),
+    Field::ufix("Signal Strength", 8, 1.0, "%"),
+    Field::ufix("Radio Frequency", 32, 10.0, "Hz"),
+    Field::uint("HD Frequency Multicast", 8),
+    Field::uint("Delete Favourite", 8),
+    Field::uint("Total Tracks", 16),
+];
+
+const ENTERTAINMENT_LIBRARY_DATA_FILE: &[Field] = &[
+    Field::lookup("Source", 8, EntertainmentSource::VALUES),
+    Field::uint("Number", 8),
+    Field::uint("ID", 32),
+    Field::lookup("File Type", 8, EntertainmentType::VALUES),
+    Field::string_lau("Name"),
+    Field::uint("Track", 16),
+    Field::uint("Station", 16),
+    Field::uint("Favourite", 8),
+    Field::ufix("Radio Frequency", 32, 10.0, "Hz"),
+    Field::uint("HD Frequency", 8),
+    Field::lookup("Zone", 8, EntertainmentZone::VALUES),
+    Field::lookup("In Play Queue", 2, YesNo::VALUES),
+    Field::lookup("Locked", 2, YesNo::VALUES),
+    Field::reserved(4),
+    Field::string_lau("Artist"),
+    Field::string_lau("Album"),
+    Field::string_lau("Station Name"),
+];
+
+const ENTERTAINMENT_LIBRARY_DATA_GROUP: &[Field] = &[
+    Field::lookup("Source", 8, EntertainmentSource::VALUES),
+    Field::uint("Number", 8),
+    Field::lookup("Group Type", 8, EntertainmentType::VALUES),
+    Field::lookup("Zone", 8, EntertainmentZone::VALUES),
+    Field::uint("Group ID", 32),
+    Field::uint("ID Offset", 16),
+    Field::uint("Entries", 16),
+    Field::uint("Total ID Count", 16),
+    Field::lookup("ID Type", 8, EntertainmentType::VALUES),
+    Field::uint("ID", 32),
+    Field::string_lau("Name"),
+];
+
+const ENTERTAINMENT_LIBRARY_DATA_SEARCH: &[Field] = &[
+    Field::lookup("Source", 8, EntertainmentSource::VALUES),
+    Field::uint("Number", 8),
+    Field::uint("Group ID", 32),
+    Field::lookup("Group Type 1", 8, EntertainmentType::VALUES),
+    Field::string_lau("Group Name 1"),
+    Field::lookup("Group Type 2", 8, EntertainmentType::VALUES),
+    Field::string_lau("Group Name 2"),
+    Field::lookup("Group Type 3", 8, EntertainmentType::VALUES),
+    Field::string_lau("Group Name 3"),
+];
+
+const ENTERTAINMENT_SUPPORTED_SOURCE_DATA: &[Field] = &[
+    Field::uint("ID Offset", 16),
+    Field::uint("Sources", 16),
+    Field::uint("Total ID Count", 16),
+    Field::uint("ID", 8),
+    Field::lookup("Source", 8, EntertainmentSource::VALUES),
+    Field::uint("Number", 8),
+    Field::string_lau("Name"),
+    Field::uint("Play Support", 32),
+    Field::uint("Browse Support", 16),
+    Field::lookup("Thumbs Support", 2, YesNo::VALUES),
+    Field::lookup("Connected", 2, YesNo::VALUES),
+    Field::uint("Repeat Support", 2),
+    Field::uint("Shuffle Support", 2),
+];
+
+const OUTSIDE_ENVIRONMENTAL_PARAMETERS: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::ufix("Water Temperature", 16, 0.01, "K"),
+    Field::ufix("Outside Ambient Air Temperature", 16, 0.01, "K"),
+    Field::ufix("Atmospheric Pressure", 16, 100.0, "Pa"),
+    Field::reserved(8),
+];
+
+const ENVIRONMENTAL_PARAMETERS: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::lookup("Temperature Source", 6, TemperatureSource::VALUES),
+    Field::lookup("Humidity Source", 2, HumiditySource::VALUES),
+    Field::ufix("Temperature", 16, 0.01, "K"),
+    Field::sfix("Humidity", 16, 0.004, "%"),
+    Field::ufix("Atmospheric Pressure", 16, 100.0, "Pa"),
+];
+
+const TEMPERATURE: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::uint("Instance", 8),
+    Field::lookup("Source", 8, TemperatureSource::VALUES),
+    Field::ufix("Actual Temperature", 16, 0.01, "K"),
+    Field::ufix("Set Temperature", 16, 0.01, "K"),
+    Field::reserved(8),
+];
+
+const HUMIDITY: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::uint("Instance", 8),
+    Field::lookup("Source", 8, HumiditySource::VALUES),
+    Field::sfix("Actual Humidity", 16, 0.004, "%"),
+    Field::sfix("Set Humidity", 16, 0.004, "%"),
+    Field::reserved(8),
+];
+
+const ACTUAL_PRESSURE: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::uint("Instance", 8),
+    Field::lookup("Source", 8, PressureSource::VALUES),
+    Field::sfix("Pressure", 32, 0.1, "Pa"),
+    Field::reserved(8),
+];
+
+const SET_PRESSURE: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::uint("Instance", 8),
+    Field::lookup("Source", 8, PressureSource::VALUES),
+    Field::sfix("Pressure", 32, 0.1, "Pa"),
+    Field::reserved(8),
+];
+
+const TEMPERATURE_EXTENDED_RANGE: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::uint("Instance", 8),
+    Field::lookup("Source", 8, TemperatureSource::VALUES),
+    Field::ufix("Temperature", 24, 0.001, "K"),
+    Field::ufix("Set Temperature", 16, 0.1, "K"),
+];
+
+const TIDE_STATION_DATA: &[Field] = &[
+    Field::lookup("Mode", 4, ResidualMode::VALUES),
+    Field::lookup("Tendency", 2, TideTendency::VALUES),
+    Field::reserved(2),
+    Field::ufix("Measurement Date", 16, 1.0, "d"),
+    Field::ufix("Measurement Time", 32, 0.0001, "s"),
+    Field::sfix("Station Latitude", 32, 1e-7, "deg"),
+    Field::sfix("Station Longitude", 32, 1e-7, "deg"),
+    Field::sfix("Tide Level", 16, 0.001, "m"),
+    Field::ufix("Tide Level Standard Deviation", 16, 0.01, "m"),
+    Field::string_lau("Station ID"),
+    Field::string_lau("Station Name"),
+];
+
+const SALINITY_STATION_DATA: &[Field] = &[
+    Field::lookup("Mode", 4, ResidualMode::VALUES),
+    Field::reserved(4),
+    Field::ufix("Measurement Date", 16, 1.0, "d"),
+    Field::ufix("Measurement Time", 32, 0.0001, "s"),
+    Field::sfix("Station Latitude", 32, 1e-7, "deg"),
+    Field::sfix("Station Longitude", 32, 1e-7, "deg"),
+    Field::float("Salinity", "ppt"),
+    Field::ufix("Water Temperature", 16, 0.01, "K"),
+    Field::string_lau("Station ID"),
+    Field::string_lau("Station Name"),
+];
+
+const CURRENT_STATION_DATA: &[Field] = &[
+    Field::lookup("Mode", 4, ResidualMode::VALUES),
+    Field::lookup("State", 3, FloodState::VALUES),
+    Field::reserved(1),
+    Field::ufix("Measurement Date", 16, 1.0, "d"),
+    Field::ufix("Measurement Time", 32, 0.0001, "s"),
+    Field::sfix("Station Latitude", 32, 1e-7, "deg"),
+    Field::sfix("Station Longitude", 32, 1e-7, "deg"),
+    Field::ufix("Measurement Depth", 32, 0.01, "m"),
+    Field::ufix("Current Speed", 16, 0.01, "m/s"),
+    Field::ufix("Current Flow Direction", 16, 0.0001, "rad"),
+    Field::ufix("Water Temperature", 16, 0.01, "K"),
+    Field::string_lau("Station ID"),
+    Field::string_lau("Station Name"),
+];
+
+const METEOROLOGICAL_STATION_DATA: &[Field] = &[
+    Field::lookup("Mode", 4, ResidualMode::VALUES),
+    Field::reserved(4),
+    Field::ufix("Measurement Date", 16, 1.0, "d"),
+    Field::ufix("Measurement Time", 32, 0.0001, "s"),
+    Field::sfix("Station Latitude", 32, 1e-7, "deg"),
+    Field::sfix("Station Longitude", 32, 1e-7, "deg"),
+    Field::ufix("Wind Speed", 16, 0.01, "m/s"),
+    Field::ufix("Wind Direction", 16, 0.0001, "rad"),
+    Field::lookup("Wind Reference", 3, WindReference::VALUES),
+    Field::reserved(5),
+    Field::ufix("Wind Gusts", 16, 0.01, "m/s"),
+    Field::ufix("Atmospheric Pressure", 16, 100.0, "Pa"),
+    Field::ufix("Ambient Temperature", 16, 0.01, "K"),
+    Field::string_lau("Station ID"),
+    Field::string_lau("Station Name"),
+];
+
+const FLUID_LEVEL: &[Field] = &[
+    Field::uint("Instance", 4),
+    Field::lookup("Fluid Type", 4, FluidType::VALUES),
+    Field::sfix("Level", 16, 0.004, "%"),
+    Field::ufix("Capacity", 32, 0.1, "L"),
+    Field::reserved(8),
+];
+
+const PROPRIETARY130820: &[Field] = &[
+    Field::uint("Manufacturer Code", 11),
+    Field::reserved(2),
+    Field::lookup("Industry Group", 3, IndustryGroup::VALUES),
+    Field::binary("Data", 0),
+];
+
+const GARMIN_COLOR_MODE: &[Field] = &[
+    Field::uint("Manufacturer Code", 11),
+    Field::reserved(2),
+    Field::lookup("Industry Group", 3, IndustryGroup::VALUES),
+    Field::uint("Unknown ID 1", 8),
+    Field::uint("Unknown ID 2", 8),
+    Field::uint("Unknown ID 3", 8),
+    Field::uint("Unknown ID 4", 8),
+    Field::reserved(16),
+    Field::uint("Mode", 8),
+    Field::reserved(8),
+    Field::uint("Backlight Or Color", 8),
+];
+
+const HEAVE: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::sfix("Heave", 16, 0.01, "m"),
+    Field::ufix("Delay", 16, 0.01, "s"),
+    Field::uint("Delay Source", 4),
+    Field::reserved(20),
+];
+
+const LEEWAY_ANGLE: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::sfix("Leeway Angle", 16, 0.0001, "rad"),
+    Field::reserved(40),
+];
+
+const SMALL_CRAFT_STATUS: &[Field] = &[
+    Field::sfix("Port Trim Tab", 8, 1.0, "%"),
+    Field::sfix("Starboard Trim Tab", 8, 1.0, "%"),
+    Field::reserved(48),
+];
+
+const DIRECTION_DATA: &[Field] = &[
+    Field::lookup("Mode", 4, ResidualMode::VALUES),
+    Field::lookup("COG Reference", 2, DirectionReference::VALUES),
+    Field::reserved(2),
+    Field::uint("SID", 8),
+    Field::ufix("COG", 16, 0.0001, "rad"),
+    Field::ufix("SOG", 16, 0.01, "m/s"),
+    Field::ufix("Heading", 16, 0.0001, "rad"),
+    Field::ufix("Speed Through Water", 16, 0.01, "m/s"),
+    Field::ufix("Set", 16, 0.0001, "rad"),
+    Field::ufix("Drift", 16, 0.01, "m/s"),
+];
+
+const VESSEL_SPEED_COMPONENTS: &[Field] = &[
+    Field::sfix("Longitudinal Water", 16, 0.001, "m/s"),
+    Field::sfix("Transverse Water", 16, 0.001, "m/s"),
+    Field::sfix("Longitudinal Ground", 16, 0.001, "m/s"),
+    Field::sfix("Transverse Ground", 16, 0.001, "m/s"),
+    Field::sfix("Stern Water", 16, 0.001, "m/s"),
+    Field::sfix("Stern Ground", 16, 0.001, "m/s"),
+];
+
+const SIMNET_DEVICE_STATUS: &[Field] = &[
+    Field::uint("Manufacturer Code", 11),
+    Field::reserved(2),
+    Field::lookup("Industry Group", 3, IndustryGroup::VALUES),
+    Field::lookup("Model", 8, SimnetDeviceModel::VALUES),
+    Field::lookup("Report", 8, SimnetDeviceReport::VALUES),
+    Field::binary("Data", 32),
+];
+
+const SIMNET_KEY_VALUE: &[Field] = &[
+    Field::uint("Manufacturer Code", 11),
+    Field::reserved(2),
+    Field::lookup("Industry Group", 3, IndustryGroup::VALUES),
+    Field::uint("Address", 8),
+    Field::uint("Instance", 8),
+    Field::lookup("Network Group", 8, SimnetNetworkGroup::VALUES),
+    Field::uint("Source", 8),
+    Field::uint("Key", 24),
+    Field::lookup("Operation", 8, SimnetKeyOperation::VALUES),
+    Field::binary("Value", 0),
+];
+
+const SIMNET_AP_COMMAND: &[Field] = &[
+    Field::uint("Manufacturer Code", 11),
+    Field::reserved(2),
+    Field::lookup("Industry Group", 3, IndustryGroup::VALUES),
+    Field::uint("Address", 8),
+    Field::reserved(8),
+    Field::lookup("Network Group", 8, SimnetNetworkGroup::VALUES),
+    Field::uint("Command Type", 8),
+    Field::lookup("Event", 8, SimnetApEvent::VALUES),
+    Field::reserved(40),
+];
+const CROSS_TRACK_ERROR: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::lookup("Mode", 4, ResidualMode::VALUES),
+    Field::reserved(2),
+    Field::lookup("Navigation Terminated", 2, YesNo::VALUES),
+    Field::sfix("XTE", 32, 0.01, "m"),
+    Field::reserved(16),
+];
+
+const NAVIGATION_DATA: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::ufix("Distance To Waypoint", 32, 0.01, "m"),
+    Field::lookup("Bearing Reference", 2, DirectionReference::VALUES),
+    Field::lookup("Perpendicular Crossed", 2, YesNo::VALUES),
+    Field::lookup("Arrival Circle Entered", 2, YesNo::VALUES),
+    Field::lookup("Calculation Type", 2, CalculationType::VALUES),
+    Field::ufix("ETA Time", 32, 0.0001, "s"),
+    Field::ufix("ETA Date", 16, 1.0, "d"),
+    Field::ufix("Bearing Origin To Destination", 16, 0.0001, "rad"),
+    Field::ufix("Bearing Position To Destination", 16, 0.0001, "rad"),
+    Field::uint("Origin Waypoint Number", 32),
+    Field::uint("Destination Waypoint Number", 32),
+    Field::sfix("Destination Latitude", 32, 1e-7, "deg"),
+    Field::sfix("Destination Longitude", 32, 1e-7, "deg"),
+    Field::sfix("Waypoint Closing Velocity", 16, 0.01, "m/s"),
+];
+
+const SET_AND_DRIFT: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::lookup("Set Reference", 2, DirectionReference::VALUES),
+    Field::reserved(6),
+    Field::ufix("Set", 16, 0.0001, "rad"),
+    Field::ufix("Drift", 16, 0.01, "m/s"),
+    Field::reserved(16),
+];
+
+const TIME_TO_MARK: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::sfix("Time To Mark", 32, 0.001, "s"),
+    Field::lookup("Mark Type", 4, MarkType::VALUES),
+    Field::reserved(4),
+    Field::uint("Mark ID", 32),
+];
+
+const BEARING_DISTANCE_BETWEEN_MARKS: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::lookup("Bearing Reference", 2, DirectionReference::VALUES),
+    Field::lookup("Calculation Type", 2, CalculationType::VALUES),
+    Field::reserved(4),
+    Field::ufix("Bearing Origin To Destination", 16, 0.0001, "rad"),
+    Field::ufix("Distance", 32, 0.01, "m"),
+    Field::lookup("Origin Mark Type", 4, MarkType::VALUES),
+    Field::lookup("Destination Mark Type", 4, MarkType::VALUES),
+    Field::uint("Origin Mark ID", 32),
+    Field::uint("Destination Mark ID", 32),
+];
+
+const SEATALK_PILOT_MODE: &[Field] = &[
+    Field::uint("Manufacturer Code", 11),
+    Field::reserved(2),
+    Field::lookup("Industry Group", 3, IndustryGroup::VALUES),
+    Field::lookup("Mode", 16, PilotMode::VALUES),
+    Field::uint("Sub Mode", 16),
+    Field::uint("Data", 8),
+    Field::reserved(8),
+];
+
+const SEATALK_PILOT_HEADING: &[Field] = &[
+    Field::uint("Manufacturer Code", 11),
+    Field::reserved(2),
+    Field::lookup("Industry Group", 3, IndustryGroup::VALUES),
+    Field::uint("SID", 8),
+    Field::ufix("Heading True", 16, 0.0001, "rad"),
+    Field::ufix("Heading Magnetic", 16, 0.0001, "rad"),
+    Field::reserved(8),
+];
+
+const SEATALK_PILOT_LOCKED_HEADING: &[Field] = &[
+    Field::uint("Manufacturer Code", 11),
+    Field::reserved(2),
+    Field::lookup("Industry Group", 3, IndustryGroup::VALUES),
+    Field::uint("SID", 8),
+    Field::ufix("Target Heading True", 16, 0.0001, "rad"),
+    Field::ufix("Target Heading Magnetic", 16, 0.0001, "rad"),
+    Field::reserved(8),
+];
+
+const SEATALK_KEYSTROKE: &[Field] = &[
+    Field::uint("Manufacturer Code", 11),
+    Field::reserved(2),
+    Field::lookup("Industry Group", 3, IndustryGroup::VALUES),
+    Field::uint("Proprietary ID", 8),
+    Field::uint("Command", 8),
+    Field::uint("Seatalk1 Command", 8),
+    Field::uint("Device", 8),
+    Field::lookup("Key", 8, SeatalkKey::VALUES),
+    Field::uint("Key Inverted", 8),
+    Field::binary("Trailer", 112),
+];
+
+const ROUTE_INFORMATION: &[Field] = &[
+    Field::uint("Start RPS", 16),
+    Field::uint("Waypoints", 16),
+    Field::uint("Database ID", 16),
+    Field::uint("Route ID", 16),
+    Field::lookup("Direction", 3, RouteDirection::VALUES),
+    Field::lookup("Supplementary Data Available", 2, OffOn::VALUES),
+    Field::reserved(3),
+    Field::string_lau("Route Name"),
+    Field::reserved(8),
+    Field::uint("WP ID", 16),
+    Field::string_lau("WP Name"),
+    Field::sfix("WP Latitude", 32, 1e-7, "deg"),
+    Field::sfix("WP Longitude", 32, 1e-7, "deg"),
+];
+
+const WAYPOINT_LIST: &[Field] = &[
+    Field::uint("Start WP ID", 16),
+    Field::uint("Waypoints", 16),
+    Field::uint("Valid WPs", 16),
+    Field::uint("Database ID", 16),
+    Field::reserved(16),
+    Field::uint("WP ID", 16),
+    Field::string_lau("WP Name"),
+    Field::sfix("WP Latitude", 32, 1e-7, "deg"),
+    Field::sfix("WP Longitude", 32, 1e-7, "deg"),
+];
+
+const HEADING_TRACK_CONTROL: &[Field] = &[
+    Field::lookup("Rudder Limit Exceeded", 2, YesNo::VALUES),
+    Field::lookup("Off Heading Limit Exceeded", 2, YesNo::VALUES),
+    Field::lookup("Off Track Limit Exceeded", 2, YesNo::VALUES),
+    Field::lookup("Overridden", 2, YesNo::VALUES),
+    Field::lookup("Steering Mode", 3, SteeringMode::VALUES),
+    Field::lookup("Turn Mode", 3, TurnMode::VALUES),
+    Field::lookup("Heading Reference", 2, DirectionReference::VALUES),
+    Field::reserved(5),
+    Field::lookup("Commanded Rudder Direction", 3, RudderDirection::VALUES),
+    Field::sfix("Commanded Rudder Angle", 16, 0.0001, "rad"),
+    Field::ufix("Heading To Steer", 16, 0.0001, "rad"),
+    Field::ufix("Track", 16, 0.0001, "rad"),
+    Field::ufix("Rudder Limit", 16, 0.0001, "rad"),
+    Field::ufix("Off Heading Limit", 16, 0.0001, "rad"),
+    Field::sfix("Radius Of Turn Order", 16, 1.0, "m"),
+    Field::sfix("Rate Of Turn Order", 16, 3.125e-5, "rad/s"),
+    Field::sfix("Off Track Limit", 16, 1.0, "m"),
+    Field::ufix("Vessel Heading", 16, 0.0001, "rad"),
+];
+
+const RUDDER: &[Field] = &[
+    Field::uint("Instance", 8),
+    Field::lookup("Direction Order", 3, RudderDirection::VALUES),
+    Field::reserved(5),
+    Field::sfix("Angle Order", 16, 0.0001, "rad"),
+    Field::sfix("Position", 16, 0.0001, "rad"),
+    Field::reserved(16),
+];
+
+const MAN_OVERBOARD_NOTIFICATION: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::uint("Emitter ID", 32),
+    Field::lookup("Status", 3, MobStatus::VALUES),
+    Field::reserved(5),
+    Field::ufix("Activation Time", 32, 0.0001, "s"),
+    Field::lookup("Position Source", 3, MobPositionSource::VALUES),
+    Field::reserved(5),
+    Field::ufix("Position Date", 16, 1.0, "d"),
+    Field::ufix("Position Time", 32, 0.0001, "s"),
+    Field::sfix("Latitude", 32, 1e-7, "deg"),
+    Field::sfix("Longitude", 32, 1e-7, "deg"),
+    Field::lookup("COG Reference", 2, DirectionReference::VALUES),
+    Field::reserved(6),
+    Field::ufix("COG", 16, 0.0001, "rad"),
+    Field::ufix("SOG", 16, 0.01, "m/s"),
+    Field::uint("User ID", 32),
+    Field::lookup("Emitter Battery", 3, BatteryLevel::VALUES),
+    Field::reserved(5),
+];
+
+const BINARY_SWITCH_BANK_STATUS: &[Field] = &[
+    Field::uint("Instance", 8),
+    Field::lookup("Indicator", 2, SwitchState::VALUES),
+];
+
+const SWITCH_BANK_CONTROL: &[Field] = &[
+    Field::uint("Instance", 8),
+    Field::lookup("Switch", 2, SwitchState::VALUES),
+];
+
+const THRUSTER_CONTROL_STATUS: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::uint("Identifier", 8),
+    Field::lookup("Direction", 4, ThrusterDirection::VALUES),
+    Field::lookup("Power Enabled", 2, OffOn::VALUES),
+    Field::lookup("Retract", 2, ThrusterRetract::VALUES),
+    Field::ufix("Speed", 8, 1.0, "%"),
+    Field::flags("Events", 8, ThrusterControlEvents::FLAGS),
+    Field::ufix("Command Timeout", 8, 0.005, "s"),
+    Field::ufix("Azimuth", 16, 0.0001, "rad"),
+];
+
+const THRUSTER_INFORMATION: &[Field] = &[
+    Field::uint("Identifier", 8),
+    Field::lookup("Motor Type", 4, ThrusterMotorType::VALUES),
+    Field::reserved(4),
+    Field::ufix("Power Rating", 16, 1.0, "W"),
+    Field::ufix("Maximum Temperature", 16, 0.01, "K"),
+    Field::ufix("Maximum Rotational Speed", 16, 0.25, "rpm"),
+];
+
+const THRUSTER_MOTOR_STATUS: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::uint("Identifier", 8),
+    Field::flags("Events", 8, ThrusterMotorEvents::FLAGS),
+    Field::ufix("Current", 8, 1.0, "A"),
+    Field::ufix("Temperature", 16, 0.01, "K"),
+    Field::ufix("Operating Time", 16, 1.0, "s"),
+];
+
+const WINDLASS_CONTROL_STATUS: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::uint("Windlass ID", 8),
+    Field::lookup("Direction", 2, WindlassDirection::VALUES),
+    Field::lookup("Anchor Docking", 2, OffOn::VALUES),
+    Field::lookup("Speed Type", 2, WindlassSpeedType::VALUES),
+    Field::reserved(2),
+    Field::ufix("Speed", 8, 1.0, "%"),
+    Field::lookup("Power Enabled", 2, OffOn::VALUES),
+    Field::lookup("Mechanical Lock", 2, OffOn::VALUES),
+    Field::lookup("Deck And Anchor Wash", 2, OffOn::VALUES),
+    Field::lookup("Anchor Light", 2, OffOn::VALUES),
+    Field::ufix("Command Timeout", 8, 0.005, "s"),
+    Field::flags("Events", 4, WindlassControlEvents::FLAGS),
+    Field::reserved(12),
+];
+
+const WINDLASS_OPERATING_STATUS: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::uint("Windlass ID", 8),
+    Field::lookup("Direction", 2, WindlassDirection::VALUES),
+    Field::lookup("Motion", 2, WindlassMotion::VALUES),
+    Field::lookup("Rode Type", 2, RodeType::VALUES),
+    Field::reserved(2),
+    Field::ufix("Rode Counter", 16, 0.1, "m"),
+    Field::ufix("Line Speed", 16, 0.01, "m/s"),
+    Field::lookup("Anchor Docking", 2, DockingStatus::VALUES),
+    Field::flags("Events", 6, WindlassOperatingEvents::FLAGS),
+];
+
+const WINDLASS_MONITORING_STATUS: &[Field] = &[
+    Field::uint("SID", 8),
+    Field::uint("Windlass ID", 8),
+    Field::flags("Events", 8, WindlassMonitoringEvents::FLAGS),
+    Field::ufix("Controller Voltage", 8, 0.2, "V"),
+    Field::ufix("Motor Current", 8, 1.0, "A"),
+    Field::ufix("Total Motor Time", 16, 60.0, "s"),
+    Field::reserved(8),
+];
+
+/// Descriptors of the parameter groups known to this crate, sorted by PGN.
+pub static CATALOGUE: &[PgnDescriptor] = &[
+    PgnDescriptor::new(
+        59392,
+        "ISO Acknowledgement",
+        Transport::Single,
+        Priority::Priority6,
+        ISO_ACKNOWLEDGEMENT,
+    ),
+    PgnDescriptor::new(
+        59904,
+        "ISO Request",
+        Transport::Single,
+        Priority::Priority6,
+        ISO_REQUEST,
+    ),
+    PgnDescriptor::new(
+        60160,
+        "ISO Transport Protocol, Data Transfer",
+        Transport::Single,
+        Priority::Priority6,
+        ISO_TRANSPORT_DATA,
+    ),
+    PgnDescriptor::new(
+        60416,
+        "ISO Transport Protocol, Connection Management",
+        Transport::Single,
+        Priority::Priority6,
+        ISO_TRANSPORT_CONNECTION,
+    ),
+    PgnDescriptor::new(
+        60928,
+        "ISO Address Claim",
+        Transport::Single,
+        Priority::Priority6,
+        ISO_ADDRESS_CLAIM,
+    ),
+    PgnDescriptor::new(
+        65001,
+        "Bus #1 Phase C Basic AC Quantities",
+        Transport::Single,
+        Priority::Priority3,
+        AC_BUS_BASIC_QUANTITIES,
+    ),
+    PgnDescriptor::new(
+        65002,
+        "Bus #1 Phase B Basic AC Quantities",
+        Transport::Single,
+        Priority::Priority3,
+        AC_BUS_BASIC_QUANTITIES,
+    ),
+    PgnDescriptor::new(
+        65003,
+        "Bus #1 Phase A Basic AC Quantities",
+        Transport::Single,
+        Priority::Priority3,
+        AC_BUS_BASIC_QUANTITIES,
+    ),
+    PgnDescriptor::new(
+        65004,
+        "Bus #1 Average Basic AC Quantities",
+        Transport::Single,
+        Priority::Priority3,
+        AC_BUS_BASIC_QUANTITIES,
+    ),
+    PgnDescriptor::new(
+        65005,
+        "Utility #1 Total AC Energy",
+        Transport::Single,
+        Priority::Priority3,
+        AC_TOTAL_ENERGY,
+    ),
+    PgnDescriptor::new(
+        65006,
+        "Utility #1 Phase C AC Reactive Power",
+        Transport::Single,
+        Priority::Priority3,
+        AC_REACTIVE_POWER,
+    ),
+    PgnDescriptor::new(
+        65007,
+        "Utility #1 Phase C AC Power",
+        Transport::Single,
+        Priority::Priority3,
+        AC_POWER,
+    ),
+    PgnDescriptor::new(
+        65008,
+        "Utility #1 Phase C Basic AC Quantities",
+        Transport::Single,
+        Priority::Priority3,
+        AC_BASIC_QUANTITIES,
+    ),
+    PgnDescriptor::new(
+        65009,
+        "Utility #1 Phase B AC Reactive Power",
+        Transport::Single,
+        Priority::Priority3,
+        AC_REACTIVE_POWER,
+    ),
+    PgnDescriptor::new(
+        65010,
+        "Utility #1 Phase B AC Power",
+        Transport::Single,
+        Priority::Priority3,
+        AC_POWER,
+    ),
+    PgnDescriptor::new(
+        65011,
+        "Utility #1 Phase B Basic AC Quantities",
+        Transport::Single,
+        Priority::Priority3,
+        AC_BASIC_QUANTITIES,
+    ),
+    PgnDescriptor::new(
+        65012,
+        "Utility #1 Phase A AC Reactive Power",
+        Transport::Single,
+        Priority::Priority3,
+        AC_REACTIVE_POWER,
+    ),
+    PgnDescriptor::new(
+        65013,
+        "Utility #1 Phase A AC Power",
+        Transport::Single,
+        Priority::Priority3,
+        AC_POWER,
+    ),
+    PgnDescriptor::new(
+        65014,
+        "Utility #1 Phase A Basic AC Quantities",
+        Transport::Single,
+        Priority::Priority3,
+        AC_BASIC_QUANTITIES,
+    ),
+    PgnDescriptor::new(
+        65015,
+        "Utility #1 Total AC Reactive Power",
+        Transport::Single,
+        Priority::Priority3,
+        AC_REACTIVE_POWER,
+    ),
+    PgnDescriptor::new(
+        65016,
+        "Utility #1 Total AC Power",
+        Transport::Single,
+        Priority::Priority3,
+        AC_POWER,
+    ),
+    PgnDescriptor::new(
+        65017,
+        "Utility #1 Average Basic AC Quantities",
+        Transport::Single,
+        Priority::Priority3,
+        AC_BASIC_QUANTITIES,
+    ),
+    PgnDescriptor::new(
+        65018,
+        "Generator #1 Total AC Energy",
+        Transport::Single,
+        Priority::Priority3,
+        AC_TOTAL_ENERGY,
+    ),
+    PgnDescriptor::new(
+        65019,
+        "Generator #1 Phase C AC Reactive Power",
+        Transport::Single,
+        Priority::Priority3,
+        AC_REACTIVE_POWER,
+    ),
+    PgnDescriptor::new(
+        65020,
+        "Generator #1 Phase C AC Power",
+        Transport::Single,
+        Priority::Priority3,
+        AC_POWER,
+    ),
+    PgnDescriptor::new(
+        65021,
+        "Generator #1 Phase C Basic AC Quantities",
+        Transport::Single,
+        Priority::Priority3,
+        AC_BASIC_QUANTITIES,
+    ),
+    PgnDescriptor::new(
+        65022,
+        "Generator #1 Phase B AC Reactive Power",
+        Transport::Single,
+        Priority::Priority3,
+        AC_REACTIVE_POWER,
+    ),
+    PgnDescriptor::new(
+        65023,
+        "Generator #1 Phase B AC Power",
+        Transport::Single,
+        Priority::Priority3,
+        AC_POWER,
+    ),
+    PgnDescriptor::new(
+        65024,
+        "Generator #1 Phase B Basic AC Quantities",
+        Transport::Single,
+        Priority::Priority3,
+        AC_BASIC_QUANTITIES,
+    ),
+    PgnDescriptor::new(
+        65025,
+        "Generator #1 Phase A AC Reactive Power",
+        Transport::Single,
+        Priority::Priority3,
+        AC_REACTIVE_POWER,
+    ),
+    PgnDescriptor::new(
+        65026,
+        "Generator #1 Phase A AC Power",
+        Transport::Single,
+        Priority::Priority3,
+        AC_POWER,
+    ),
+    PgnDescriptor::new(
+        65027,
+        "Generator #1 Phase A Basic AC Quantities",
+        Transport::Single,
+        Priority::Priority3,
+        AC_BASIC_QUANTITIES,
+    ),
+    PgnDescriptor::new(
+        65028,
+        "Generator #1 Total AC Reactive Power",
+        Transport::Single,
+        Priority::Priority3,
+        AC_REACTIVE_POWER,
+    ),
+    PgnDescriptor::new(
+        65029,
+        "Generator #1 Total AC Power",
+        Transport::Single,
+        Priority::Priority3,
+        AC_POWER,
+    ),
+    PgnDescriptor::new(
+        65030,
+        "Generator #1 Average Basic AC Quantities",
+        Transport::Single,
+        Priority::Priority3,
+        AC_BASIC_QUANTITIES,
+    ),
+    PgnDescriptor::new(
+        65305,
+        "Simnet: Device Status",
+        Transport::Single,
+        Priority::Priority7,
+        SIMNET_DEVICE_STATUS,
+    )
+    .with_manufacturer(SIMRAD_MANUFACTURER_CODE),
+    PgnDescriptor::new(
+        65359,
+        "Seatalk: Pilot Heading",
+        Transport::Single,
+        Priority::Priority7,
+        SEATALK_PILOT_HEADING,
+    )
+    .with_manufacturer(RAYMARINE_MANUFACTURER_CODE),
+    PgnDescriptor::new(
+        65360,
+        "Seatalk: Pilot Locked Heading",
+        Transport::Single,
+        Priority::Priority7,
+        SEATALK_PILOT_LOCKED_HEADING,
+    )
+    .with_manufacturer(RAYMARINE_MANUFACTURER_CODE),
+    PgnDescriptor::new(
+        65379,
+        "Seatalk: Pilot Mode",
+        Transport::Single,
+        Priority::Priority7,
+        SEATALK_PILOT_MODE,
+    )
+    .with_manufacturer(RAYMARINE_MANUFACTURER_CODE),
+    PgnDescriptor::new(
+        126208,
+        "NMEA Request/Command/Acknowledge group function",
+        Transport::Fast,
+        Priority::Priority3,
+        GROUP_FUNCTION,
+    ),
+    PgnDescriptor::new(
+        126464,
+        "PGN List (Transmit and Receive)",
+        Transport::Fast,
+        Priority::Priority6,
+        PGN_LIST,
+    )
+    .with_repeating(None, 1),
+    PgnDescriptor::new(
+        126720,
+        "Garmin: Day Mode, Night Mode and Color mode",
+        Transport::Fast,
+        Priority::Priority7,
+        GARMIN_COLOR_MODE,
+    )
+    .with_manufacturer(GARMIN_MANUFACTURER_CODE),
+    PgnDescriptor::new(
+        126720,
+        "Seatalk1: Keystroke",
+        Transport::Fast,
+        Priority::Priority7,
+        SEATALK_KEYSTROKE,
+    )
+    .with_manufacturer(RAYMARINE_MANUFACTURER_CODE),
+    PgnDescriptor::new(126983, "Alert", Transport::Fast, Priority::Priority2, ALERT),
+    PgnDescriptor::new(
+        126984,
+        "Alert Response",
+        Transport::Fast,
+        Priority::Priority2,
+        ALERT_RESPONSE,
+    ),
+    PgnDescriptor::new(
+        126985,
+        "Alert Text",
+        Transport::Fast,
+        Priority::Priority2,
+        ALERT_TEXT,
+    ),
+    PgnDescriptor::new(
+        126986,
+        "Alert Configuration",
+        Transport::Fast,
+        Priority::Priority2,
+        ALERT_CONFIGURATION,
+    ),
+    PgnDescriptor::new(
+        126987,
+        "Alert Threshold",
+        Transport::Fast,
+        Priority::Priority2,
+        ALERT_THRESHOLD,
+    )
+    .with_repeating(Some(9), 10),
+    PgnDescriptor::new(
+        126988,
+        "Alert Value",
+        Transport::Fast,
+        Priority::Priority2,
+        ALERT_VALUE,
+    )
+    .with_repeating(Some(9), 10),
+    PgnDescriptor::new(
+        126992,
+        "System Time",
+        Transport::Single,
+        Priority::Priority3,
+        SYSTEM_TIME,
+    )
+    .with_interval(1000),
+    PgnDescriptor::new(
+        126993,
+        "Heartbeat",
+        Transport::Single,
+        Priority::Priority7,
+        HEARTBEAT,
+    )
+    .with_interval(60000),
+    PgnDescriptor::new(
+        126996,
+        "Product Information",
+        Transport::Fast,
+        Priority::Priority6,
+        PRODUCT_INFORMATION,
+    ),
+    PgnDescriptor::new(
+        126998,
+        "Configuration Information",
+        Transport::Fast,
+        Priority::Priority6,
+        CONFIGURATION_INFORMATION,
+    ),
+    PgnDescriptor::new(
+        127233,
+        "Man Overboard Notification (MOB)",
+        Transport::Fast,
+        Priority::Priority3,
+        MAN_OVERBOARD_NOTIFICATION,
+    ),
+    PgnDescriptor::new(
+        127237,
+        "Heading/Track Control",
+        Transport::Fast,
+        Priority::Priority2,
+        HEADING_TRACK_CONTROL,
+    )
+    .with_interval(250),
+    PgnDescriptor::new(
+        127245,
+        "Rudder",
+        Transport::Single,
+        Priority::Priority2,
+        RUDDER,
+    )
+    .with_interval(100),
+    PgnDescriptor::new(
+        127252,
+        "Heave",
+        Transport::Single,
+        Priority::Priority3,
+        HEAVE,
+    )
+    .with_interval(100),
+    PgnDescriptor::new(
+        127488,
+        "Engine Parameters, Rapid Update",
+        Transport::Single,
+        Priority::Priority2,
+        ENGINE_RAPID_UPDATE,
+    )
+    .with_interval(100),
+    PgnDescriptor::new(
+        127489,
+        "Engine Parameters, Dynamic",
+        Transport::Fast,
+        Priority::Priority2,
+        ENGINE_DYNAMIC_PARAMETERS,
+    )
+    .with_interval(500),
+    PgnDescriptor::new(
+        127493,
+        "Transmission Parameters, Dynamic",
+        Transport::Single,
+        Priority::Priority2,
+        TRANSMISSION_PARAMETERS,
+    )
+    .with_interval(100),
+    PgnDescriptor::new(
+        127496,
+        "Trip Parameters, Vessel",
+        Transport::Fast,
+        Priority::Priority5,
+        TRIP_PARAMETERS_VESSEL,
+    )
+    .with_interval(1000),
+    PgnDescriptor::new(
+        127497,
+        "Trip Parameters, Engine",
+        Transport::Fast,
+        Priority::Priority5,
+        TRIP_PARAMETERS_ENGINE,
+    )
+    .with_interval(1000),
+    PgnDescriptor::new(
+        127501,
+        "Binary Switch Bank Status",
+        Transport::Single,
+        Priority::Priority3,
+        BINARY_SWITCH_BANK_STATUS,
+    )
+    .with_interval(2000)
+    .with_repeating(None, 1),
+    PgnDescriptor::new(
+        127502,
+        "Switch Bank Control",
+        Transport::Single,
+        Priority::Priority3,
+        SWITCH_BANK_CONTROL,
+    )
+    .with_repeating(None, 1),
+    PgnDescriptor::new(
+        127503,
+        "AC Input Status",
+        Transport::Fast,
+        Priority::Priority6,
+        AC_INPUT_STATUS,
+    )
+    .with_repeating(Some(1), 2),
+    PgnDescriptor::new(
+        127504,
+        "AC Output Status",
+        Transport::Fast,
+        Priority::Priority6,
+        AC_OUTPUT_STATUS,
+    )
+    .with_repeating(Some(1), 2),
+    PgnDescriptor::new(
+        127505,
+        "Fluid Level",
+        Transport::Single,
+        Priority::Priority6,
+        FLUID_LEVEL,
+    )
+    .with_interval(2500),
+    PgnDescriptor::new(
+        127506,
+        "DC Detailed Status",
+        Transport::Fast,
+        Priority::Priority6,
+        DC_DETAILED_STATUS,
+    )
+    .with_interval(1500),
+    PgnDescriptor::new(
+        127507,
+        "Charger Status",
+        Transport::Fast,
+        Priority::Priority6,
+        CHARGER_STATUS,
+    )
+    .with_interval(1500),
+    PgnDescriptor::new(
+        127508,
+        "Battery Status",
+        Transport::Single,
+        Priority::Priority6,
+        BATTERY_STATUS,
+    )
+    .with_interval(1500),
+    PgnDescriptor::new(
+        127509,
+        "Inverter Status",
+        Transport::Fast,
+        Priority::Priority6,
+        INVERTER_STATUS,
+    )
+    .with_interval(1500),
+    PgnDescriptor::new(
+        127513,
+        "Battery Configuration Status",
+        Transport::Fast,
+        Priority::Priority6,
+        BATTERY_CONFIGURATION_STATUS,
+    ),
+    PgnDescriptor::new(
+        127750,
+        "Converter Status",
+        Transport::Single,
+        Priority::Priority6,
+        CONVERTER_STATUS,
+    ),
+    PgnDescriptor::new(
+        127751,
+        "DC Voltage/Current",
+        Transport::Single,
+        Priority::Priority6,
+        DC_VOLTAGE_CURRENT,
+    ),
+    PgnDescriptor::new(
+        128000,
+        "Nautical Leeway Angle",
+        Transport::Single,
+        Priority::Priority4,
+        LEEWAY_ANGLE,
+    ),
+    PgnDescriptor::new(
+        128006,
+        "Thruster Control Status",
+        Transport::Single,
+        Priority::Priority2,
+        THRUSTER_CONTROL_STATUS,
+    )
+    .with_interval(100),
+    PgnDescriptor::new(
+        128007,
+        "Thruster Information",
+        Transport::Single,
+        Priority::Priority6,
+        THRUSTER_INFORMATION,
+    ),
+    PgnDescriptor::new(
+        128008,
+        "Thruster Motor Status",
+        Transport::Single,
+        Priority::Priority6,
+        THRUSTER_MOTOR_STATUS,
+    )
+    .with_interval(500),
+    PgnDescriptor::new(
+        128776,
+        "Anchor Windlass Control Status",
+        Transport::Single,
+        Priority::Priority2,
+        WINDLASS_CONTROL_STATUS,
+    ),
+    PgnDescriptor::new(
+        128777,
+        "Anchor Windlass Operating Status",
+        Transport::Single,
+        Priority::Priority2,
+        WINDLASS_OPERATING_STATUS,
+    ),
+    PgnDescriptor::new(
+        128778,
+        "Anchor Windlass Monitoring Status",
+        Transport::Single,
+        Priority::Priority2,
+        WINDLASS_MONITORING_STATUS,
+    ),
+    PgnDescriptor::new(
+        129038,
+        "AIS Class A Position Report",
+        Transport::Fast,
+        Priority::Priority4,
+        AIS_CLASS_A_POSITION_REPORT,
+    ),
+    PgnDescriptor::new(
+        129039,
+        "AIS Class B Position Report",
+        Transport::Fast,
+        Priority::Priority4,
+        AIS_CLASS_B_POSITION_REPORT,
+    ),
+    PgnDescriptor::new(
+        129040,
+        "AIS Class B Extended Position Report",
+        Transport::Fast,
+        Priority::Priority4,
+        AIS_CLASS_B_EXTENDED_POSITION_REPORT,
+    ),
+    PgnDescriptor::new(
+        129041,
+        "AIS Aids to Navigation (AtoN) Report",
+        Transport::Fast,
+        Priority::Priority4,
+        AIS_ATON_REPORT,
+    ),
+    PgnDescriptor::new(
+        129283,
+        "Cross Track Error",
+        Transport::Single,
+        Priority::Priority3,
+        CROSS_TRACK_ERROR,
+    )
+    .with_interval(1000),
+    PgnDescriptor::new(
+        129284,
+        "Navigation Data",
+        Transport::Fast,
+        Priority::Priority3,
+        NAVIGATION_DATA,
+    )
+    .with_interval(1000),
+    PgnDescriptor::new(
+        129285,
+        "Navigation - Route/WP Information",
+        Transport::Fast,
+        Priority::Priority6,
+        ROUTE_INFORMATION,
+    )
+    .with_repeating(Some(1), 9),
+    PgnDescriptor::new(
+        129291,
+        "Set & Drift, Rapid Update",
+        Transport::Single,
+        Priority::Priority3,
+        SET_AND_DRIFT,
+    )
+    .with_interval(1000),
+    PgnDescriptor::new(
+        129301,
+        "Time to/from Mark",
+        Transport::Fast,
+        Priority::Priority3,
+        TIME_TO_MARK,
+    )
+    .with_interval(1000),
+    PgnDescriptor::new(
+        129302,
+        "Bearing and Distance between two Marks",
+        Transport::Fast,
+        Priority::Priority6,
+        BEARING_DISTANCE_BETWEEN_MARKS,
+    )
+    .with_interval(1000),
+    PgnDescriptor::new(
+        129793,
+        "AIS UTC and Date Report",
+        Transport::Fast,
+        Priority::Priority7,
+        AIS_UTC_DATE_REPORT,
+    ),
+    PgnDescriptor::new(
+        129794,
+        "AIS Class A Static and Voyage Related Data",
+        Transport::Fast,
+        Priority::Priority6,
+        AIS_CLASS_A_STATIC_DATA,
+    ),
+    PgnDescriptor::new(
+        129798,
+        "AIS SAR Aircraft Position Report",
+        Transport::Fast,
+        Priority::Priority4,
+        AIS_SAR_AIRCRAFT_POSITION_REPORT,
+    ),
+    PgnDescriptor::new(
+        129799,
+        "Radio Frequency/Mode/Power",
+        Transport::Fast,
+        Priority::Priority3,
+        RADIO_FREQUENCY_MODE_POWER,
+    ),
+    PgnDescriptor::new(
+        129801,
+        "AIS Addressed Safety Related Message",
+        Transport::Fast,
+        Priority::Priority5,
+        AIS_ADDRESSED_SAFETY_MESSAGE,
+    ),
+    PgnDescriptor::new(
+        129802,
+        "AIS Safety Related Broadcast Message",
+        Transport::Fast,
+        Priority::Priority5,
+        AIS_SAFETY_BROADCAST_MESSAGE,
+    ),
+    PgnDescriptor::new(
+        129808,
+        "DSC Call Information",
+        Transport::Fast,
+        Priority::Priority4,
+        DSC_CALL_INFORMATION,
+    )
+    .with_repeating(None, 20),
+    PgnDescriptor::new(
+        129809,
+        "AIS Class B \"CS\" Static Data Report, Part A",
+        Transport::Fast,
+        Priority::Priority6,
+        AIS_CLASS_B_STATIC_DATA_PART_A,
+    ),
+    PgnDescriptor::new(
+        129810,
+        "AIS Class B \"CS\" Static Data Report, Part B",
+        Transport::Fast,
+        Priority::Priority6,
+        AIS_CLASS_B_STATIC_DATA_PART_B,
+    ),
+    PgnDescriptor::new(
+        130074,
+        "Route and WP Service - WP List - WP Name & Position",
+        Transport::Fast,
+        Priority::Priority7,
+        WAYPOINT_LIST,
+    )
+    .with_repeating(Some(1), 5),
+    PgnDescriptor::new(
+        130310,
+        "Environmental Parameters (obsolete)",
+        Transport::Single,
+        Priority::Priority5,
+        OUTSIDE_ENVIRONMENTAL_PARAMETERS,
+    )
+    .with_interval(500),
+    PgnDescriptor::new(
+        130311,
+        "Environmental Parameters",
+        Transport::Single,
+        Priority::Priority5,
+        ENVIRONMENTAL_PARAMETERS,
+    )
+    .with_interval(500),
+    PgnDescriptor::new(
+        130312,
+        "Temperature",
+        Transport::Single,
+        Priority::Priority5,
+        TEMPERATURE,
+    )
+    .with_interval(2000),
+    PgnDescriptor::new(
+        130313,
+        "Humidity",
+        Transport::Single,
+        Priority::Priority5,
+        HUMIDITY,
+    )
+    .with_interval(2000),
+    PgnDescriptor::new(
+        130314,
+        "Actual Pressure",
+        Transport::Single,
+        Priority::Priority5,
+        ACTUAL_PRESSURE,
+    )
+    .with_interval(2000),
+    PgnDescriptor::new(
+        130315,
+        "Set Pressure",
+        Transport::Single,
+        Priority::Priority5,
+        SET_PRESSURE,
+    ),
+    PgnDescriptor::new(
+        130316,
+        "Temperature Extended Range",
+        Transport::Single,
+        Priority::Priority5,
+        TEMPERATURE_EXTENDED_RANGE,
+    )
+    .with_interval(2000),
+    PgnDescriptor::new(
+        130320,
+        "Tide Station Data",
+        Transport::Fast,
+        Priority::Priority6,
+        TIDE_STATION_DATA,
+    ),
+    PgnDescriptor::new(
+        130321,
+        "Salinity Station Data",
+        Transport::Fast,
+        Priority::Priority6,
+        SALINITY_STATION_DATA,
+    ),
+    PgnDescriptor::new(
+        130322,
+        "Current Station Data",
+        Transport::Fast,
+        Priority::Priority6,
+        CURRENT_STATION_DATA,
+    ),
+    PgnDescriptor::new(
+        130323,
+        "Meteorological Station Data",
+        Transport::Fast,
+        Priority::Priority6,
+        METEOROLOGICAL_STATION_DATA,
+    ),
+    PgnDescriptor::new(
+        130569,
+        "Entertainment - Current File and Status",
+        Transport::Fast,
+        Priority::Priority6,
+        ENTERTAINMENT_CURRENT_FILE_STATUS,
+    ),
+    PgnDescriptor::new(
+        130570,
+        "Entertainment - Library Data File",
+        Transport::Fast,
+        Priority::Priority6,
+        ENTERTAINMENT_LIBRARY_DATA_FILE,
+    ),
+    PgnDescriptor::new(
+        130571,
+        "Entertainment - Library Data Group",
+        Transport::Fast,
+        Priority::Priority6,
+        ENTERTAINMENT_LIBRARY_DATA_GROUP,
+    )
+    .with_repeating(Some(6), 8),
+    PgnDescriptor::new(
+        130572,
+        "Entertainment - Library Data Search",
+        Transport::Fast,
+        Priority::Priority6,
+        ENTERTAINMENT_LIBRARY_DATA_SEARCH,
+    ),
+    PgnDescriptor::new(
+        130573,
+        "Entertainment - Supported Source Data",
+        Transport::Fast,
+        Priority::Priority6,
+        ENTERTAINMENT_SUPPORTED_SOURCE_DATA,
+    )
+    .with_repeating(Some(1), 3),
+    PgnDescriptor::new(
+        130576,
+        "Small Craft Status",
+        Transport::Single,
+        Priority::Priority2,
+        SMALL_CRAFT_STATUS,
+    )
+    .with_interval(100),
+    PgnDescriptor::new(
+        130577,
+        "Direction Data",
+        Transport::Fast,
+        Priority::Priority3,
+        DIRECTION_DATA,
+    )
+    .with_interval(1000),
+    PgnDescriptor::new(
+        130578,
+        "Vessel Speed Components",
+        Transport::Fast,
+        Priority::Priority2,
+        VESSEL_SPEED_COMPONENTS,
+    )
+    .with_interval(250),
+    PgnDescriptor::new(
+        130820,
+        "Manufacturer Proprietary fast-packet non-addressed",
+        Transport::Fast,
+        Priority::Priority7,
+        PROPRIETARY130820,
+    ),
+    PgnDescriptor::new(
+        130845,
+        "Simnet: Key Value",
+        Transport::Fast,
+        Priority::Priority7,
+        SIMNET_KEY_VALUE,
+    )
+    .with_manufacturer(SIMRAD_MANUFACTURER_CODE),
+    PgnDescriptor::new(
+        130850,
+        "Simnet: AP command",
+        Transport::Fast,
+        Priority::Priority2,
+        SIMNET_AP_COMMAND,
+    )
+    .with_manufacturer(SIMRAD_MANUFACTURER_CODE),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::GENERATED_CATALOGUE;
+    use crate::pgns::*;
+    use crate::Priority;
+
+    #[test]
+    fn catalogue_matches_pgns() {
+        macro_rules! check {
+            ($($pgn:ty),*) => {$(
+                let descriptor = PgnDescriptor::find(<$pgn>::PGN).unwrap();
+                assert_eq!(descriptor.priority, <$pgn>::PRIORITY);
+                assert_eq!(descriptor.transport == Transport::Fast, <$pgn>::FAST_PACKET);
+            )*};
+        }
+        check!(
+            AcPower<65016>,
+            Alert,
+            DscCallInformation,
+            EngineRapidUpdate,
+            Heave,
+            SeatalkKeystroke,
+            SimnetApCommand,
+            TemperatureExtendedRange,
+            WaypointList
+        );
+
+        // `find` binary searches both tables
+        for pair in CATALOGUE.windows(2).chain(GENERATED_CATALOGUE.windows(2)) {
+            assert!(pair[0].pgn <= pair[1].pgn);
+        }
+        for descriptor in CATALOGUE {
+            let fixed = descriptor.fixed_fields();
+            if fixed.iter().all(|field| !field.is_variable()) {
+                let bits: usize = fixed.iter().map(|field| field.bits as usize).sum();
+                assert_eq!(bits % 8, 0, "{}", descriptor.name);
+            }
+            if descriptor.transport == Transport::Single {
+                assert!(descriptor.min_length() <= 8, "{}", descriptor.name);
+            }
+        }
+    }
+
+    #[test]
+    fn lookup_values_have_display_names() {
+        // names that are written together
+        const PROPER_NAMES: &[&str] = &["AirPlay", "NiCd", "NiMH", "ZnO"];
+        // a name run together like an identifier, such as "AltimeterSetting"
+        let identifier = |name: &str| {
+            let chars = name.as_bytes();
+            name.contains('_')
+                || !name.contains(' ')
+                    && chars[0].is_ascii_uppercase()
+                    && chars
+                        .windows(2)
+                        .any(|pair| pair[0].is_ascii_lowercase() && pair[1].is_ascii_uppercase())
+        };
+        let lookups = CATALOGUE
+            .iter()
+            .flat_map(|descriptor| descriptor.fields)
+            .filter_map(|field| match field.kind {
+                FieldKind::Lookup(values) => Some(values),
+                _ => None,
+            });
+        // lookups of messages without a catalogue layout
+        let others = [
+            DscNature::VALUES,
+            FusionMuteState::VALUES,
+            FusionPowerState::VALUES,
+            FusionSourceType::VALUES,
+            GarminColor::VALUES,
+        ];
+        for values in lookups.chain(others.iter().copied()) {
+            for &(value, name) in values {
+                assert!(!name.is_empty(), "{}", value);
+                let readable = !identifier(name) || PROPER_NAMES.contains(&name);
+                assert!(readable, "{} = {}", name, value);
+            }
+        }
+    }
+
+    #[test]
+    fn descriptor_lookup() {
+        let heave = PgnDescriptor::find(127252).unwrap();
+        assert_eq!(heave.name, "Heave");
+        assert_eq!(heave.priority, Priority::Priority3);
+        assert_eq!(heave.interval, Some(100));
+        assert_eq!(heave.min_length(), 8);
+        assert_eq!(heave.validate(&[0; 8]), Ok(()));
+        assert_eq!(heave.validate(&[0; 7]), Err(PgnError::DataTooShort));
+        assert!(PgnDescriptor::find(12345).is_none());
+
+        let mut buf = [0; 16];
+        let mode = GarminColorMode::Color(GarminColor::NightRedBlack);
+        let len = mode.encode(&mut buf).unwrap();
+        let garmin = PgnDescriptor::for_message(126720, &buf[..len]).unwrap();
+        assert_eq!(garmin.manufacturer_code, Some(229));
+        assert_eq!(garmin.min_length(), len);
+        let seatalk = PgnDescriptor::for_message(126720, &[0x3b, 0x9f, 0xf0, 0x81]).unwrap();
+        assert_eq!(seatalk.name, "Seatalk1: Keystroke");
+        // manufacturer 1 has no 126720 messages, even in the canboat database
+        assert!(PgnDescriptor::for_message(126720, &[0x01, 0x98, 0x00]).is_none());
+
+        let route = PgnDescriptor::find(129285).unwrap();
+        let repeating = route.repeating.unwrap();
+        assert_eq!(route.fields[repeating.count.unwrap()].name, "Waypoints");
+        assert_eq!(route.repeating_fields()[0].name, "WP ID");
+    }
+
+    #[test]
+    fn transport_select() {
+        struct TestCase {
+            pgn: u32,
+            length: usize,
+            transport: Transport,
+        }
+        let test_cases = [
+            TestCase {
+                pgn: 127252,
+                length: 8,
+                transport: Transport::Single,
+            },
+            TestCase {
+                pgn: 129284,
+                length: 8,
+                transport: Transport::Fast,
+            },
+            TestCase {
+                pgn: 130900,
+                length: 4,
+                transport: Transport::Fast,
+            },
+            TestCase {
+                pgn: 12345,
+                length: 7,
+                transport: Transport::Single,
+            },
+            TestCase {
+                pgn: 12345,
+                length: 17,
+                transport: Transport::IsoTp,
+            },
+        ];
+        for case in &test_cases {
+            assert_eq!(Transport::select(case.pgn, case.length), case.transport);
+        }
+    }
+}
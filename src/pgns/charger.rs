@@ -0,0 +1,288 @@
+use crate::pgns::field::{Reader, Writer};
+use crate::pgns::{GoodWarningError, OffOn, Pgn, Result};
+use crate::Priority;
+
+lookup! {
+    pub enum ChargerState: u8 {
+        NotCharging = 0 => "Not charging",
+        Bulk = 1,
+        Absorption = 2,
+        Overcharge = 3,
+        Equalise = 4,
+        Float = 5,
+        NoFloat = 6 => "No float",
+        ConstantVi = 7 => "Constant VI",
+        Disabled = 8,
+        Fault = 9,
+    }
+}
+
+lookup! {
+    pub enum ChargerMode: u8 {
+        Standalone = 0,
+        Primary = 1,
+        Secondary = 2,
+        Echo = 3,
+    }
+}
+
+lookup! {
+    pub enum InverterState: u8 {
+        Invert = 0,
+        AcPassthru = 1 => "AC passthru",
+        LoadSense = 2 => "Load sense",
+        Fault = 3,
+        Disabled = 4,
+    }
+}
+
+lookup! {
+    pub enum ConverterState: u8 {
+        Off = 0,
+        LowPowerMode = 1 => "Low Power Mode",
+        Fault = 2,
+        Bulk = 3,
+        Absorption = 4,
+        Float = 5,
+        Storage = 6,
+        Equalize = 7,
+        PassThru = 8 => "Pass thru",
+        Inverting = 9,
+        Assisting = 10,
+    }
+}
+
+/// PGN 127507 - Charger Status
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChargerStatus {
+    pub instance: u8,
+    pub battery_instance: u8,
+    pub operating_state: ChargerState,
+    pub charge_mode: ChargerMode,
+    pub enabled: OffOn,
+    pub equalization_pending: OffOn,
+    /// Seconds
+    pub equalization_time_remaining: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for ChargerStatus {
+    const PGN: u32 = 127507;
+    const PRIORITY: Priority = Priority::Priority6;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let instance = r.u8()?;
+        let battery_instance = r.u8()?;
+        let operating_state = r.lookup(4)?;
+        let charge_mode = r.lookup(4)?;
+        let enabled = r.lookup(2)?;
+        let equalization_pending = r.lookup(2)?;
+        r.reserved(4)?;
+        Ok(ChargerStatus {
+            instance,
+            battery_instance,
+            operating_state,
+            charge_mode,
+            enabled,
+            equalization_pending,
+            equalization_time_remaining: r.ufix(16, 60.0)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.instance)?;
+        w.u8(self.battery_instance)?;
+        w.lookup(4, self.operating_state)?;
+        w.lookup(4, self.charge_mode)?;
+        w.lookup(2, self.enabled)?;
+        w.lookup(2, self.equalization_pending)?;
+        w.reserved(4)?;
+        w.ufix(16, 60.0, self.equalization_time_remaining)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 127509 - Inverter Status
+#[derive(Clone, Debug, PartialEq)]
+pub struct InverterStatus {
+    pub instance: u8,
+    pub ac_instance: u8,
+    pub dc_instance: u8,
+    pub operating_state: InverterState,
+    pub enabled: OffOn,
+}
+
+impl<'a> Pgn<'a> for InverterStatus {
+    const PGN: u32 = 127509;
+    const PRIORITY: Priority = Priority::Priority6;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(InverterStatus {
+            instance: r.u8()?,
+            ac_instance: r.u8()?,
+            dc_instance: r.u8()?,
+            operating_state: r.lookup(4)?,
+            enabled: r.lookup(2)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.instance)?;
+        w.u8(self.ac_instance)?;
+        w.u8(self.dc_instance)?;
+        w.lookup(4, self.operating_state)?;
+        w.lookup(2, self.enabled)?;
+        w.reserved(2)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 127750 - Converter Status
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConverterStatus {
+    pub sid: u8,
+    pub connection_number: u8,
+    pub operating_state: ConverterState,
+    pub temperature_state: GoodWarningError,
+    pub overload_state: GoodWarningError,
+    pub low_dc_voltage_state: GoodWarningError,
+    pub ripple_state: GoodWarningError,
+}
+
+impl<'a> Pgn<'a> for ConverterStatus {
+    const PGN: u32 = 127750;
+    const PRIORITY: Priority = Priority::Priority6;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(ConverterStatus {
+            sid: r.u8()?,
+            connection_number: r.u8()?,
+            operating_state: r.lookup(8)?,
+            temperature_state: r.lookup(2)?,
+            overload_state: r.lookup(2)?,
+            low_dc_voltage_state: r.lookup(2)?,
+            ripple_state: r.lookup(2)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.u8(self.connection_number)?;
+        w.lookup(8, self.operating_state)?;
+        w.lookup(2, self.temperature_state)?;
+        w.lookup(2, self.overload_state)?;
+        w.lookup(2, self.low_dc_voltage_state)?;
+        w.lookup(2, self.ripple_state)?;
+        w.reserved(32)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 127751 - DC Voltage/Current
+#[derive(Clone, Debug, PartialEq)]
+pub struct DcVoltageCurrent {
+    pub sid: u8,
+    pub connection_number: u8,
+    /// Volts
+    pub voltage: Option<f32>,
+    /// Amperes
+    pub current: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for DcVoltageCurrent {
+    const PGN: u32 = 127751;
+    const PRIORITY: Priority = Priority::Priority6;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(DcVoltageCurrent {
+            sid: r.u8()?,
+            connection_number: r.u8()?,
+            voltage: r.ufix(16, 0.1)?,
+            current: r.sfix(24, 0.01)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.u8(self.connection_number)?;
+        w.ufix(16, 0.1, self.voltage)?;
+        w.sfix(24, 0.01, self.current)?;
+        w.reserved(8)?;
+        Ok(w.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pgns::{
+        ChargerMode, ChargerState, ChargerStatus, DcVoltageCurrent, InverterState, InverterStatus,
+        OffOn, Pgn,
+    };
+
+    #[test]
+    fn charger_status_decode() {
+        struct TestCase {
+            data: [u8; 6],
+            operating_state: ChargerState,
+            charge_mode: ChargerMode,
+            enabled: OffOn,
+        }
+        let test_cases = [
+            TestCase {
+                data: [0x00, 0x01, 0x01, 0xf1, 0xff, 0xff],
+                operating_state: ChargerState::Bulk,
+                charge_mode: ChargerMode::Standalone,
+                enabled: OffOn::On,
+            },
+            TestCase {
+                data: [0x00, 0x01, 0x18, 0xf0, 0xff, 0xff],
+                operating_state: ChargerState::Disabled,
+                charge_mode: ChargerMode::Primary,
+                enabled: OffOn::Off,
+            },
+        ];
+        for i in &test_cases {
+            let status = ChargerStatus::decode(&i.data).unwrap();
+            assert_eq!(status.operating_state, i.operating_state);
+            assert_eq!(status.charge_mode, i.charge_mode);
+            assert_eq!(status.enabled, i.enabled);
+            assert_eq!(status.equalization_pending, OffOn::Off);
+            assert_eq!(status.equalization_time_remaining, None);
+
+            let mut buf = [0; 6];
+            status.encode(&mut buf).unwrap();
+            assert_eq!(buf, i.data);
+        }
+    }
+
+    #[test]
+    fn inverter_status_encode() {
+        let status = InverterStatus {
+            instance: 0,
+            ac_instance: 1,
+            dc_instance: 2,
+            operating_state: InverterState::AcPassthru,
+            enabled: OffOn::On,
+        };
+        let mut buf = [0; 4];
+        assert_eq!(status.encode(&mut buf).unwrap(), 4);
+        assert_eq!(buf, [0x00, 0x01, 0x02, 0xd1]);
+    }
+
+    #[test]
+    fn dc_voltage_current_decode() {
+        let data = [0x00, 0x02, 0x8a, 0x00, 0x18, 0xfc, 0xff, 0xff];
+        let dc = DcVoltageCurrent::decode(&data).unwrap();
+        assert_eq!(dc.voltage, Some(13.8));
+        assert_eq!(dc.current, Some(-10.0));
+    }
+}
@@ -0,0 +1,94 @@
+use core::fmt::{self, Write};
+
+lookup! {
+    pub enum YesNo: u8 {
+        No = 0,
+        Yes = 1,
+    }
+}
+
+lookup! {
+    pub enum OffOn: u8 {
+        Off = 0,
+        On = 1,
+    }
+}
+
+lookup! {
+    pub enum GoodWarningError: u8 {
+        Good = 0,
+        Warning = 1,
+        Error = 2,
+    }
+}
+
+lookup! {
+    pub enum DirectionReference: u8 {
+        True = 0,
+        Magnetic = 1,
+        Error = 2,
+    }
+}
+
+/// Variable length string in the encoding chosen by its sender.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum LauString<'a> {
+    /// ASCII or UTF-8 text.
+    Utf8(&'a str),
+    /// UTF-16 text as received, in little endian code units.
+    Utf16(&'a [u8]),
+}
+
+impl<'a> LauString<'a> {
+    /// The text if it was sent as ASCII or UTF-8.
+    pub fn as_str(&self) -> Option<&'a str> {
+        match *self {
+            LauString::Utf8(text) => Some(text),
+            LauString::Utf16(_) => None,
+        }
+    }
+
+    /// Length of the encoded text in bytes.
+    pub fn len(&self) -> usize {
+        match *self {
+            LauString::Utf8(text) => text.len(),
+            LauString::Utf16(data) => data.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn utf16(data: &[u8]) -> impl Iterator<Item = char> + '_ {
+        let units = data
+            .chunks_exact(2)
+            .map(|unit| u16::from_le_bytes([unit[0], unit[1]]));
+        core::char::decode_utf16(units).map(|c| c.unwrap_or(core::char::REPLACEMENT_CHARACTER))
+    }
+}
+
+impl<'a> From<&'a str> for LauString<'a> {
+    fn from(text: &'a str) -> Self {
+        LauString::Utf8(text)
+    }
+}
+
+impl PartialEq<&str> for LauString<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        match *self {
+            LauString::Utf8(text) => text == *other,
+            LauString::Utf16(data) => Self::utf16(data).eq(other.chars()),
+        }
+    }
+}
+
+impl fmt::Display for LauString<'_> {
+    /// Writes the text, replacing invalid UTF-16 with U+FFFD.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LauString::Utf8(text) => f.write_str(text),
+            LauString::Utf16(data) => Self::utf16(data).try_for_each(|c| f.write_char(c)),
+        }
+    }
+}
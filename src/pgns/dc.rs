@@ -0,0 +1,251 @@
+use crate::pgns::field::{Reader, Writer};
+use crate::pgns::{Pgn, Result, YesNo};
+use crate::Priority;
+
+lookup! {
+    pub enum DcType: u8 {
+        Battery = 0,
+        Alternator = 1,
+        Convertor = 2,
+        SolarCell = 3 => "Solar cell",
+        WindGenerator = 4 => "Wind generator",
+    }
+}
+
+lookup! {
+    pub enum BatteryType: u8 {
+        Flooded = 0,
+        Gel = 1,
+        Agm = 2 => "AGM",
+    }
+}
+
+lookup! {
+    pub enum BatteryVoltage: u8 {
+        Volts6 = 0 => "6V",
+        Volts12 = 1 => "12V",
+        Volts24 = 2 => "24V",
+        Volts32 = 3 => "32V",
+        Volts36 = 4 => "36V",
+        Volts42 = 5 => "42V",
+        Volts48 = 6 => "48V",
+    }
+}
+
+lookup! {
+    pub enum BatteryChemistry: u8 {
+        Lead = 0 => "Pb (Lead)",
+        LithiumIon = 1 => "Li",
+        NickelCadmium = 2 => "NiCd",
+        ZincOxide = 3 => "ZnO",
+        NickelMetalHydride = 4 => "NiMH",
+    }
+}
+
+/// PGN 127506 - DC Detailed Status
+#[derive(Clone, Debug, PartialEq)]
+pub struct DcDetailedStatus {
+    pub sid: u8,
+    pub instance: u8,
+    pub dc_type: DcType,
+    /// Percent
+    pub state_of_charge: Option<u8>,
+    /// Percent
+    pub state_of_health: Option<u8>,
+    /// Seconds
+    pub time_remaining: Option<f32>,
+    /// Volts
+    pub ripple_voltage: Option<f32>,
+    /// Amp hours
+    pub remaining_capacity: Option<u16>,
+}
+
+impl<'a> Pgn<'a> for DcDetailedStatus {
+    const PGN: u32 = 127506;
+    const PRIORITY: Priority = Priority::Priority6;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(DcDetailedStatus {
+            sid: r.u8()?,
+            instance: r.u8()?,
+            dc_type: r.lookup(8)?,
+            state_of_charge: r.uint(8)?.map(|v| v as u8),
+            state_of_health: r.uint(8)?.map(|v| v as u8),
+            time_remaining: r.ufix(16, 60.0)?,
+            ripple_voltage: r.ufix(16, 0.001)?,
+            remaining_capacity: r.uint(16)?.map(|v| v as u16),
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.u8(self.instance)?;
+        w.lookup(8, self.dc_type)?;
+        w.uint(8, self.state_of_charge.map(|v| v as u64))?;
+        w.uint(8, self.state_of_health.map(|v| v as u64))?;
+        w.ufix(16, 60.0, self.time_remaining)?;
+        w.ufix(16, 0.001, self.ripple_voltage)?;
+        w.uint(16, self.remaining_capacity.map(|v| v as u64))?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 127508 - Battery Status
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatteryStatus {
+    pub instance: u8,
+    /// Volts
+    pub voltage: Option<f32>,
+    /// Amperes
+    pub current: Option<f32>,
+    /// Kelvin
+    pub temperature: Option<f32>,
+    pub sid: u8,
+}
+
+impl<'a> Pgn<'a> for BatteryStatus {
+    const PGN: u32 = 127508;
+    const PRIORITY: Priority = Priority::Priority6;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(BatteryStatus {
+            instance: r.u8()?,
+            voltage: r.sfix(16, 0.01)?,
+            current: r.sfix(16, 0.1)?,
+            temperature: r.ufix(16, 0.01)?,
+            sid: r.u8()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.instance)?;
+        w.sfix(16, 0.01, self.voltage)?;
+        w.sfix(16, 0.1, self.current)?;
+        w.ufix(16, 0.01, self.temperature)?;
+        w.u8(self.sid)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 127513 - Battery Configuration Status
+#[derive(Clone, Debug, PartialEq)]
+pub struct BatteryConfigurationStatus {
+    pub instance: u8,
+    pub battery_type: BatteryType,
+    pub supports_equalization: YesNo,
+    pub nominal_voltage: BatteryVoltage,
+    pub chemistry: BatteryChemistry,
+    /// Amp hours
+    pub capacity: Option<u16>,
+    /// Percent
+    pub temperature_coefficient: Option<i8>,
+    pub peukert_exponent: Option<f32>,
+    /// Percent
+    pub charge_efficiency_factor: Option<i8>,
+}
+
+impl<'a> Pgn<'a> for BatteryConfigurationStatus {
+    const PGN: u32 = 127513;
+    const PRIORITY: Priority = Priority::Priority6;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let instance = r.u8()?;
+        let battery_type = r.lookup(4)?;
+        let supports_equalization = r.lookup(2)?;
+        r.reserved(2)?;
+        Ok(BatteryConfigurationStatus {
+            instance,
+            battery_type,
+            supports_equalization,
+            nominal_voltage: r.lookup(4)?,
+            chemistry: r.lookup(4)?,
+            capacity: r.uint(16)?.map(|v| v as u16),
+            temperature_coefficient: r.int(8)?.map(|v| v as i8),
+            // transmitted with an offset of 1, covering 1.0 to 1.5
+            peukert_exponent: r.ufix(8, 0.002)?.map(|v| v + 1.0),
+            charge_efficiency_factor: r.int(8)?.map(|v| v as i8),
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.instance)?;
+        w.lookup(4, self.battery_type)?;
+        w.lookup(2, self.supports_equalization)?;
+        w.reserved(2)?;
+        w.lookup(4, self.nominal_voltage)?;
+        w.lookup(4, self.chemistry)?;
+        w.uint(16, self.capacity.map(|v| v as u64))?;
+        w.int(8, self.temperature_coefficient.map(|v| v as i64))?;
+        w.ufix(8, 0.002, self.peukert_exponent.map(|v| v - 1.0))?;
+        w.int(8, self.charge_efficiency_factor.map(|v| v as i64))?;
+        Ok(w.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pgns::{
+        BatteryChemistry, BatteryConfigurationStatus, BatteryStatus, BatteryType, BatteryVoltage,
+        Pgn, YesNo,
+    };
+
+    #[test]
+    fn battery_status_decode() {
+        struct TestCase {
+            data: [u8; 8],
+            voltage: Option<f32>,
+            current: Option<f32>,
+        }
+        let test_cases = [
+            TestCase {
+                data: [0x00, 0xf6, 0x04, 0x9c, 0xff, 0xff, 0xff, 0x03],
+                voltage: Some(12.70),
+                current: Some(-10.0),
+            },
+            TestCase {
+                data: [0x01, 0xff, 0x7f, 0xff, 0x7f, 0x83, 0x72, 0x04],
+                voltage: None,
+                current: None,
+            },
+        ];
+        for i in &test_cases {
+            let status = BatteryStatus::decode(&i.data).unwrap();
+            assert_eq!(status.voltage, i.voltage);
+            assert_eq!(status.current, i.current);
+
+            let mut buf = [0; 8];
+            status.encode(&mut buf).unwrap();
+            assert_eq!(buf, i.data);
+        }
+    }
+
+    #[test]
+    fn battery_configuration_status_encode() {
+        let configuration = BatteryConfigurationStatus {
+            instance: 0,
+            battery_type: BatteryType::Agm,
+            supports_equalization: YesNo::No,
+            nominal_voltage: BatteryVoltage::Volts12,
+            chemistry: BatteryChemistry::Lead,
+            capacity: Some(400),
+            temperature_coefficient: Some(4),
+            peukert_exponent: Some(1.25),
+            charge_efficiency_factor: Some(95),
+        };
+        let mut buf = [0; 8];
+        assert_eq!(configuration.encode(&mut buf).unwrap(), 8);
+        assert_eq!(buf, [0x00, 0xc2, 0x01, 0x90, 0x01, 0x04, 0x7d, 0x5f]);
+        assert_eq!(
+            BatteryConfigurationStatus::decode(&buf).unwrap(),
+            configuration
+        );
+    }
+}
@@ -0,0 +1,398 @@
+use heapless::Vec;
+
+use crate::pgns::field::{Reader, Writer};
+use crate::pgns::{LauString, Pgn, PgnError, Result, YesNo};
+use crate::Priority;
+
+lookup! {
+    pub enum DscFormat: u8 {
+        GeographicalArea = 102 => "Geographical area",
+        Distress = 112,
+        CommonInterest = 114 => "Common interest",
+        AllShips = 116 => "All ships",
+        IndividualStations = 120 => "Individual stations",
+        NonCallingPurpose = 121 => "Non-calling purpose",
+        IndividualStationAutomatic = 123 => "Individual station automatic",
+    }
+}
+
+lookup! {
+    pub enum DscCategory: u8 {
+        Routine = 100,
+        Safety = 108,
+        Urgency = 110,
+        Distress = 112,
+    }
+}
+
+lookup! {
+    pub enum DscNature: u8 {
+        FireExplosion = 100 => "Fire",
+        Flooding = 101,
+        Collision = 102,
+        Grounding = 103,
+        Listing = 104,
+        Sinking = 105,
+        DisabledAndAdrift = 106 => "Disabled and adrift",
+        Undesignated = 107,
+        AbandoningShip = 108 => "Abandoning ship",
+        Piracy = 109,
+        ManOverboard = 110 => "Man overboard",
+        Epirb = 112 => "EPIRB emission",
+    }
+}
+
+lookup! {
+    pub enum TelephoneMode: u8 {
+        SimplexTelephone = 0 => "F3E/G3E simplex, telephone",
+        DuplexTelephone = 1 => "F3E/G3E duplex, telephone",
+        J3eTelephone = 2 => "J3E, telephone",
+        H3eTelephone = 3 => "H3E, telephone",
+        FecNbdpTelex = 4 => "F1B/J2B FEC NBDP, telex/teleprinter",
+        ArqNbdpTelex = 5 => "F1B/J2B ARQ NBDP, telex/teleprinter",
+        ReceiveOnlyTeleprinter = 6 => "F1B/J2B receive only, teleprinter/DSC",
+        Teleprinter = 7 => "F1B/J2B, teleprinter/DSC",
+        MorseTapeRecorder = 8 => "A1A Morse, tape recorder",
+        MorseKey = 9 => "A1A Morse, Morse key/head set",
+        Fax = 10 => "F1C/F2C/F3C, FAX machine",
+    }
+}
+
+// DSC addresses are five symbols of two decimal digits each, most
+// significant first.
+fn decode_address(r: &mut Reader) -> Result<Option<u64>> {
+    let mut value = 0;
+    let mut valid = true;
+    for symbol in r.bytes(5)? {
+        valid &= *symbol < 100;
+        value = value * 100 + *symbol as u64;
+    }
+    Ok(if valid { Some(value) } else { None })
+}
+
+fn encode_address(w: &mut Writer, value: Option<u64>) -> Result<()> {
+    match value {
+        Some(value) => {
+            let mut symbols = [0; 5];
+            let mut rest = value;
+            for symbol in symbols.iter_mut().rev() {
+                *symbol = (rest % 100) as u8;
+                rest /= 100;
+            }
+            w.bytes(&symbols)
+        }
+        None => w.reserved(40),
+    }
+}
+
+/// An expansion field of a DSC call, such as the enhanced position.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DscExpansion<'a> {
+    pub symbol: u8,
+    pub data: LauString<'a>,
+}
+
+/// PGN 129808 - DSC Call Information
+#[derive(Clone, Debug, PartialEq)]
+pub struct DscCallInformation<'a> {
+    pub format: DscFormat,
+    pub category: DscCategory,
+    /// Ten decimal digits: an MMSI followed by a 0, or an area for
+    /// geographical calls
+    pub address: Option<u64>,
+    /// The nature of distress for distress calls, the first telecommand
+    /// otherwise
+    pub first_telecommand: u8,
+    /// The subsequent communication mode for distress calls, the second
+    /// telecommand otherwise
+    pub second_telecommand: u8,
+    pub proposed_rx_channel: &'a str,
+    pub proposed_tx_channel: &'a str,
+    pub telephone_number: LauString<'a>,
+    /// Degrees
+    pub latitude: Option<f64>,
+    /// Degrees
+    pub longitude: Option<f64>,
+    /// Seconds since midnight
+    pub time_of_position: Option<f64>,
+    /// Ten decimal digits, as `address`
+    pub vessel_in_distress: Option<u64>,
+    pub end_of_sequence: u8,
+    pub expansion_enabled: YesNo,
+    pub calling_rx_channel: &'a str,
+    pub calling_tx_channel: &'a str,
+    /// Seconds since midnight
+    pub time_of_receipt: Option<f64>,
+    /// Days since 1970-01-01
+    pub date_of_receipt: Option<u16>,
+    pub equipment_message_id: u16,
+    pub expansions: Vec<DscExpansion<'a>, 8>,
+}
+
+impl<'a> DscCallInformation<'a> {
+    /// Returns the nature of distress of a distress call.
+    pub fn nature_of_distress(&self) -> Option<DscNature> {
+        if self.format == DscFormat::Distress || self.category == DscCategory::Distress {
+            Some(DscNature::from(self.first_telecommand))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> Pgn<'a> for DscCallInformation<'a> {
+    const PGN: u32 = 129808;
+    const PRIORITY: Priority = Priority::Priority4;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let format = r.lookup(8)?;
+        let category = r.lookup(8)?;
+        let address = decode_address(&mut r)?;
+        let first_telecommand = r.u8()?;
+        let second_telecommand = r.u8()?;
+        let proposed_rx_channel = r.string_fix(6)?;
+        let proposed_tx_channel = r.string_fix(6)?;
+        let telephone_number = r.string_lau()?;
+        let latitude = r.sfix64(32, 1e-7)?;
+        let longitude = r.sfix64(32, 1e-7)?;
+        let time_of_position = r.ufix64(32, 0.0001)?;
+        let vessel_in_distress = decode_address(&mut r)?;
+        let end_of_sequence = r.u8()?;
+        let expansion_enabled = r.lookup(2)?;
+        r.reserved(6)?;
+        let calling_rx_channel = r.string_fix(6)?;
+        let calling_tx_channel = r.string_fix(6)?;
+        let time_of_receipt = r.ufix64(32, 0.0001)?;
+        let date_of_receipt = r.uint(16)?.map(|v| v as u16);
+        let equipment_message_id = r.bits(16)? as u16;
+
+        let mut expansions = Vec::new();
+        let mut rest = Reader::new(r.rest());
+        while let Ok(symbol) = rest.u8() {
+            let expansion = DscExpansion {
+                symbol,
+                data: rest.string_lau()?,
+            };
+            expansions
+                .push(expansion)
+                .map_err(|_| PgnError::TooManyEntries)?;
+        }
+        Ok(DscCallInformation {
+            format,
+            category,
+            address,
+            first_telecommand,
+            second_telecommand,
+            proposed_rx_channel,
+            proposed_tx_channel,
+            telephone_number,
+            latitude,
+            longitude,
+            time_of_position,
+            vessel_in_distress,
+            end_of_sequence,
+            expansion_enabled,
+            calling_rx_channel,
+            calling_tx_channel,
+            time_of_receipt,
+            date_of_receipt,
+            equipment_message_id,
+            expansions,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.lookup(8, self.format)?;
+        w.lookup(8, self.category)?;
+        encode_address(&mut w, self.address)?;
+        w.u8(self.first_telecommand)?;
+        w.u8(self.second_telecommand)?;
+        w.string_fix(self.proposed_rx_channel, 6, b' ')?;
+        w.string_fix(self.proposed_tx_channel, 6, b' ')?;
+        w.string_lau(self.telephone_number)?;
+        w.sfix64(32, 1e-7, self.latitude)?;
+        w.sfix64(32, 1e-7, self.longitude)?;
+        w.ufix64(32, 0.0001, self.time_of_position)?;
+        encode_address(&mut w, self.vessel_in_distress)?;
+        w.u8(self.end_of_sequence)?;
+        w.lookup(2, self.expansion_enabled)?;
+        w.reserved(6)?;
+        w.string_fix(self.calling_rx_channel, 6, b' ')?;
+        w.string_fix(self.calling_tx_channel, 6, b' ')?;
+        w.ufix64(32, 0.0001, self.time_of_receipt)?;
+        w.uint(16, self.date_of_receipt.map(|v| v as u64))?;
+        w.bits(16, self.equipment_message_id as u64)?;
+        for expansion in &self.expansions {
+            w.u8(expansion.symbol)?;
+            w.string_lau(expansion.data)?;
+        }
+        Ok(w.len())
+    }
+}
+
+/// PGN 129799 - Radio Frequency/Mode/Power
+#[derive(Clone, Debug, PartialEq)]
+pub struct RadioFrequencyModePower<'a> {
+    /// Hertz
+    pub rx_frequency: Option<f64>,
+    /// Hertz
+    pub tx_frequency: Option<f64>,
+    pub channel: &'a str,
+    /// Watts
+    pub tx_power: Option<u16>,
+    pub mode: TelephoneMode,
+    /// Hertz
+    pub channel_bandwidth: Option<u16>,
+}
+
+impl<'a> Pgn<'a> for RadioFrequencyModePower<'a> {
+    const PGN: u32 = 129799;
+    const PRIORITY: Priority = Priority::Priority3;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(RadioFrequencyModePower {
+            rx_frequency: r.ufix64(32, 10.0)?,
+            tx_frequency: r.ufix64(32, 10.0)?,
+            channel: r.string_fix(6)?,
+            tx_power: r.uint(16)?.map(|v| v as u16),
+            mode: r.lookup(8)?,
+            channel_bandwidth: r.uint(16)?.map(|v| v as u16),
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.ufix64(32, 10.0, self.rx_frequency)?;
+        w.ufix64(32, 10.0, self.tx_frequency)?;
+        w.string_fix(self.channel, 6, b' ')?;
+        w.uint(16, self.tx_power.map(|v| v as u64))?;
+        w.lookup(8, self.mode)?;
+        w.uint(16, self.channel_bandwidth.map(|v| v as u64))?;
+        Ok(w.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use heapless::Vec;
+
+    use crate::pgns::{
+        DscCallInformation, DscCategory, DscExpansion, DscFormat, DscNature, Pgn, PgnError,
+        RadioFrequencyModePower, TelephoneMode, YesNo,
+    };
+
+    #[test]
+    fn dsc_call_information_decode() {
+        let mut data = [0xffu8; 66];
+        data[0] = 112; // distress
+        data[1] = 112;
+        data[2..7].copy_from_slice(&[24, 41, 23, 45, 60]);
+        data[7] = 101; // flooding
+        data[8] = 100;
+        data[9..21].copy_from_slice(b"16    16    ");
+        data[21..23].copy_from_slice(&[0x02, 0x01]);
+        data[23..31].copy_from_slice(&[0xff, 0xff, 0xff, 0x7f, 0xff, 0xff, 0xff, 0x7f]);
+        data[35..40].copy_from_slice(&[24, 41, 23, 45, 60]);
+        data[40] = 127;
+        data[41] = 0xfc;
+        data[42..54].copy_from_slice(b"70    70    ");
+        data[60..62].copy_from_slice(&[0x2a, 0x00]);
+        data[62..66].copy_from_slice(&[100, 0x03, 0x01, b'0']);
+
+        let call = DscCallInformation::decode(&data).unwrap();
+        assert_eq!(call.format, DscFormat::Distress);
+        assert_eq!(call.category, DscCategory::Distress);
+        assert_eq!(call.address, Some(2441234560));
+        assert_eq!(call.nature_of_distress(), Some(DscNature::Flooding));
+        assert_eq!(call.proposed_rx_channel, "16");
+        assert_eq!(call.telephone_number, "");
+        assert_eq!(call.latitude, None);
+        assert_eq!(call.vessel_in_distress, Some(2441234560));
+        assert_eq!(call.expansion_enabled, YesNo::No);
+        assert_eq!(call.calling_rx_channel, "70");
+        assert_eq!(call.date_of_receipt, None);
+        assert_eq!(call.equipment_message_id, 42);
+        assert_eq!(call.expansions.len(), 1);
+        assert_eq!(call.expansions[0].symbol, 100);
+        assert_eq!(call.expansions[0].data, "0");
+
+        let mut buf = [0; 223];
+        let len = call.encode(&mut buf).unwrap();
+        assert_eq!(&buf[..len], &data);
+    }
+
+    #[test]
+    fn dsc_call_information_roundtrip() {
+        let mut expansions = Vec::new();
+        expansions
+            .push(DscExpansion {
+                symbol: 106,
+                data: "4".into(),
+            })
+            .unwrap();
+        let call = DscCallInformation {
+            format: DscFormat::IndividualStations,
+            category: DscCategory::Routine,
+            address: Some(2440001230),
+            first_telecommand: 100,
+            second_telecommand: 126,
+            proposed_rx_channel: "72",
+            proposed_tx_channel: "72",
+            telephone_number: "".into(),
+            latitude: Some(52.0),
+            longitude: Some(4.5),
+            time_of_position: Some(3600.0),
+            vessel_in_distress: None,
+            end_of_sequence: 117,
+            expansion_enabled: YesNo::Yes,
+            calling_rx_channel: "70",
+            calling_tx_channel: "70",
+            time_of_receipt: Some(3601.0),
+            date_of_receipt: Some(19500),
+            equipment_message_id: 1,
+            expansions,
+        };
+        assert_eq!(call.nature_of_distress(), None);
+
+        let mut buf = [0; 223];
+        let len = call.encode(&mut buf).unwrap();
+        assert_eq!(len, 66);
+        assert_eq!(&buf[2..7], &[24, 40, 0, 12, 30]);
+        assert_eq!(DscCallInformation::decode(&buf[..len]).unwrap(), call);
+
+        // there are fewer than 8 expansion symbols, more are refused
+        let mut data = [0; 62 + 9 * 3];
+        data[..62].copy_from_slice(&buf[..62]);
+        for expansion in data[62..].chunks_mut(3) {
+            expansion.copy_from_slice(&[106, 0x02, 0x01]);
+        }
+        assert_eq!(
+            DscCallInformation::decode(&data),
+            Err(PgnError::TooManyEntries)
+        );
+    }
+
+    #[test]
+    fn radio_frequency_mode_power_decode() {
+        let data = [
+            0x00, 0x42, 0xef, 0x00, 0x00, 0x42, 0xef, 0x00, b'1', b'6', b' ', b' ', b' ', b' ',
+            0x19, 0x00, 0x00, 0xa8, 0x61,
+        ];
+        let radio = RadioFrequencyModePower::decode(&data).unwrap();
+        assert_eq!(radio.rx_frequency, Some(156_800_000.0));
+        assert_eq!(radio.tx_frequency, Some(156_800_000.0));
+        assert_eq!(radio.channel, "16");
+        assert_eq!(radio.tx_power, Some(25));
+        assert_eq!(radio.mode, TelephoneMode::SimplexTelephone);
+        assert_eq!(radio.channel_bandwidth, Some(25_000));
+
+        let mut buf = [0; 19];
+        assert_eq!(radio.encode(&mut buf).unwrap(), 19);
+        assert_eq!(buf, data);
+    }
+}
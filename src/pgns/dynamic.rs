@@ -0,0 +1,315 @@
+use crate::pgns::field::Reader;
+use crate::pgns::{Field, FieldKind, LauString, PgnDescriptor, PgnError, Result};
+use crate::Message;
+
+/// The value of a field decoded without a compile-time type.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum FieldValue<'a> {
+    /// The sender marked the field as not available.
+    NotAvailable,
+    Unsigned(u64),
+    Signed(i64),
+    /// A scaled number in the unit of the field.
+    Number(f64),
+    Lookup {
+        value: u32,
+        /// Name of the value, `None` if it is not a known one.
+        name: Option<&'static str>,
+    },
+    Flags {
+        value: u32,
+        /// Bit values and names of the known flags, set or not.
+        flags: &'static [(u32, &'static str)],
+    },
+    Float(f32),
+    Decimal(u64),
+    Binary(&'a [u8]),
+    /// Binary field that does not fill whole bytes.
+    Bits(u64),
+    String(&'a str),
+    /// String sent as UTF-16, in little endian code units.
+    Utf16(&'a [u8]),
+}
+
+impl<'a> FieldValue<'a> {
+    /// Names of the known flags set in a flags value.
+    pub fn set_flags(&self) -> impl Iterator<Item = &'static str> {
+        let (value, flags): (u32, &'static [(u32, &'static str)]) = match *self {
+            FieldValue::Flags { value, flags } => (value, flags),
+            _ => (0, &[]),
+        };
+        flags
+            .iter()
+            .filter(move |(flag, _)| value & flag != 0)
+            .map(|(_, name)| *name)
+    }
+}
+
+/// A field of a payload decoded by `FieldDecoder`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DecodedField<'a> {
+    pub name: &'static str,
+    pub value: FieldValue<'a>,
+    pub unit: Option<&'static str>,
+    /// Zero based index of the repeating field set the field belongs to.
+    pub repetition: Option<usize>,
+}
+
+/// Decodes a payload field by field using the layout of its catalogue
+/// descriptor, skipping reserved fields.
+///
+/// Like canboat's analyzer, decoding ends quietly at the end of a payload
+/// that omits trailing fields. A field cut short or an invalid string yields
+/// an error and ends decoding.
+pub struct FieldDecoder<'a> {
+    descriptor: &'static PgnDescriptor,
+    reader: Reader<'a>,
+    index: usize,
+    repetition: usize,
+    repetitions: u64,
+    done: bool,
+}
+
+impl<'a> FieldDecoder<'a> {
+    pub fn new(descriptor: &'static PgnDescriptor, data: &'a [u8]) -> Self {
+        FieldDecoder {
+            descriptor,
+            reader: Reader::new(data),
+            index: 0,
+            repetition: 0,
+            repetitions: 0,
+            done: false,
+        }
+    }
+
+    /// A decoder for a message, or `None` if its parameter group is not in
+    /// the catalogue.
+    pub fn for_message(message: &'a Message) -> Option<Self> {
+        let pgn = message.id().pgn();
+        let descriptor = PgnDescriptor::for_message(pgn, message.data())?;
+        Some(Self::new(descriptor, message.data()))
+    }
+
+    pub fn descriptor(&self) -> &'static PgnDescriptor {
+        self.descriptor
+    }
+
+    /// Moves to the next field, returning it with its repetition, or `None`
+    /// once the layout is exhausted.
+    fn advance(&mut self) -> Option<(&'static Field, Option<usize>)> {
+        let fields = self.descriptor.fields;
+        let repeating = match self.descriptor.repeating {
+            Some(repeating) if self.index >= repeating.start => repeating,
+            _ => {
+                let field = fields.get(self.index)?;
+                self.index += 1;
+                return Some((field, None));
+            }
+        };
+        if repeating.start >= fields.len() {
+            return None;
+        }
+        if self.index == fields.len() {
+            self.index = repeating.start;
+            self.repetition += 1;
+        }
+        if self.index == repeating.start {
+            let more = match repeating.count {
+                Some(_) => (self.repetition as u64) < self.repetitions,
+                None => self.reader.remaining() > 0,
+            };
+            if !more {
+                return None;
+            }
+        }
+        let field = &fields[self.index];
+        self.index += 1;
+        Some((field, Some(self.repetition)))
+    }
+
+    fn read(&mut self, field: &Field) -> Result<FieldValue<'a>> {
+        let r = &mut self.reader;
+        let bits = field.bits as usize;
+        let aligned = r.remaining() % 8 == 0;
+        let value = match field.kind {
+            // a single bit has no room for "data not available"
+            FieldKind::Unsigned { .. } if bits == 1 => FieldValue::Unsigned(r.bits(1)?),
+            FieldKind::Unsigned { resolution, offset } => match r.uint(bits)? {
+                None => FieldValue::NotAvailable,
+                Some(v) if resolution == 1.0 && offset == 0.0 => FieldValue::Unsigned(v),
+                Some(v) => FieldValue::Number(v as f64 * resolution + offset),
+            },
+            FieldKind::Signed { resolution } => match r.int(bits)? {
+                None => FieldValue::NotAvailable,
+                Some(v) if resolution == 1.0 => FieldValue::Signed(v),
+                Some(v) => FieldValue::Number(v as f64 * resolution),
+            },
+            FieldKind::Lookup(values) => {
+                let value = r.bits(bits)? as u32;
+                let name = values.iter().find(|(v, _)| *v == value).map(|(_, n)| *n);
+                FieldValue::Lookup { value, name }
+            }
+            FieldKind::Flags(flags) => FieldValue::Flags {
+                value: r.bits(bits)? as u32,
+                flags,
+            },
+            FieldKind::Float => match r.bits(32)? as u32 {
+                0xffff_ffff => FieldValue::NotAvailable,
+                v => FieldValue::Float(f32::from_bits(v)),
+            },
+            FieldKind::Decimal => {
+                let mut value = 0;
+                let mut available = false;
+                for _ in 0..bits / 8 {
+                    let digits = r.u8()?;
+                    available |= digits != 0xff;
+                    value = value * 100 + u64::from(digits.min(99));
+                }
+                if available {
+                    FieldValue::Decimal(value)
+                } else {
+                    FieldValue::NotAvailable
+                }
+            }
+            FieldKind::Binary if aligned && bits == 0 => FieldValue::Binary(r.rest()),
+            FieldKind::Binary if aligned && bits % 8 == 0 => FieldValue::Binary(r.bytes(bits / 8)?),
+            FieldKind::Binary => FieldValue::Bits(r.bits(bits.min(64))?),
+            FieldKind::StringFix | FieldKind::StringLau | FieldKind::StringLz if !aligned => {
+                return Err(PgnError::InvalidString)
+            }
+            FieldKind::StringFix => FieldValue::String(r.string_fix(bits / 8)?),
+            FieldKind::StringLau => match r.string_lau()? {
+                LauString::Utf8(text) => FieldValue::String(text),
+                LauString::Utf16(data) => FieldValue::Utf16(data),
+            },
+            FieldKind::StringLz => FieldValue::String(r.string_lz()?),
+            FieldKind::Reserved => {
+                r.reserved(bits)?;
+                FieldValue::NotAvailable
+            }
+        };
+        Ok(value)
+    }
+}
+
+impl<'a> Iterator for FieldDecoder<'a> {
+    type Item = Result<DecodedField<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            let (field, repetition) = match self.advance() {
+                Some(next) if self.reader.remaining() > 0 => next,
+                _ => break,
+            };
+            let value = match self.read(field) {
+                Ok(value) => value,
+                Err(error) => {
+                    self.done = true;
+                    return Some(Err(error));
+                }
+            };
+            if let Some(count) = self.descriptor.repeating.and_then(|r| r.count) {
+                if self.index == count + 1 && repetition.is_none() {
+                    self.repetitions = match value {
+                        FieldValue::Unsigned(count) => count,
+                        _ => 0,
+                    };
+                }
+            }
+            if matches!(field.kind, FieldKind::Reserved) {
+                continue;
+            }
+            return Some(Ok(DecodedField {
+                name: field.name,
+                value,
+                unit: field.unit,
+                repetition,
+            }));
+        }
+        self.done = true;
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pgns::{FieldDecoder, FieldValue, PgnDescriptor, PgnError};
+    use heapless::Vec;
+
+    #[test]
+    fn dynamic_decode_fields() {
+        let heave = PgnDescriptor::find(127252).unwrap();
+        let data = [0x01, 0x83, 0xff, 0xff, 0xff, 0xf0, 0xff, 0xff];
+        let fields: Vec<_, 32> = FieldDecoder::new(heave, &data)
+            .map(|field| field.unwrap())
+            .map(|field| (field.name, field.value, field.unit))
+            .collect();
+        assert_eq!(
+            fields,
+            [
+                ("SID", FieldValue::Unsigned(1), None),
+                ("Heave", FieldValue::Number(-125.0 * 0.01), Some("m")),
+                ("Delay", FieldValue::NotAvailable, Some("s")),
+                ("Delay Source", FieldValue::Unsigned(0), None),
+            ]
+        );
+
+        let fields: Vec<_, 32> = FieldDecoder::new(heave, &data[..3]).collect();
+        assert_eq!(fields.len(), 2);
+        let fields: Vec<_, 32> = FieldDecoder::new(heave, &data[..4]).collect();
+        assert_eq!(fields.last(), Some(&Err(PgnError::DataTooShort)));
+    }
+
+    #[test]
+    fn dynamic_decode_display_names() {
+        let position = PgnDescriptor::find(129038).unwrap();
+        let field = FieldDecoder::new(position, &[0x41]).nth(1).unwrap();
+        assert_eq!(
+            field.unwrap().value,
+            FieldValue::Lookup {
+                value: 1,
+                name: Some("First retransmission")
+            }
+        );
+    }
+
+    #[test]
+    fn dynamic_decode_repeating() {
+        let bank = PgnDescriptor::find(127501).unwrap();
+        let data = [0x02, 0x09, 0x00, 0x00, 0x00, 0x00, 0x00, 0xc0];
+        let fields: Vec<_, 32> = FieldDecoder::new(bank, &data).map(|f| f.unwrap()).collect();
+        assert_eq!(fields.len(), 29);
+        assert_eq!(fields[1].repetition, Some(0));
+        assert_eq!(
+            fields[1].value,
+            FieldValue::Lookup {
+                value: 1,
+                name: Some("On")
+            }
+        );
+        assert_eq!(
+            fields[2].value,
+            FieldValue::Lookup {
+                value: 2,
+                name: Some("Error")
+            }
+        );
+        assert_eq!(fields[28].repetition, Some(27));
+
+        let route = PgnDescriptor::find(129285).unwrap();
+        let data = [
+            0x00, 0x00, 0x02, 0x00, 0x01, 0x00, 0x07, 0x00, 0xe0, 0x03, 0x01, b'R', 0xff, //
+            0x01, 0x00, 0x03, 0x01, b'A', 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+            0x02, 0x00, 0x03, 0x01, b'B', 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+            0xff, 0xff, 0xff, 0xff,
+        ];
+        let fields: Vec<_, 32> = FieldDecoder::new(route, &data)
+            .map(|f| f.unwrap())
+            .collect();
+        assert_eq!(fields.len(), 15);
+        assert_eq!(fields[6].value, FieldValue::String("R"));
+        assert_eq!(fields[12].name, "WP Name");
+        assert_eq!(fields[12].value, FieldValue::String("B"));
+        assert_eq!(fields[14].repetition, Some(1));
+    }
+}
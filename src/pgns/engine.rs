@@ -0,0 +1,374 @@
+use crate::pgns::field::{Reader, Writer};
+use crate::pgns::{Pgn, Result};
+use crate::Priority;
+
+lookup! {
+    pub enum EngineInstance: u8 {
+        SingleOrPort = 0 => "Single Engine or Dual Engine Port",
+        Starboard = 1 => "Dual Engine Starboard",
+    }
+}
+
+lookup! {
+    pub enum GearStatus: u8 {
+        Forward = 0,
+        Neutral = 1,
+        Reverse = 2,
+    }
+}
+
+flags! {
+    pub struct EngineDiscreteStatus1: u16 {
+        const CHECK_ENGINE = 1 << 0;
+        const OVER_TEMPERATURE = 1 << 1;
+        const LOW_OIL_PRESSURE = 1 << 2;
+        const LOW_OIL_LEVEL = 1 << 3;
+        const LOW_FUEL_PRESSURE = 1 << 4;
+        const LOW_SYSTEM_VOLTAGE = 1 << 5;
+        const LOW_COOLANT_LEVEL = 1 << 6;
+        const WATER_FLOW = 1 << 7;
+        const WATER_IN_FUEL = 1 << 8;
+        const CHARGE_INDICATOR = 1 << 9;
+        const PREHEAT_INDICATOR = 1 << 10;
+        const HIGH_BOOST_PRESSURE = 1 << 11;
+        const REV_LIMIT_EXCEEDED = 1 << 12;
+        const EGR_SYSTEM = 1 << 13;
+        const THROTTLE_POSITION_SENSOR = 1 << 14;
+        const EMERGENCY_STOP = 1 << 15;
+    }
+}
+
+flags! {
+    pub struct EngineDiscreteStatus2: u16 {
+        const WARNING_LEVEL_1 = 1 << 0;
+        const WARNING_LEVEL_2 = 1 << 1;
+        const POWER_REDUCTION = 1 << 2;
+        const MAINTENANCE_NEEDED = 1 << 3;
+        const ENGINE_COMM_ERROR = 1 << 4;
+        const SUB_OR_SECONDARY_THROTTLE = 1 << 5;
+        const NEUTRAL_START_PROTECT = 1 << 6;
+        const ENGINE_SHUTTING_DOWN = 1 << 7;
+    }
+}
+
+flags! {
+    pub struct TransmissionDiscreteStatus: u8 {
+        const CHECK_TEMPERATURE = 1 << 0;
+        const OVER_TEMPERATURE = 1 << 1;
+        const LOW_OIL_PRESSURE = 1 << 2;
+        const LOW_OIL_LEVEL = 1 << 3;
+        const SAIL_DRIVE = 1 << 4;
+    }
+}
+
+/// PGN 127488 - Engine Parameters, Rapid Update
+#[derive(Clone, Debug, PartialEq)]
+pub struct EngineRapidUpdate {
+    pub instance: EngineInstance,
+    /// Revolutions per minute
+    pub speed: Option<f32>,
+    /// Pascal
+    pub boost_pressure: Option<f32>,
+    /// Percent
+    pub tilt_trim: Option<i8>,
+}
+
+impl<'a> Pgn<'a> for EngineRapidUpdate {
+    const PGN: u32 = 127488;
+    const PRIORITY: Priority = Priority::Priority2;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(EngineRapidUpdate {
+            instance: r.lookup(8)?,
+            speed: r.ufix(16, 0.25)?,
+            boost_pressure: r.ufix(16, 100.0)?,
+            tilt_trim: r.int(8)?.map(|v| v as i8),
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.lookup(8, self.instance)?;
+        w.ufix(16, 0.25, self.speed)?;
+        w.ufix(16, 100.0, self.boost_pressure)?;
+        w.int(8, self.tilt_trim.map(|v| v as i64))?;
+        w.reserved(16)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 127489 - Engine Parameters, Dynamic
+#[derive(Clone, Debug, PartialEq)]
+pub struct EngineDynamicParameters {
+    pub instance: EngineInstance,
+    /// Pascal
+    pub oil_pressure: Option<f32>,
+    /// Kelvin
+    pub oil_temperature: Option<f32>,
+    /// Kelvin
+    pub temperature: Option<f32>,
+    /// Volts
+    pub alternator_potential: Option<f32>,
+    /// Litres per hour
+    pub fuel_rate: Option<f32>,
+    /// Seconds
+    pub total_engine_hours: Option<u32>,
+    /// Pascal
+    pub coolant_pressure: Option<f32>,
+    /// Pascal
+    pub fuel_pressure: Option<f32>,
+    pub discrete_status_1: EngineDiscreteStatus1,
+    pub discrete_status_2: EngineDiscreteStatus2,
+    /// Percent
+    pub engine_load: Option<i8>,
+    /// Percent
+    pub engine_torque: Option<i8>,
+}
+
+impl<'a> Pgn<'a> for EngineDynamicParameters {
+    const PGN: u32 = 127489;
+    const PRIORITY: Priority = Priority::Priority2;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(EngineDynamicParameters {
+            instance: r.lookup(8)?,
+            oil_pressure: r.ufix(16, 100.0)?,
+            oil_temperature: r.ufix(16, 0.1)?,
+            temperature: r.ufix(16, 0.01)?,
+            alternator_potential: r.sfix(16, 0.01)?,
+            fuel_rate: r.sfix(16, 0.1)?,
+            total_engine_hours: r.uint(32)?.map(|v| v as u32),
+            coolant_pressure: r.ufix(16, 100.0)?,
+            fuel_pressure: r.ufix(16, 1000.0)?,
+            discrete_status_1: {
+                r.reserved(8)?;
+                EngineDiscreteStatus1::from_bits(r.bits(16)? as u16)
+            },
+            discrete_status_2: EngineDiscreteStatus2::from_bits(r.bits(16)? as u16),
+            engine_load: r.int(8)?.map(|v| v as i8),
+            engine_torque: r.int(8)?.map(|v| v as i8),
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.lookup(8, self.instance)?;
+        w.ufix(16, 100.0, self.oil_pressure)?;
+        w.ufix(16, 0.1, self.oil_temperature)?;
+        w.ufix(16, 0.01, self.temperature)?;
+        w.sfix(16, 0.01, self.alternator_potential)?;
+        w.sfix(16, 0.1, self.fuel_rate)?;
+        w.uint(32, self.total_engine_hours.map(|v| v as u64))?;
+        w.ufix(16, 100.0, self.coolant_pressure)?;
+        w.ufix(16, 1000.0, self.fuel_pressure)?;
+        w.reserved(8)?;
+        w.bits(16, self.discrete_status_1.bits() as u64)?;
+        w.bits(16, self.discrete_status_2.bits() as u64)?;
+        w.int(8, self.engine_load.map(|v| v as i64))?;
+        w.int(8, self.engine_torque.map(|v| v as i64))?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 127493 - Transmission Parameters, Dynamic
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransmissionParameters {
+    pub instance: EngineInstance,
+    pub gear: GearStatus,
+    /// Pascal
+    pub oil_pressure: Option<f32>,
+    /// Kelvin
+    pub oil_temperature: Option<f32>,
+    pub discrete_status: TransmissionDiscreteStatus,
+}
+
+impl<'a> Pgn<'a> for TransmissionParameters {
+    const PGN: u32 = 127493;
+    const PRIORITY: Priority = Priority::Priority2;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(TransmissionParameters {
+            instance: r.lookup(8)?,
+            gear: {
+                let gear = r.lookup(2)?;
+                r.reserved(6)?;
+                gear
+            },
+            oil_pressure: r.ufix(16, 100.0)?,
+            oil_temperature: r.ufix(16, 0.1)?,
+            discrete_status: TransmissionDiscreteStatus::from_bits(r.u8()?),
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.lookup(8, self.instance)?;
+        w.lookup(2, self.gear)?;
+        w.reserved(6)?;
+        w.ufix(16, 100.0, self.oil_pressure)?;
+        w.ufix(16, 0.1, self.oil_temperature)?;
+        w.u8(self.discrete_status.bits())?;
+        w.reserved(8)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 127496 - Trip Parameters, Vessel
+#[derive(Clone, Debug, PartialEq)]
+pub struct TripParametersVessel {
+    /// Seconds
+    pub time_to_empty: Option<f64>,
+    /// Metres
+    pub distance_to_empty: Option<f64>,
+    /// Litres
+    pub estimated_fuel_remaining: Option<u16>,
+    /// Seconds
+    pub trip_run_time: Option<f64>,
+}
+
+impl<'a> Pgn<'a> for TripParametersVessel {
+    const PGN: u32 = 127496;
+    const PRIORITY: Priority = Priority::Priority5;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(TripParametersVessel {
+            time_to_empty: r.ufix64(32, 0.001)?,
+            distance_to_empty: r.ufix64(32, 0.01)?,
+            estimated_fuel_remaining: r.uint(16)?.map(|v| v as u16),
+            trip_run_time: r.ufix64(32, 0.001)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.ufix64(32, 0.001, self.time_to_empty)?;
+        w.ufix64(32, 0.01, self.distance_to_empty)?;
+        w.uint(16, self.estimated_fuel_remaining.map(|v| v as u64))?;
+        w.ufix64(32, 0.001, self.trip_run_time)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 127497 - Trip Parameters, Engine
+#[derive(Clone, Debug, PartialEq)]
+pub struct TripParametersEngine {
+    pub instance: EngineInstance,
+    /// Litres
+    pub trip_fuel_used: Option<u16>,
+    /// Litres per hour
+    pub fuel_rate_average: Option<f32>,
+    /// Litres per hour
+    pub fuel_rate_economy: Option<f32>,
+    /// Litres per hour
+    pub instantaneous_fuel_economy: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for TripParametersEngine {
+    const PGN: u32 = 127497;
+    const PRIORITY: Priority = Priority::Priority5;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(TripParametersEngine {
+            instance: r.lookup(8)?,
+            trip_fuel_used: r.uint(16)?.map(|v| v as u16),
+            fuel_rate_average: r.sfix(16, 0.1)?,
+            fuel_rate_economy: r.sfix(16, 0.1)?,
+            instantaneous_fuel_economy: r.sfix(16, 0.1)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.lookup(8, self.instance)?;
+        w.uint(16, self.trip_fuel_used.map(|v| v as u64))?;
+        w.sfix(16, 0.1, self.fuel_rate_average)?;
+        w.sfix(16, 0.1, self.fuel_rate_economy)?;
+        w.sfix(16, 0.1, self.instantaneous_fuel_economy)?;
+        Ok(w.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pgns::{
+        EngineDiscreteStatus1, EngineDiscreteStatus2, EngineDynamicParameters, EngineInstance,
+        EngineRapidUpdate, Pgn,
+    };
+
+    #[test]
+    fn engine_rapid_update_decode() {
+        struct TestCase {
+            data: [u8; 8],
+            instance: EngineInstance,
+            speed: Option<f32>,
+            tilt_trim: Option<i8>,
+        }
+        let test_cases = [
+            TestCase {
+                data: [0x00, 0x40, 0x1f, 0xff, 0xff, 0x7f, 0xff, 0xff],
+                instance: EngineInstance::SingleOrPort,
+                speed: Some(2000.0),
+                tilt_trim: None,
+            },
+            TestCase {
+                data: [0x01, 0xff, 0xff, 0xff, 0xff, 0xf6, 0xff, 0xff],
+                instance: EngineInstance::Starboard,
+                speed: None,
+                tilt_trim: Some(-10),
+            },
+        ];
+        for i in &test_cases {
+            let update = EngineRapidUpdate::decode(&i.data).unwrap();
+            assert_eq!(update.instance, i.instance);
+            assert_eq!(update.speed, i.speed);
+            assert_eq!(update.boost_pressure, None);
+            assert_eq!(update.tilt_trim, i.tilt_trim);
+
+            let mut buf = [0; 8];
+            update.encode(&mut buf).unwrap();
+            assert_eq!(buf, i.data);
+        }
+    }
+
+    #[test]
+    fn engine_dynamic_parameters_status() {
+        let parameters = EngineDynamicParameters {
+            instance: EngineInstance::Starboard,
+            oil_pressure: Some(400000.0),
+            oil_temperature: None,
+            temperature: Some(353.15),
+            alternator_potential: Some(14.2),
+            fuel_rate: Some(12.5),
+            total_engine_hours: Some(3600 * 1250),
+            coolant_pressure: None,
+            fuel_pressure: None,
+            discrete_status_1: EngineDiscreteStatus1::OVER_TEMPERATURE
+                | EngineDiscreteStatus1::WATER_IN_FUEL,
+            discrete_status_2: EngineDiscreteStatus2::WARNING_LEVEL_1,
+            engine_load: Some(75),
+            engine_torque: None,
+        };
+        let mut buf = [0; 32];
+        assert_eq!(parameters.encode(&mut buf).unwrap(), 26);
+        assert_eq!(&buf[20..24], &[0x02, 0x01, 0x01, 0x00]);
+
+        let decoded = EngineDynamicParameters::decode(&buf[..26]).unwrap();
+        assert!(decoded
+            .discrete_status_1
+            .contains(EngineDiscreteStatus1::WATER_IN_FUEL));
+        assert!(!decoded
+            .discrete_status_1
+            .contains(EngineDiscreteStatus1::CHECK_ENGINE));
+        assert_eq!(decoded.total_engine_hours, Some(3600 * 1250));
+        assert_eq!(decoded.engine_load, Some(75));
+        assert_eq!(decoded.engine_torque, None);
+    }
+}
@@ -0,0 +1,600 @@
+use heapless::Vec;
+
+use crate::pgns::field::{Reader, Writer};
+use crate::pgns::{LauString, Pgn, PgnError, Result, YesNo};
+use crate::Priority;
+
+lookup! {
+    pub enum EntertainmentZone: u8 {
+        AllZones = 0 => "All zones",
+        Zone1 = 1 => "Zone 1",
+        Zone2 = 2 => "Zone 2",
+        Zone3 = 3 => "Zone 3",
+        Zone4 = 4 => "Zone 4",
+    }
+}
+
+lookup! {
+    pub enum EntertainmentSource: u8 {
+        VesselAlarm = 0 => "Vessel alarm",
+        Am = 1 => "AM",
+        Fm = 2 => "FM",
+        Weather = 3,
+        Dab = 4 => "DAB",
+        Aux = 5,
+        Usb = 6 => "USB",
+        Cd = 7 => "CD",
+        Mp3 = 8 => "MP3",
+        AppleIos = 9 => "Apple iOS",
+        Android = 10,
+        Bluetooth = 11,
+        SiriusXm = 12 => "Sirius XM",
+        Pandora = 13,
+        Spotify = 14,
+        Slacker = 15,
+        Songza = 16,
+        AppleRadio = 17 => "Apple Radio",
+        LastFm = 18 => "Last FM",
+        Ethernet = 19,
+        VideoMp4 = 20 => "Video MP4",
+        VideoDvd = 21 => "Video DVD",
+        VideoBluRay = 22 => "Video BluRay",
+        Hdmi = 23 => "HDMI",
+        Video = 24,
+    }
+}
+
+lookup! {
+    pub enum PlayStatus: u8 {
+        Play = 0,
+        Pause = 1,
+        Stop = 2,
+        FastForward1x = 3 => "FF 1x",
+        FastForward2x = 4 => "FF 2x",
+        FastForward3x = 5 => "FF 3x",
+        FastForward4x = 6 => "FF 4x",
+        Rewind1x = 7 => "RW 1x",
+        Rewind2x = 8 => "RW 2x",
+        Rewind3x = 9 => "RW 3x",
+        Rewind4x = 10 => "RW 4x",
+        SkipAhead = 11 => "Skip ahead",
+        SkipBack = 12 => "Skip back",
+        JogAhead = 13 => "Jog ahead",
+        JogBack = 14 => "Jog back",
+        SeekUp = 15 => "Seek up",
+        SeekDown = 16 => "Seek down",
+        ScanUp = 17 => "Scan up",
+        ScanDown = 18 => "Scan down",
+        TuneUp = 19 => "Tune up",
+        TuneDown = 20 => "Tune down",
+    }
+}
+
+lookup! {
+    pub enum RepeatStatus: u8 {
+        Off = 0,
+        One = 1,
+        All = 2,
+    }
+}
+
+lookup! {
+    pub enum ShuffleStatus: u8 {
+        Off = 0,
+        PlayQueue = 1 => "Play queue",
+        All = 2,
+    }
+}
+
+lookup! {
+    pub enum LikeStatus: u8 {
+        None = 0,
+        ThumbsUp = 1 => "Thumbs up",
+        ThumbsDown = 2 => "Thumbs down",
+    }
+}
+
+lookup! {
+    pub enum EntertainmentType: u8 {
+        File = 0,
+        PlaylistName = 1 => "Playlist Name",
+        GenreName = 2 => "Genre Name",
+        AlbumName = 3 => "Album Name",
+        ArtistName = 4 => "Artist Name",
+        TrackName = 5 => "Track Name",
+        StationName = 6 => "Station Name",
+        StationNumber = 7 => "Station Number",
+        FavouriteNumber = 8 => "Favourite Number",
+        PlayQueue = 9 => "Play Queue",
+        ContentInfo = 10 => "Content Info",
+    }
+}
+
+/// PGN 130569 - Entertainment - Current File and Status
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntertainmentCurrentFileStatus {
+    pub zone: EntertainmentZone,
+    pub source: EntertainmentSource,
+    pub number: u8,
+    pub id: Option<u32>,
+    pub play_status: PlayStatus,
+    /// Seconds
+    pub elapsed_track_time: Option<u16>,
+    /// Seconds
+    pub track_time: Option<u16>,
+    pub repeat: RepeatStatus,
+    pub shuffle: ShuffleStatus,
+    pub save_favourite: Option<u8>,
+    pub play_favourite: Option<u16>,
+    pub like: LikeStatus,
+    /// Percent
+    pub signal_strength: Option<u8>,
+    /// Hertz
+    pub radio_frequency: Option<f32>,
+    pub hd_frequency_multicast: Option<u8>,
+    pub delete_favourite: Option<u8>,
+    pub total_tracks: Option<u16>,
+}
+
+impl<'a> Pgn<'a> for EntertainmentCurrentFileStatus {
+    const PGN: u32 = 130569;
+    const PRIORITY: Priority = Priority::Priority6;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(EntertainmentCurrentFileStatus {
+            zone: r.lookup(8)?,
+            source: r.lookup(8)?,
+            number: r.u8()?,
+            id: r.uint(32)?.map(|v| v as u32),
+            play_status: r.lookup(8)?,
+            elapsed_track_time: r.uint(16)?.map(|v| v as u16),
+            track_time: r.uint(16)?.map(|v| v as u16),
+            repeat: r.lookup(4)?,
+            shuffle: r.lookup(4)?,
+            save_favourite: r.uint(8)?.map(|v| v as u8),
+            play_favourite: r.uint(16)?.map(|v| v as u16),
+            like: r.lookup(8)?,
+            signal_strength: r.uint(8)?.map(|v| v as u8),
+            radio_frequency: r.ufix(32, 10.0)?,
+            hd_frequency_multicast: r.uint(8)?.map(|v| v as u8),
+            delete_favourite: r.uint(8)?.map(|v| v as u8),
+            total_tracks: r.uint(16)?.map(|v| v as u16),
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.lookup(8, self.zone)?;
+        w.lookup(8, self.source)?;
+        w.u8(self.number)?;
+        w.uint(32, self.id.map(|v| v as u64))?;
+        w.lookup(8, self.play_status)?;
+        w.uint(16, self.elapsed_track_time.map(|v| v as u64))?;
+        w.uint(16, self.track_time.map(|v| v as u64))?;
+        w.lookup(4, self.repeat)?;
+        w.lookup(4, self.shuffle)?;
+        w.uint(8, self.save_favourite.map(|v| v as u64))?;
+        w.uint(16, self.play_favourite.map(|v| v as u64))?;
+        w.lookup(8, self.like)?;
+        w.uint(8, self.signal_strength.map(|v| v as u64))?;
+        w.ufix(32, 10.0, self.radio_frequency)?;
+        w.uint(8, self.hd_frequency_multicast.map(|v| v as u64))?;
+        w.uint(8, self.delete_favourite.map(|v| v as u64))?;
+        w.uint(16, self.total_tracks.map(|v| v as u64))?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 130570 - Entertainment - Library Data File
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntertainmentLibraryDataFile<'a> {
+    pub source: EntertainmentSource,
+    pub number: u8,
+    pub id: u32,
+    pub file_type: EntertainmentType,
+    pub name: LauString<'a>,
+    pub track: Option<u16>,
+    pub station: Option<u16>,
+    pub favourite: Option<u8>,
+    /// Hertz
+    pub radio_frequency: Option<f32>,
+    pub hd_frequency: Option<u8>,
+    pub zone: EntertainmentZone,
+    pub in_play_queue: YesNo,
+    pub locked: YesNo,
+    pub artist: LauString<'a>,
+    pub album: LauString<'a>,
+    pub station_name: LauString<'a>,
+}
+
+impl<'a> Pgn<'a> for EntertainmentLibraryDataFile<'a> {
+    const PGN: u32 = 130570;
+    const PRIORITY: Priority = Priority::Priority6;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let source = r.lookup(8)?;
+        let number = r.u8()?;
+        let id = r.bits(32)? as u32;
+        let file_type = r.lookup(8)?;
+        let name = r.string_lau()?;
+        let track = r.uint(16)?.map(|v| v as u16);
+        let station = r.uint(16)?.map(|v| v as u16);
+        let favourite = r.uint(8)?.map(|v| v as u8);
+        let radio_frequency = r.ufix(32, 10.0)?;
+        let hd_frequency = r.uint(8)?.map(|v| v as u8);
+        let zone = r.lookup(8)?;
+        let in_play_queue = r.lookup(2)?;
+        let locked = r.lookup(2)?;
+        r.reserved(4)?;
+        Ok(EntertainmentLibraryDataFile {
+            source,
+            number,
+            id,
+            file_type,
+            name,
+            track,
+            station,
+            favourite,
+            radio_frequency,
+            hd_frequency,
+            zone,
+            in_play_queue,
+            locked,
+            artist: r.string_lau()?,
+            album: r.string_lau()?,
+            station_name: r.string_lau()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.lookup(8, self.source)?;
+        w.u8(self.number)?;
+        w.bits(32, self.id as u64)?;
+        w.lookup(8, self.file_type)?;
+        w.string_lau(self.name)?;
+        w.uint(16, self.track.map(|v| v as u64))?;
+        w.uint(16, self.station.map(|v| v as u64))?;
+        w.uint(8, self.favourite.map(|v| v as u64))?;
+        w.ufix(32, 10.0, self.radio_frequency)?;
+        w.uint(8, self.hd_frequency.map(|v| v as u64))?;
+        w.lookup(8, self.zone)?;
+        w.lookup(2, self.in_play_queue)?;
+        w.lookup(2, self.locked)?;
+        w.reserved(4)?;
+        w.string_lau(self.artist)?;
+        w.string_lau(self.album)?;
+        w.string_lau(self.station_name)?;
+        Ok(w.len())
+    }
+}
+
+/// An entry of a library data group.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LibraryGroupEntry<'a> {
+    pub id_type: EntertainmentType,
+    pub id: u32,
+    pub name: LauString<'a>,
+}
+
+/// PGN 130571 - Entertainment - Library Data Group
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntertainmentLibraryDataGroup<'a> {
+    pub source: EntertainmentSource,
+    pub number: u8,
+    pub group_type: EntertainmentType,
+    pub zone: EntertainmentZone,
+    pub group_id: u32,
+    pub id_offset: u16,
+    pub total_id_count: u16,
+    pub entries: Vec<LibraryGroupEntry<'a>, 34>,
+    pub artist: LauString<'a>,
+}
+
+impl<'a> Pgn<'a> for EntertainmentLibraryDataGroup<'a> {
+    const PGN: u32 = 130571;
+    const PRIORITY: Priority = Priority::Priority6;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let source = r.lookup(8)?;
+        let number = r.u8()?;
+        let group_type = r.lookup(8)?;
+        let zone = r.lookup(8)?;
+        let group_id = r.bits(32)? as u32;
+        let id_offset = r.bits(16)? as u16;
+        let count = r.bits(16)?;
+        let total_id_count = r.bits(16)? as u16;
+        let mut entries = Vec::new();
+        for _ in 0..count {
+            let entry = LibraryGroupEntry {
+                id_type: r.lookup(8)?,
+                id: r.bits(32)? as u32,
+                name: r.string_lau()?,
+            };
+            entries.push(entry).map_err(|_| PgnError::TooManyEntries)?;
+        }
+        Ok(EntertainmentLibraryDataGroup {
+            source,
+            number,
+            group_type,
+            zone,
+            group_id,
+            id_offset,
+            total_id_count,
+            entries,
+            artist: r.string_lau()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.lookup(8, self.source)?;
+        w.u8(self.number)?;
+        w.lookup(8, self.group_type)?;
+        w.lookup(8, self.zone)?;
+        w.bits(32, self.group_id as u64)?;
+        w.bits(16, self.id_offset as u64)?;
+        w.bits(16, self.entries.len() as u64)?;
+        w.bits(16, self.total_id_count as u64)?;
+        for entry in &self.entries {
+            w.lookup(8, entry.id_type)?;
+            w.bits(32, entry.id as u64)?;
+            w.string_lau(entry.name)?;
+        }
+        w.string_lau(self.artist)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 130572 - Entertainment - Library Data Search
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntertainmentLibraryDataSearch<'a> {
+    pub source: EntertainmentSource,
+    pub number: u8,
+    pub group_id: u32,
+    pub group_type_1: EntertainmentType,
+    pub group_name_1: LauString<'a>,
+    pub group_type_2: EntertainmentType,
+    pub group_name_2: LauString<'a>,
+    pub group_type_3: EntertainmentType,
+    pub group_name_3: LauString<'a>,
+}
+
+impl<'a> Pgn<'a> for EntertainmentLibraryDataSearch<'a> {
+    const PGN: u32 = 130572;
+    const PRIORITY: Priority = Priority::Priority6;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(EntertainmentLibraryDataSearch {
+            source: r.lookup(8)?,
+            number: r.u8()?,
+            group_id: r.bits(32)? as u32,
+            group_type_1: r.lookup(8)?,
+            group_name_1: r.string_lau()?,
+            group_type_2: r.lookup(8)?,
+            group_name_2: r.string_lau()?,
+            group_type_3: r.lookup(8)?,
+            group_name_3: r.string_lau()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.lookup(8, self.source)?;
+        w.u8(self.number)?;
+        w.bits(32, self.group_id as u64)?;
+        w.lookup(8, self.group_type_1)?;
+        w.string_lau(self.group_name_1)?;
+        w.lookup(8, self.group_type_2)?;
+        w.string_lau(self.group_name_2)?;
+        w.lookup(8, self.group_type_3)?;
+        w.string_lau(self.group_name_3)?;
+        Ok(w.len())
+    }
+}
+
+/// A source listed in a supported source data report.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SupportedSource<'a> {
+    pub id: u8,
+    pub source: EntertainmentSource,
+    pub number: u8,
+    pub name: LauString<'a>,
+    pub play_support: u32,
+    pub browse_support: u16,
+    pub thumbs_support: YesNo,
+    pub connected: YesNo,
+    pub repeat_support: u8,
+    pub shuffle_support: u8,
+}
+
+/// PGN 130573 - Entertainment - Supported Source Data
+#[derive(Clone, Debug, PartialEq)]
+pub struct EntertainmentSupportedSourceData<'a> {
+    pub id_offset: u16,
+    pub total_id_count: u16,
+    pub sources: Vec<SupportedSource<'a>, 19>,
+}
+
+impl<'a> Pgn<'a> for EntertainmentSupportedSourceData<'a> {
+    const PGN: u32 = 130573;
+    const PRIORITY: Priority = Priority::Priority6;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let id_offset = r.bits(16)? as u16;
+        let count = r.bits(16)?;
+        let total_id_count = r.bits(16)? as u16;
+        let mut sources = Vec::new();
+        for _ in 0..count {
+            let source = SupportedSource {
+                id: r.u8()?,
+                source: r.lookup(8)?,
+                number: r.u8()?,
+                name: r.string_lau()?,
+                play_support: r.bits(32)? as u32,
+                browse_support: r.bits(16)? as u16,
+                thumbs_support: r.lookup(2)?,
+                connected: r.lookup(2)?,
+                repeat_support: r.bits(2)? as u8,
+                shuffle_support: r.bits(2)? as u8,
+            };
+            sources.push(source).map_err(|_| PgnError::TooManyEntries)?;
+        }
+        Ok(EntertainmentSupportedSourceData {
+            id_offset,
+            total_id_count,
+            sources,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.bits(16, self.id_offset as u64)?;
+        w.bits(16, self.sources.len() as u64)?;
+        w.bits(16, self.total_id_count as u64)?;
+        for source in &self.sources {
+            w.u8(source.id)?;
+            w.lookup(8, source.source)?;
+            w.u8(source.number)?;
+            w.string_lau(source.name)?;
+            w.bits(32, source.play_support as u64)?;
+            w.bits(16, source.browse_support as u64)?;
+            w.lookup(2, source.thumbs_support)?;
+            w.lookup(2, source.connected)?;
+            w.bits(2, source.repeat_support as u64)?;
+            w.bits(2, source.shuffle_support as u64)?;
+        }
+        Ok(w.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use heapless::Vec;
+
+    use crate::pgns::{
+        EntertainmentCurrentFileStatus, EntertainmentLibraryDataGroup, EntertainmentSource,
+        EntertainmentSupportedSourceData, EntertainmentType, EntertainmentZone, LibraryGroupEntry,
+        LikeStatus, Pgn, PgnError, PlayStatus, RepeatStatus, ShuffleStatus, SupportedSource, YesNo,
+    };
+
+    #[test]
+    fn entertainment_current_file_status_decode() {
+        let data = [
+            0x01, 0x02, 0x00, // zone 1, FM, number 0
+            0xff, 0xff, 0xff, 0xff, // id
+            0x00, // play
+            0xff, 0xff, 0xff, 0xff, // track times
+            0x00, // repeat off, shuffle off
+            0xff, 0xff, 0xff, // favourites
+            0x00, // no like
+            0x50, // signal strength 80
+            0xb0, 0x9a, 0x96, 0x00, // 98.7 MHz
+            0xff, 0xff, 0xff, 0xff,
+        ];
+        let status = EntertainmentCurrentFileStatus::decode(&data).unwrap();
+        assert_eq!(status.zone, EntertainmentZone::Zone1);
+        assert_eq!(status.source, EntertainmentSource::Fm);
+        assert_eq!(status.id, None);
+        assert_eq!(status.play_status, PlayStatus::Play);
+        assert_eq!(status.track_time, None);
+        assert_eq!(status.repeat, RepeatStatus::Off);
+        assert_eq!(status.shuffle, ShuffleStatus::Off);
+        assert_eq!(status.like, LikeStatus::None);
+        assert_eq!(status.signal_strength, Some(80));
+        assert_eq!(status.radio_frequency, Some(98_700_000.0));
+        assert_eq!(status.total_tracks, None);
+
+        let mut buf = [0; 26];
+        assert_eq!(status.encode(&mut buf).unwrap(), 26);
+        assert_eq!(buf, data);
+    }
+
+    #[test]
+    fn entertainment_lists_roundtrip() {
+        let mut entries = Vec::new();
+        entries
+            .push(LibraryGroupEntry {
+                id_type: EntertainmentType::GenreName,
+                id: 1,
+                name: "Jazz".into(),
+            })
+            .unwrap();
+        entries
+            .push(LibraryGroupEntry {
+                id_type: EntertainmentType::GenreName,
+                id: 2,
+                name: "Rock".into(),
+            })
+            .unwrap();
+        let group = EntertainmentLibraryDataGroup {
+            source: EntertainmentSource::Usb,
+            number: 0,
+            group_type: EntertainmentType::GenreName,
+            zone: EntertainmentZone::AllZones,
+            group_id: 7,
+            id_offset: 0,
+            total_id_count: 12,
+            entries,
+            artist: "Various".into(),
+        };
+        let mut buf = [0; 223];
+        let len = group.encode(&mut buf).unwrap();
+        assert_eq!(len, 14 + 2 * 11 + 9);
+        assert_eq!(&buf[10..12], &[0x02, 0x00]);
+        assert_eq!(
+            EntertainmentLibraryDataGroup::decode(&buf[..len]).unwrap(),
+            group
+        );
+
+        // more entries than fit in a fast packet are refused, not dropped
+        let mut data = [0; 14 + 35 * 7];
+        data[..14].copy_from_slice(&buf[..14]);
+        data[10] = 35;
+        for entry in data[14..].chunks_mut(7) {
+            entry[5..].copy_from_slice(&[0x02, 0x01]);
+        }
+        assert_eq!(
+            EntertainmentLibraryDataGroup::decode(&data),
+            Err(PgnError::TooManyEntries)
+        );
+
+        let mut sources = Vec::new();
+        sources
+            .push(SupportedSource {
+                id: 3,
+                source: EntertainmentSource::Bluetooth,
+                number: 0,
+                name: "Phone".into(),
+                play_support: 0x7,
+                browse_support: 0,
+                thumbs_support: YesNo::No,
+                connected: YesNo::Yes,
+                repeat_support: 0x1,
+                shuffle_support: 0x3,
+            })
+            .unwrap();
+        let supported = EntertainmentSupportedSourceData {
+            id_offset: 0,
+            total_id_count: 1,
+            sources,
+        };
+        let len = supported.encode(&mut buf).unwrap();
+        assert_eq!(len, 6 + 3 + 7 + 7);
+        assert_eq!(buf[len - 1], 0xd4);
+        assert_eq!(
+            EntertainmentSupportedSourceData::decode(&buf[..len]).unwrap(),
+            supported
+        );
+    }
+}
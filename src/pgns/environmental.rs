@@ -0,0 +1,771 @@
+use crate::pgns::field::{Reader, Writer};
+use crate::pgns::{LauString, Pgn, Result};
+use crate::Priority;
+
+lookup! {
+    pub enum TemperatureSource: u8 {
+        SeaTemperature = 0 => "Sea Temperature",
+        OutsideTemperature = 1 => "Outside Temperature",
+        InsideTemperature = 2 => "Inside Temperature",
+        EngineRoomTemperature = 3 => "Engine Room Temperature",
+        MainCabinTemperature = 4 => "Main Cabin Temperature",
+        LiveWellTemperature = 5 => "Live Well Temperature",
+        BaitWellTemperature = 6 => "Bait Well Temperature",
+        RefrigerationTemperature = 7 => "Refrigeration Temperature",
+        HeatingSystemTemperature = 8 => "Heating System Temperature",
+        DewPointTemperature = 9 => "Dew Point Temperature",
+        ApparentWindChillTemperature = 10 => "Apparent Wind Chill Temperature",
+        TheoreticalWindChillTemperature = 11 => "Theoretical Wind Chill Temperature",
+        HeatIndexTemperature = 12 => "Heat Index Temperature",
+        FreezerTemperature = 13 => "Freezer Temperature",
+        ExhaustGasTemperature = 14 => "Exhaust Gas Temperature",
+        ShaftSealTemperature = 15 => "Shaft Seal Temperature",
+    }
+}
+
+lookup! {
+    pub enum HumiditySource: u8 {
+        Inside = 0,
+        Outside = 1,
+    }
+}
+
+lookup! {
+    pub enum PressureSource: u8 {
+        Atmospheric = 0,
+        Water = 1,
+        Steam = 2,
+        CompressedAir = 3 => "Compressed Air",
+        Hydraulic = 4,
+        Filter = 5,
+        AltimeterSetting = 6 => "Altimeter Setting",
+        Oil = 7,
+        Fuel = 8,
+    }
+}
+
+lookup! {
+    pub enum ResidualMode: u8 {
+        Autonomous = 0,
+        DifferentialEnhanced = 1 => "Differential enhanced",
+        Estimated = 2,
+        Simulator = 3,
+        Manual = 4,
+    }
+}
+
+lookup! {
+    pub enum WindReference: u8 {
+        TrueNorth = 0 => "True (ground referenced to North)",
+        Magnetic = 1 => "Magnetic (ground referenced to Magnetic North)",
+        Apparent = 2,
+        TrueBoat = 3 => "True (boat referenced)",
+        TrueWater = 4 => "True (water referenced)",
+    }
+}
+
+lookup! {
+    pub enum TideTendency: u8 {
+        Falling = 0,
+        Rising = 1,
+    }
+}
+
+lookup! {
+    pub enum FloodState: u8 {
+        Flood = 0,
+        Slack = 1,
+        Ebb = 2,
+    }
+}
+
+/// PGN 130310 - Environmental Parameters (obsolete)
+#[derive(Clone, Debug, PartialEq)]
+pub struct OutsideEnvironmentalParameters {
+    pub sid: u8,
+    /// Kelvin
+    pub water_temperature: Option<f32>,
+    /// Kelvin
+    pub outside_ambient_air_temperature: Option<f32>,
+    /// Pascal
+    pub atmospheric_pressure: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for OutsideEnvironmentalParameters {
+    const PGN: u32 = 130310;
+    const PRIORITY: Priority = Priority::Priority5;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(OutsideEnvironmentalParameters {
+            sid: r.u8()?,
+            water_temperature: r.ufix(16, 0.01)?,
+            outside_ambient_air_temperature: r.ufix(16, 0.01)?,
+            atmospheric_pressure: r.ufix(16, 100.0)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.ufix(16, 0.01, self.water_temperature)?;
+        w.ufix(16, 0.01, self.outside_ambient_air_temperature)?;
+        w.ufix(16, 100.0, self.atmospheric_pressure)?;
+        w.reserved(8)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 130311 - Environmental Parameters
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnvironmentalParameters {
+    pub sid: u8,
+    pub temperature_source: TemperatureSource,
+    pub humidity_source: HumiditySource,
+    /// Kelvin
+    pub temperature: Option<f32>,
+    /// Percent
+    pub humidity: Option<f32>,
+    /// Pascal
+    pub atmospheric_pressure: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for EnvironmentalParameters {
+    const PGN: u32 = 130311;
+    const PRIORITY: Priority = Priority::Priority5;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(EnvironmentalParameters {
+            sid: r.u8()?,
+            temperature_source: r.lookup(6)?,
+            humidity_source: r.lookup(2)?,
+            temperature: r.ufix(16, 0.01)?,
+            humidity: r.sfix(16, 0.004)?,
+            atmospheric_pressure: r.ufix(16, 100.0)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.lookup(6, self.temperature_source)?;
+        w.lookup(2, self.humidity_source)?;
+        w.ufix(16, 0.01, self.temperature)?;
+        w.sfix(16, 0.004, self.humidity)?;
+        w.ufix(16, 100.0, self.atmospheric_pressure)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 130312 - Temperature
+#[derive(Clone, Debug, PartialEq)]
+pub struct Temperature {
+    pub sid: u8,
+    pub instance: u8,
+    pub source: TemperatureSource,
+    /// Kelvin
+    pub actual_temperature: Option<f32>,
+    /// Kelvin
+    pub set_temperature: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for Temperature {
+    const PGN: u32 = 130312;
+    const PRIORITY: Priority = Priority::Priority5;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(Temperature {
+            sid: r.u8()?,
+            instance: r.u8()?,
+            source: r.lookup(8)?,
+            actual_temperature: r.ufix(16, 0.01)?,
+            set_temperature: r.ufix(16, 0.01)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.u8(self.instance)?;
+        w.lookup(8, self.source)?;
+        w.ufix(16, 0.01, self.actual_temperature)?;
+        w.ufix(16, 0.01, self.set_temperature)?;
+        w.reserved(8)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 130313 - Humidity
+#[derive(Clone, Debug, PartialEq)]
+pub struct Humidity {
+    pub sid: u8,
+    pub instance: u8,
+    pub source: HumiditySource,
+    /// Percent
+    pub actual_humidity: Option<f32>,
+    /// Percent
+    pub set_humidity: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for Humidity {
+    const PGN: u32 = 130313;
+    const PRIORITY: Priority = Priority::Priority5;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(Humidity {
+            sid: r.u8()?,
+            instance: r.u8()?,
+            source: r.lookup(8)?,
+            actual_humidity: r.sfix(16, 0.004)?,
+            set_humidity: r.sfix(16, 0.004)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.u8(self.instance)?;
+        w.lookup(8, self.source)?;
+        w.sfix(16, 0.004, self.actual_humidity)?;
+        w.sfix(16, 0.004, self.set_humidity)?;
+        w.reserved(8)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 130314 - Actual Pressure
+#[derive(Clone, Debug, PartialEq)]
+pub struct ActualPressure {
+    pub sid: u8,
+    pub instance: u8,
+    pub source: PressureSource,
+    /// Pascal
+    pub pressure: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for ActualPressure {
+    const PGN: u32 = 130314;
+    const PRIORITY: Priority = Priority::Priority5;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(ActualPressure {
+            sid: r.u8()?,
+            instance: r.u8()?,
+            source: r.lookup(8)?,
+            pressure: r.sfix(32, 0.1)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.u8(self.instance)?;
+        w.lookup(8, self.source)?;
+        w.sfix(32, 0.1, self.pressure)?;
+        w.reserved(8)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 130315 - Set Pressure
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetPressure {
+    pub sid: u8,
+    pub instance: u8,
+    pub source: PressureSource,
+    /// Pascal
+    pub pressure: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for SetPressure {
+    const PGN: u32 = 130315;
+    const PRIORITY: Priority = Priority::Priority5;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(SetPressure {
+            sid: r.u8()?,
+            instance: r.u8()?,
+            source: r.lookup(8)?,
+            pressure: r.sfix(32, 0.1)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.u8(self.instance)?;
+        w.lookup(8, self.source)?;
+        w.sfix(32, 0.1, self.pressure)?;
+        w.reserved(8)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 130316 - Temperature Extended Range
+#[derive(Clone, Debug, PartialEq)]
+pub struct TemperatureExtendedRange {
+    pub sid: u8,
+    pub instance: u8,
+    pub source: TemperatureSource,
+    /// Kelvin
+    pub temperature: Option<f32>,
+    /// Kelvin
+    pub set_temperature: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for TemperatureExtendedRange {
+    const PGN: u32 = 130316;
+    const PRIORITY: Priority = Priority::Priority5;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(TemperatureExtendedRange {
+            sid: r.u8()?,
+            instance: r.u8()?,
+            source: r.lookup(8)?,
+            temperature: r.ufix(24, 0.001)?,
+            set_temperature: r.ufix(16, 0.1)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.u8(self.instance)?;
+        w.lookup(8, self.source)?;
+        w.ufix(24, 0.001, self.temperature)?;
+        w.ufix(16, 0.1, self.set_temperature)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 130320 - Tide Station Data
+#[derive(Clone, Debug, PartialEq)]
+pub struct TideStationData<'a> {
+    pub mode: ResidualMode,
+    pub tendency: TideTendency,
+    /// Days since 1970-01-01
+    pub measurement_date: Option<u16>,
+    /// Seconds since midnight
+    pub measurement_time: Option<f64>,
+    /// Degrees
+    pub station_latitude: Option<f64>,
+    /// Degrees
+    pub station_longitude: Option<f64>,
+    /// Metres
+    pub tide_level: Option<f32>,
+    /// Metres
+    pub tide_level_standard_deviation: Option<f32>,
+    pub station_id: LauString<'a>,
+    pub station_name: LauString<'a>,
+}
+
+impl<'a> Pgn<'a> for TideStationData<'a> {
+    const PGN: u32 = 130320;
+    const PRIORITY: Priority = Priority::Priority6;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let mode = r.lookup(4)?;
+        let tendency = r.lookup(2)?;
+        r.reserved(2)?;
+        Ok(TideStationData {
+            mode,
+            tendency,
+            measurement_date: r.uint(16)?.map(|v| v as u16),
+            measurement_time: r.ufix64(32, 0.0001)?,
+            station_latitude: r.sfix64(32, 1e-7)?,
+            station_longitude: r.sfix64(32, 1e-7)?,
+            tide_level: r.sfix(16, 0.001)?,
+            tide_level_standard_deviation: r.ufix(16, 0.01)?,
+            station_id: r.string_lau()?,
+            station_name: r.string_lau()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.lookup(4, self.mode)?;
+        w.lookup(2, self.tendency)?;
+        w.reserved(2)?;
+        w.uint(16, self.measurement_date.map(|v| v as u64))?;
+        w.ufix64(32, 0.0001, self.measurement_time)?;
+        w.sfix64(32, 1e-7, self.station_latitude)?;
+        w.sfix64(32, 1e-7, self.station_longitude)?;
+        w.sfix(16, 0.001, self.tide_level)?;
+        w.ufix(16, 0.01, self.tide_level_standard_deviation)?;
+        w.string_lau(self.station_id)?;
+        w.string_lau(self.station_name)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 130321 - Salinity Station Data
+#[derive(Clone, Debug, PartialEq)]
+pub struct SalinityStationData<'a> {
+    pub mode: ResidualMode,
+    /// Days since 1970-01-01
+    pub measurement_date: Option<u16>,
+    /// Seconds since midnight
+    pub measurement_time: Option<f64>,
+    /// Degrees
+    pub station_latitude: Option<f64>,
+    /// Degrees
+    pub station_longitude: Option<f64>,
+    /// Parts per thousand, sent as a 32 bit float
+    pub salinity: f32,
+    /// Kelvin
+    pub water_temperature: Option<f32>,
+    pub station_id: LauString<'a>,
+    pub station_name: LauString<'a>,
+}
+
+impl<'a> Pgn<'a> for SalinityStationData<'a> {
+    const PGN: u32 = 130321;
+    const PRIORITY: Priority = Priority::Priority6;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let mode = r.lookup(4)?;
+        r.reserved(4)?;
+        Ok(SalinityStationData {
+            mode,
+            measurement_date: r.uint(16)?.map(|v| v as u16),
+            measurement_time: r.ufix64(32, 0.0001)?,
+            station_latitude: r.sfix64(32, 1e-7)?,
+            station_longitude: r.sfix64(32, 1e-7)?,
+            salinity: f32::from_bits(r.bits(32)? as u32),
+            water_temperature: r.ufix(16, 0.01)?,
+            station_id: r.string_lau()?,
+            station_name: r.string_lau()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.lookup(4, self.mode)?;
+        w.reserved(4)?;
+        w.uint(16, self.measurement_date.map(|v| v as u64))?;
+        w.ufix64(32, 0.0001, self.measurement_time)?;
+        w.sfix64(32, 1e-7, self.station_latitude)?;
+        w.sfix64(32, 1e-7, self.station_longitude)?;
+        w.bits(32, self.salinity.to_bits() as u64)?;
+        w.ufix(16, 0.01, self.water_temperature)?;
+        w.string_lau(self.station_id)?;
+        w.string_lau(self.station_name)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 130322 - Current Station Data
+#[derive(Clone, Debug, PartialEq)]
+pub struct CurrentStationData<'a> {
+    pub mode: ResidualMode,
+    pub state: FloodState,
+    /// Days since 1970-01-01
+    pub measurement_date: Option<u16>,
+    /// Seconds since midnight
+    pub measurement_time: Option<f64>,
+    /// Degrees
+    pub station_latitude: Option<f64>,
+    /// Degrees
+    pub station_longitude: Option<f64>,
+    /// Metres
+    pub measurement_depth: Option<f32>,
+    /// Metres per second
+    pub current_speed: Option<f32>,
+    /// Radians
+    pub current_flow_direction: Option<f32>,
+    /// Kelvin
+    pub water_temperature: Option<f32>,
+    pub station_id: LauString<'a>,
+    pub station_name: LauString<'a>,
+}
+
+impl<'a> Pgn<'a> for CurrentStationData<'a> {
+    const PGN: u32 = 130322;
+    const PRIORITY: Priority = Priority::Priority6;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let mode = r.lookup(4)?;
+        let state = r.lookup(3)?;
+        r.reserved(1)?;
+        Ok(CurrentStationData {
+            mode,
+            state,
+            measurement_date: r.uint(16)?.map(|v| v as u16),
+            measurement_time: r.ufix64(32, 0.0001)?,
+            station_latitude: r.sfix64(32, 1e-7)?,
+            station_longitude: r.sfix64(32, 1e-7)?,
+            measurement_depth: r.ufix(32, 0.01)?,
+            current_speed: r.ufix(16, 0.01)?,
+            current_flow_direction: r.ufix(16, 0.0001)?,
+            water_temperature: r.ufix(16, 0.01)?,
+            station_id: r.string_lau()?,
+            station_name: r.string_lau()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.lookup(4, self.mode)?;
+        w.lookup(3, self.state)?;
+        w.reserved(1)?;
+        w.uint(16, self.measurement_date.map(|v| v as u64))?;
+        w.ufix64(32, 0.0001, self.measurement_time)?;
+        w.sfix64(32, 1e-7, self.station_latitude)?;
+        w.sfix64(32, 1e-7, self.station_longitude)?;
+        w.ufix(32, 0.01, self.measurement_depth)?;
+        w.ufix(16, 0.01, self.current_speed)?;
+        w.ufix(16, 0.0001, self.current_flow_direction)?;
+        w.ufix(16, 0.01, self.water_temperature)?;
+        w.string_lau(self.station_id)?;
+        w.string_lau(self.station_name)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 130323 - Meteorological Station Data
+#[derive(Clone, Debug, PartialEq)]
+pub struct MeteorologicalStationData<'a> {
+    pub mode: ResidualMode,
+    /// Days since 1970-01-01
+    pub measurement_date: Option<u16>,
+    /// Seconds since midnight
+    pub measurement_time: Option<f64>,
+    /// Degrees
+    pub station_latitude: Option<f64>,
+    /// Degrees
+    pub station_longitude: Option<f64>,
+    /// Metres per second
+    pub wind_speed: Option<f32>,
+    /// Radians
+    pub wind_direction: Option<f32>,
+    pub wind_reference: WindReference,
+    /// Metres per second
+    pub wind_gusts: Option<f32>,
+    /// Pascal
+    pub atmospheric_pressure: Option<f32>,
+    /// Kelvin
+    pub ambient_temperature: Option<f32>,
+    pub station_id: LauString<'a>,
+    pub station_name: LauString<'a>,
+}
+
+impl<'a> Pgn<'a> for MeteorologicalStationData<'a> {
+    const PGN: u32 = 130323;
+    const PRIORITY: Priority = Priority::Priority6;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let mode = r.lookup(4)?;
+        r.reserved(4)?;
+        Ok(MeteorologicalStationData {
+            mode,
+            measurement_date: r.uint(16)?.map(|v| v as u16),
+            measurement_time: r.ufix64(32, 0.0001)?,
+            station_latitude: r.sfix64(32, 1e-7)?,
+            station_longitude: r.sfix64(32, 1e-7)?,
+            wind_speed: r.ufix(16, 0.01)?,
+            wind_direction: r.ufix(16, 0.0001)?,
+            wind_reference: {
+                let reference = r.lookup(3)?;
+                r.reserved(5)?;
+                reference
+            },
+            wind_gusts: r.ufix(16, 0.01)?,
+            atmospheric_pressure: r.ufix(16, 100.0)?,
+            ambient_temperature: r.ufix(16, 0.01)?,
+            station_id: r.string_lau()?,
+            station_name: r.string_lau()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.lookup(4, self.mode)?;
+        w.reserved(4)?;
+        w.uint(16, self.measurement_date.map(|v| v as u64))?;
+        w.ufix64(32, 0.0001, self.measurement_time)?;
+        w.sfix64(32, 1e-7, self.station_latitude)?;
+        w.sfix64(32, 1e-7, self.station_longitude)?;
+        w.ufix(16, 0.01, self.wind_speed)?;
+        w.ufix(16, 0.0001, self.wind_direction)?;
+        w.lookup(3, self.wind_reference)?;
+        w.reserved(5)?;
+        w.ufix(16, 0.01, self.wind_gusts)?;
+        w.ufix(16, 100.0, self.atmospheric_pressure)?;
+        w.ufix(16, 0.01, self.ambient_temperature)?;
+        w.string_lau(self.station_id)?;
+        w.string_lau(self.station_name)?;
+        Ok(w.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pgns::{
+        CurrentStationData, EnvironmentalParameters, FloodState, HumiditySource,
+        MeteorologicalStationData, Pgn, ResidualMode, SalinityStationData, Temperature,
+        TemperatureSource, TideStationData, TideTendency, WindReference,
+    };
+
+    #[test]
+    fn temperature_decode() {
+        struct TestCase {
+            data: [u8; 8],
+            source: TemperatureSource,
+            actual_temperature: Option<f32>,
+        }
+        let test_cases = [
+            TestCase {
+                data: [0x01, 0x00, 0x00, 0x83, 0x72, 0xff, 0xff, 0xff],
+                source: TemperatureSource::SeaTemperature,
+                actual_temperature: Some(293.15),
+            },
+            TestCase {
+                data: [0x01, 0x02, 0x0e, 0xff, 0xff, 0xff, 0xff, 0xff],
+                source: TemperatureSource::ExhaustGasTemperature,
+                actual_temperature: None,
+            },
+        ];
+        for i in &test_cases {
+            let temperature = Temperature::decode(&i.data).unwrap();
+            assert_eq!(temperature.source, i.source);
+            assert_eq!(temperature.set_temperature, None);
+            match (temperature.actual_temperature, i.actual_temperature) {
+                (Some(a), Some(b)) => assert!((a - b).abs() < 0.005),
+                (a, b) => assert_eq!(a, b),
+            }
+
+            let mut buf = [0; 8];
+            assert_eq!(temperature.encode(&mut buf).unwrap(), 8);
+            assert_eq!(buf, i.data);
+        }
+    }
+
+    #[test]
+    fn environmental_parameters_encode() {
+        let parameters = EnvironmentalParameters {
+            sid: 0,
+            temperature_source: TemperatureSource::OutsideTemperature,
+            humidity_source: HumiditySource::Outside,
+            temperature: Some(283.15),
+            humidity: Some(50.0),
+            atmospheric_pressure: Some(101300.0),
+        };
+        let mut buf = [0; 8];
+        parameters.encode(&mut buf).unwrap();
+        assert_eq!(buf, [0x00, 0x41, 0x9b, 0x6e, 0xd4, 0x30, 0xf5, 0x03]);
+        let decoded = EnvironmentalParameters::decode(&buf).unwrap();
+        assert_eq!(decoded.humidity_source, HumiditySource::Outside);
+        assert_eq!(decoded.atmospheric_pressure, Some(101300.0));
+    }
+
+    #[test]
+    fn meteorological_station_data_roundtrip() {
+        let data = MeteorologicalStationData {
+            mode: ResidualMode::Autonomous,
+            measurement_date: Some(19000),
+            measurement_time: Some(43200.0),
+            station_latitude: Some(52.5),
+            station_longitude: Some(-4.25),
+            wind_speed: Some(7.5),
+            wind_direction: Some(1.5),
+            wind_reference: WindReference::TrueNorth,
+            wind_gusts: None,
+            atmospheric_pressure: Some(101300.0),
+            ambient_temperature: Some(288.15),
+            station_id: "62001".into(),
+            station_name: "Gascogne".into(),
+        };
+        let mut buf = [0; 64];
+        let len = data.encode(&mut buf).unwrap();
+        assert_eq!(len, 26 + 7 + 10);
+        let decoded = MeteorologicalStationData::decode(&buf[..len]).unwrap();
+        assert_eq!(decoded.station_id, "62001");
+        assert_eq!(decoded.station_name, "Gascogne");
+        assert_eq!(decoded.measurement_date, Some(19000));
+        assert_eq!(decoded.wind_gusts, None);
+        assert_eq!(decoded.wind_reference, WindReference::TrueNorth);
+    }
+
+    #[test]
+    fn tide_station_data_decode() {
+        let data = [
+            0xd0, // autonomous, rising
+            0x2c, 0x4c, // 19500
+            0x80, 0x74, 0xd2, 0x1a, // 45000.0
+            0x00, 0x92, 0xfe, 0x1e, // 52.0
+            0x40, 0xa5, 0xae, 0x02, // 4.5
+            0x7c, 0xfc, // -0.9
+            0x05, 0x00, // 0.05
+            0x07, 0x01, b'H', b'V', b'H', b'0', b'1', // station id
+            0x0c, 0x01, b'H', b'o', b'e', b'k', b' ', b'v', b'a', b'n', b' ', b'H', // name
+        ];
+        let tide = TideStationData::decode(&data).unwrap();
+        assert_eq!(tide.mode, ResidualMode::Autonomous);
+        assert_eq!(tide.tendency, TideTendency::Rising);
+        assert_eq!(tide.measurement_date, Some(19500));
+        assert_eq!(tide.measurement_time, Some(45000.0));
+        assert_eq!(tide.station_latitude, Some(52.0));
+        assert_eq!(tide.station_longitude, Some(4.5));
+        assert_eq!(tide.tide_level, Some(-0.9));
+        assert_eq!(tide.tide_level_standard_deviation, Some(0.05));
+        assert_eq!(tide.station_id, "HVH01");
+        assert_eq!(tide.station_name, "Hoek van H");
+
+        let mut buf = [0; 64];
+        let len = tide.encode(&mut buf).unwrap();
+        assert_eq!(&buf[..len], &data);
+    }
+
+    #[test]
+    fn station_data_roundtrip() {
+        let salinity = SalinityStationData {
+            mode: ResidualMode::Estimated,
+            measurement_date: Some(19500),
+            measurement_time: None,
+            station_latitude: Some(52.0),
+            station_longitude: Some(4.5),
+            salinity: 34.5,
+            water_temperature: Some(288.15),
+            station_id: "S1".into(),
+            station_name: "".into(),
+        };
+        let mut buf = [0; 64];
+        let len = salinity.encode(&mut buf).unwrap();
+        assert_eq!(len, 27);
+        assert_eq!(&buf[15..19], &34.5f32.to_le_bytes());
+        assert_eq!(SalinityStationData::decode(&buf[..len]).unwrap(), salinity);
+
+        let current = CurrentStationData {
+            mode: ResidualMode::Autonomous,
+            state: FloodState::Ebb,
+            measurement_date: None,
+            measurement_time: Some(3600.0),
+            station_latitude: None,
+            station_longitude: None,
+            measurement_depth: Some(5.5),
+            current_speed: Some(1.25),
+            current_flow_direction: Some(3.0),
+            water_temperature: None,
+            station_id: "C7".into(),
+            station_name: "Channel".into(),
+        };
+        let len = current.encode(&mut buf).unwrap();
+        assert_eq!(len, 38);
+        assert_eq!(buf[0], 0xa0);
+        assert_eq!(CurrentStationData::decode(&buf[..len]).unwrap(), current);
+    }
+}
@@ -0,0 +1,383 @@
+use crate::pgns::{LauString, PgnError, Result};
+
+// Fields are packed little endian, least significant bit first. The highest
+// unsigned value (all bits set) and the highest positive signed value mean
+// "data not available".
+
+fn max_unsigned(count: usize) -> u64 {
+    if count >= 64 {
+        u64::MAX
+    } else {
+        (1 << count) - 1
+    }
+}
+
+fn max_signed(count: usize) -> i64 {
+    (max_unsigned(count) >> 1) as i64
+}
+
+fn round(value: f64) -> i64 {
+    if value >= 0.0 {
+        (value + 0.5) as i64
+    } else {
+        (value - 0.5) as i64
+    }
+}
+
+fn trim(data: &[u8]) -> &[u8] {
+    let mut end = data.len();
+    while end > 0 && matches!(data[end - 1], 0x00 | 0xff | b'@' | b' ') {
+        end -= 1;
+    }
+    &data[..end]
+}
+
+pub(crate) struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Reader { data, position: 0 }
+    }
+
+    pub fn bits(&mut self, count: usize) -> Result<u64> {
+        if self.position + count > self.data.len() * 8 {
+            return Err(PgnError::DataTooShort);
+        }
+        let mut value = 0u64;
+        for i in 0..count {
+            let bit = self.position + i;
+            if self.data[bit / 8] & (1 << (bit % 8)) != 0 {
+                value |= 1 << i;
+            }
+        }
+        self.position += count;
+        Ok(value)
+    }
+
+    pub fn u8(&mut self) -> Result<u8> {
+        Ok(self.bits(8)? as u8)
+    }
+
+    pub fn lookup<T: From<u8>>(&mut self, count: usize) -> Result<T> {
+        Ok(T::from(self.bits(count)? as u8))
+    }
+
+    pub fn reserved(&mut self, count: usize) -> Result<()> {
+        self.bits(count).map(|_| ())
+    }
+
+    pub fn uint(&mut self, count: usize) -> Result<Option<u64>> {
+        let value = self.bits(count)?;
+        if value == max_unsigned(count) {
+            Ok(None)
+        } else {
+            Ok(Some(value))
+        }
+    }
+
+    pub fn int(&mut self, count: usize) -> Result<Option<i64>> {
+        let shift = 64 - count as u32;
+        let value = ((self.bits(count)? << shift) as i64) >> shift;
+        if value == max_signed(count) {
+            Ok(None)
+        } else {
+            Ok(Some(value))
+        }
+    }
+
+    pub fn ufix(&mut self, count: usize, resolution: f64) -> Result<Option<f32>> {
+        Ok(self.ufix64(count, resolution)?.map(|v| v as f32))
+    }
+
+    pub fn sfix(&mut self, count: usize, resolution: f64) -> Result<Option<f32>> {
+        Ok(self.sfix64(count, resolution)?.map(|v| v as f32))
+    }
+
+    pub fn ufix64(&mut self, count: usize, resolution: f64) -> Result<Option<f64>> {
+        Ok(self.uint(count)?.map(|v| v as f64 * resolution))
+    }
+
+    pub fn sfix64(&mut self, count: usize, resolution: f64) -> Result<Option<f64>> {
+        Ok(self.int(count)?.map(|v| v as f64 * resolution))
+    }
+
+    pub fn bytes(&mut self, count: usize) -> Result<&'a [u8]> {
+        debug_assert!(self.position % 8 == 0);
+        let start = self.position / 8;
+        if start + count > self.data.len() {
+            return Err(PgnError::DataTooShort);
+        }
+        self.position += count * 8;
+        Ok(&self.data[start..start + count])
+    }
+
+    /// Fixed length string, padded with `@`, spaces, NUL or 0xff.
+    pub fn string_fix(&mut self, count: usize) -> Result<&'a str> {
+        let data = trim(self.bytes(count)?);
+        core::str::from_utf8(data).map_err(|_| PgnError::InvalidString)
+    }
+
+    /// Variable length string prefixed by its total length and an encoding
+    /// byte, 0 for UTF-16 and 1 for ASCII.
+    pub fn string_lau(&mut self) -> Result<LauString<'a>> {
+        let length = self.u8()? as usize;
+        if length < 2 {
+            return Err(PgnError::InvalidString);
+        }
+        let control = self.u8()?;
+        let data = self.bytes(length - 2)?;
+        match control {
+            0 => Ok(LauString::Utf16(data)),
+            1 => core::str::from_utf8(trim(data))
+                .map(LauString::Utf8)
+                .map_err(|_| PgnError::InvalidString),
+            _ => Err(PgnError::InvalidString),
+        }
+    }
+
+    /// Variable length string prefixed by its length and followed by a NUL
+    /// terminator that is not included in the length.
+    pub fn string_lz(&mut self) -> Result<&'a str> {
+        let length = self.u8()? as usize;
+        let data = self.bytes(length)?;
+        if self.u8()? != 0 {
+            return Err(PgnError::InvalidString);
+        }
+        core::str::from_utf8(trim(data)).map_err(|_| PgnError::InvalidString)
+    }
+
+    /// Number of bits left to read.
+    pub fn remaining(&self) -> usize {
+        (self.data.len() * 8).saturating_sub(self.position)
+    }
+
+    /// Consumes the remaining bytes, typically a repeating field set.
+    pub fn rest(&mut self) -> &'a [u8] {
+        debug_assert!(self.position % 8 == 0);
+        let start = (self.position / 8).min(self.data.len());
+        self.position = self.data.len() * 8;
+        &self.data[start..]
+    }
+}
+
+pub(crate) struct Writer<'a> {
+    buf: &'a mut [u8],
+    position: usize,
+}
+
+impl<'a> Writer<'a> {
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Writer { buf, position: 0 }
+    }
+
+    pub fn bits(&mut self, count: usize, value: u64) -> Result<()> {
+        if self.position + count > self.buf.len() * 8 {
+            return Err(PgnError::BufferTooSmall);
+        }
+        for i in 0..count {
+            let bit = self.position + i;
+            if value & (1 << i) != 0 {
+                self.buf[bit / 8] |= 1 << (bit % 8);
+            } else {
+                self.buf[bit / 8] &= !(1 << (bit % 8));
+            }
+        }
+        self.position += count;
+        Ok(())
+    }
+
+    pub fn u8(&mut self, value: u8) -> Result<()> {
+        self.bits(8, value as u64)
+    }
+
+    pub fn lookup<T: Into<u8>>(&mut self, count: usize, value: T) -> Result<()> {
+        self.bits(count, value.into() as u64)
+    }
+
+    pub fn reserved(&mut self, count: usize) -> Result<()> {
+        self.bits(count, u64::MAX)
+    }
+
+    pub fn uint(&mut self, count: usize, value: Option<u64>) -> Result<()> {
+        let max = max_unsigned(count);
+        match value {
+            Some(v) => self.bits(count, v.min(max - 1)),
+            None => self.bits(count, max),
+        }
+    }
+
+    pub fn int(&mut self, count: usize, value: Option<i64>) -> Result<()> {
+        let max = max_signed(count);
+        let value = match value {
+            Some(v) => v.max(-max - 1).min(max - 1),
+            None => max,
+        };
+        self.bits(count, value as u64)
+    }
+
+    pub fn ufix(&mut self, count: usize, resolution: f64, value: Option<f32>) -> Result<()> {
+        self.ufix64(count, resolution, value.map(|v| v as f64))
+    }
+
+    pub fn sfix(&mut self, count: usize, resolution: f64, value: Option<f32>) -> Result<()> {
+        self.sfix64(count, resolution, value.map(|v| v as f64))
+    }
+
+    pub fn ufix64(&mut self, count: usize, resolution: f64, value: Option<f64>) -> Result<()> {
+        self.uint(count, value.map(|v| round(v / resolution).max(0) as u64))
+    }
+
+    pub fn sfix64(&mut self, count: usize, resolution: f64, value: Option<f64>) -> Result<()> {
+        self.int(count, value.map(|v| round(v / resolution)))
+    }
+
+    pub fn bytes(&mut self, data: &[u8]) -> Result<()> {
+        debug_assert!(self.position % 8 == 0);
+        let start = self.position / 8;
+        if start + data.len() > self.buf.len() {
+            return Err(PgnError::BufferTooSmall);
+        }
+        self.buf[start..start + data.len()].copy_from_slice(data);
+        self.position += data.len() * 8;
+        Ok(())
+    }
+
+    pub fn string_fix(&mut self, value: &str, count: usize, padding: u8) -> Result<()> {
+        let data = value.as_bytes();
+        let length = data.len().min(count);
+        self.bytes(&data[..length])?;
+        for _ in length..count {
+            self.u8(padding)?;
+        }
+        Ok(())
+    }
+
+    pub fn string_lau(&mut self, value: LauString) -> Result<()> {
+        let (control, data) = match value {
+            LauString::Utf16(data) => (0, data),
+            LauString::Utf8(text) => (1, text.as_bytes()),
+        };
+        if data.len() > 253 {
+            return Err(PgnError::InvalidString);
+        }
+        self.u8(data.len() as u8 + 2)?;
+        self.u8(control)?;
+        self.bytes(data)
+    }
+
+    pub fn string_lz(&mut self, value: &str) -> Result<()> {
+        let data = value.as_bytes();
+        if data.len() > 255 {
+            return Err(PgnError::InvalidString);
+        }
+        self.u8(data.len() as u8)?;
+        self.bytes(data)?;
+        self.u8(0)
+    }
+
+    /// Number of bytes written so far, including a partially written one.
+    pub fn len(&self) -> usize {
+        (self.position + 7) / 8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pgns::field::{Reader, Writer};
+    use crate::pgns::LauString;
+
+    #[test]
+    fn field_bits() {
+        let data = [0xa5, 0x3c, 0xff];
+        let mut reader = Reader::new(&data);
+        assert_eq!(reader.bits(4).unwrap(), 0x5);
+        assert_eq!(reader.bits(8).unwrap(), 0xca);
+        assert_eq!(reader.bits(4).unwrap(), 0x3);
+        assert_eq!(reader.uint(8).unwrap(), None);
+        assert!(reader.bits(1).is_err());
+
+        let mut buf = [0; 3];
+        let mut writer = Writer::new(&mut buf);
+        writer.bits(4, 0x5).unwrap();
+        writer.bits(8, 0xca).unwrap();
+        writer.bits(4, 0x3).unwrap();
+        writer.uint(8, None).unwrap();
+        assert!(writer.bits(1, 0).is_err());
+        assert_eq!(writer.len(), 3);
+        assert_eq!(buf, data);
+    }
+
+    #[test]
+    fn field_fixed_point() {
+        struct TestCase {
+            data: [u8; 2],
+            value: Option<f32>,
+        }
+        let test_cases = [
+            TestCase {
+                data: [0x00, 0x00],
+                value: Some(0.0),
+            },
+            TestCase {
+                data: [0x18, 0xfc],
+                value: Some(-10.0),
+            },
+            TestCase {
+                data: [0xe8, 0x03],
+                value: Some(10.0),
+            },
+            TestCase {
+                data: [0xff, 0x7f],
+                value: None,
+            },
+        ];
+        for i in &test_cases {
+            let value = Reader::new(&i.data).sfix(16, 0.01).unwrap();
+            match (value, i.value) {
+                (Some(a), Some(b)) => assert!((a - b).abs() < 0.001),
+                (a, b) => assert_eq!(a, b),
+            }
+
+            let mut buf = [0; 2];
+            Writer::new(&mut buf).sfix(16, 0.01, i.value).unwrap();
+            assert_eq!(buf, i.data);
+        }
+    }
+
+    #[test]
+    fn field_strings() {
+        let mut buf = [0; 16];
+        let mut writer = Writer::new(&mut buf);
+        writer.string_lau("N2K".into()).unwrap();
+        let len = writer.len();
+        assert_eq!(&buf[..len], b"\x05\x01N2K");
+
+        let mut reader = Reader::new(&buf[..len]);
+        assert_eq!(reader.string_lau().unwrap(), LauString::Utf8("N2K"));
+        assert!(reader.string_lau().is_err());
+
+        let mut reader = Reader::new(b"\x08\x00N\x002\x00K\x00\x05\x01N2K");
+        let text = reader.string_lau().unwrap();
+        assert_eq!(text, LauString::Utf16(b"N\x002\x00K\x00"));
+        assert_eq!(text, "N2K");
+        assert_eq!(reader.string_lau().unwrap(), "N2K");
+        assert!(Reader::new(b"\x03\x02N").string_lau().is_err());
+
+        let mut writer = Writer::new(&mut buf);
+        writer.string_lau(text).unwrap();
+        let len = writer.len();
+        assert_eq!(&buf[..len], b"\x08\x00N\x002\x00K\x00");
+
+        let mut writer = Writer::new(&mut buf);
+        writer.string_lz("FM").unwrap();
+        let len = writer.len();
+        assert_eq!(&buf[..len], b"\x02FM\x00");
+
+        let mut reader = Reader::new(&buf[..len]);
+        assert_eq!(reader.string_lz().unwrap(), "FM");
+        assert!(Reader::new(b"\x02FMX").string_lz().is_err());
+    }
+}
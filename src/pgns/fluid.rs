@@ -0,0 +1,206 @@
+use heapless::Vec;
+
+use crate::pgns::field::{Reader, Writer};
+use crate::pgns::{Pgn, Result};
+use crate::Priority;
+
+lookup! {
+    pub enum FluidType: u8 {
+        Fuel = 0,
+        FreshWater = 1 => "Water",
+        WasteWater = 2 => "Gray water",
+        LiveWell = 3 => "Live well",
+        Oil = 4,
+        BlackWater = 5 => "Black water",
+        Gasoline = 6,
+    }
+}
+
+/// PGN 127505 - Fluid Level
+#[derive(Clone, Debug, PartialEq)]
+pub struct FluidLevel {
+    pub instance: u8,
+    pub fluid_type: FluidType,
+    /// Percent
+    pub level: Option<f32>,
+    /// Litres
+    pub capacity: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for FluidLevel {
+    const PGN: u32 = 127505;
+    const PRIORITY: Priority = Priority::Priority6;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(FluidLevel {
+            instance: r.bits(4)? as u8,
+            fluid_type: r.lookup(4)?,
+            level: r.sfix(16, 0.004)?,
+            capacity: r.ufix(32, 0.1)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.bits(4, self.instance as u64)?;
+        w.lookup(4, self.fluid_type)?;
+        w.sfix(16, 0.004, self.level)?;
+        w.ufix(32, 0.1, self.capacity)?;
+        w.reserved(8)?;
+        Ok(w.len())
+    }
+}
+
+/// Combined contents of all tanks holding one fluid type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FluidTotal {
+    pub tanks: usize,
+    /// Percent, weighted by capacity when every tank reports one
+    pub level: Option<f32>,
+    /// Litres, of the tanks that report both level and capacity
+    pub capacity: f32,
+    /// Litres, of the tanks that report both level and capacity
+    pub volume: f32,
+}
+
+struct Tank {
+    source: u8,
+    instance: u8,
+    fluid_type: FluidType,
+    level: Option<f32>,
+    capacity: Option<f32>,
+}
+
+/// Tracks the latest fluid level of up to `N` tanks, identified by the
+/// source address, instance and fluid type of their PGN 127505 reports.
+pub struct TankLevels<const N: usize> {
+    tanks: Vec<Tank, N>,
+}
+
+impl<const N: usize> TankLevels<N> {
+    pub fn new() -> Self {
+        TankLevels { tanks: Vec::new() }
+    }
+
+    /// Records a fluid level report, returning false if a new tank could not
+    /// be tracked because `N` tanks are already known.
+    pub fn update(&mut self, source: u8, level: &FluidLevel) -> bool {
+        let tank = Tank {
+            source,
+            instance: level.instance,
+            fluid_type: level.fluid_type,
+            level: level.level,
+            capacity: level.capacity,
+        };
+        match self.tanks.iter_mut().find(|t| {
+            t.source == tank.source
+                && t.instance == tank.instance
+                && t.fluid_type == tank.fluid_type
+        }) {
+            Some(t) => {
+                *t = tank;
+                true
+            }
+            None => self.tanks.push(tank).is_ok(),
+        }
+    }
+
+    /// Aggregates the levels of all tanks holding `fluid_type`.
+    pub fn total(&self, fluid_type: FluidType) -> Option<FluidTotal> {
+        let mut tanks = 0;
+        let mut levels = 0;
+        let mut level_sum = 0.0;
+        let mut capacity = 0.0;
+        let mut volume = 0.0;
+        let mut weighted = true;
+        for tank in self.tanks.iter().filter(|t| t.fluid_type == fluid_type) {
+            tanks += 1;
+            if let Some(level) = tank.level {
+                levels += 1;
+                level_sum += level;
+                match tank.capacity {
+                    Some(c) => {
+                        capacity += c;
+                        volume += c * level / 100.0;
+                    }
+                    None => weighted = false,
+                }
+            }
+        }
+        if tanks == 0 {
+            return None;
+        }
+
+        let level = if levels == 0 {
+            None
+        } else if weighted && capacity > 0.0 {
+            Some(volume / capacity * 100.0)
+        } else {
+            Some(level_sum / levels as f32)
+        };
+        Some(FluidTotal {
+            tanks,
+            level,
+            capacity,
+            volume,
+        })
+    }
+}
+
+impl<const N: usize> Default for TankLevels<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pgns::{FluidLevel, FluidType, Pgn, TankLevels};
+
+    #[test]
+    fn fluid_level_decode() {
+        let data = [0x21, 0xa8, 0x61, 0xd0, 0x07, 0x00, 0x00, 0xff];
+        let level = FluidLevel::decode(&data).unwrap();
+        assert_eq!(level.instance, 1);
+        assert_eq!(level.fluid_type, FluidType::WasteWater);
+        assert_eq!(level.level, Some(100.0));
+        assert_eq!(level.capacity, Some(200.0));
+
+        let mut buf = [0; 8];
+        level.encode(&mut buf).unwrap();
+        assert_eq!(buf, data);
+    }
+
+    #[test]
+    fn tank_levels_total() {
+        let mut tanks: TankLevels<4> = TankLevels::new();
+        let reports = [
+            (10, 0, FluidType::Fuel, Some(50.0), Some(300.0)),
+            (11, 0, FluidType::Fuel, Some(25.0), Some(100.0)),
+            (10, 1, FluidType::FreshWater, Some(80.0), None),
+            (10, 0, FluidType::Fuel, Some(40.0), Some(300.0)),
+        ];
+        for (source, instance, fluid_type, level, capacity) in &reports {
+            let level = FluidLevel {
+                instance: *instance,
+                fluid_type: *fluid_type,
+                level: *level,
+                capacity: *capacity,
+            };
+            assert!(tanks.update(*source, &level));
+        }
+
+        let fuel = tanks.total(FluidType::Fuel).unwrap();
+        assert_eq!(fuel.tanks, 2);
+        assert_eq!(fuel.capacity, 400.0);
+        assert_eq!(fuel.volume, 145.0);
+        assert_eq!(fuel.level, Some(36.25));
+
+        let water = tanks.total(FluidType::FreshWater).unwrap();
+        assert_eq!(water.level, Some(80.0));
+        assert_eq!(water.capacity, 0.0);
+
+        assert_eq!(tanks.total(FluidType::Oil), None);
+    }
+}
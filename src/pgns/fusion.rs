@@ -0,0 +1,346 @@
+use crate::pgns::field::{Reader, Writer};
+use crate::pgns::{IndustryGroup, Pgn, PgnError, ProprietaryDecoder, ProprietaryHeader, Result};
+use crate::Priority;
+
+/// Manufacturer code of Fusion Electronics.
+pub const FUSION_MANUFACTURER_CODE: u16 = 419;
+
+const SOURCE: u16 = 0x8002;
+const TRACK_TITLE: u16 = 0x8005;
+const TRACK_ARTIST: u16 = 0x8006;
+const TRACK_ALBUM: u16 = 0x8007;
+const TRACK_PROGRESS: u16 = 0x8009;
+const MUTE: u16 = 0x8017;
+const VOLUME: u16 = 0x801d;
+const POWER: u16 = 0x8020;
+
+lookup! {
+    pub enum FusionPowerState: u8 {
+        On = 1,
+        Off = 2,
+    }
+}
+
+lookup! {
+    pub enum FusionMuteState: u8 {
+        Muted = 1,
+        NotMuted = 2 => "Not muted",
+    }
+}
+
+lookup! {
+    pub enum FusionSourceType: u8 {
+        Am = 0 => "AM",
+        Fm = 1 => "FM",
+        Aux = 2,
+        Sirius = 3,
+        Ipod = 4 => "iPod",
+        Usb = 5 => "USB",
+        Dvd = 6 => "DVD",
+        Vhf = 7 => "VHF",
+        Invalid = 8,
+        Mtp = 9 => "MTP",
+        Bluetooth = 10,
+        Arc = 11 => "ARC",
+        Android = 12,
+        Pandora = 13,
+        Dab = 14 => "DAB",
+        AirPlay = 15,
+        Upnp = 16 => "UPNP",
+    }
+}
+
+/// A Fusion stereo status message carried in PGN 130820, selected by its
+/// message id.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FusionMessage<'a> {
+    /// Message 0x8002
+    SourceName {
+        source_id: u8,
+        current_source_id: u8,
+        source_type: FusionSourceType,
+        flags: u8,
+        name: &'a str,
+    },
+    /// Message 0x8005
+    Track {
+        source_id: u8,
+        index: u32,
+        title: &'a str,
+    },
+    /// Message 0x8006
+    Artist {
+        source_id: u8,
+        index: u32,
+        artist: &'a str,
+    },
+    /// Message 0x8007
+    Album {
+        source_id: u8,
+        index: u32,
+        album: &'a str,
+    },
+    /// Message 0x8009
+    PlayProgress {
+        source_id: u8,
+        /// Seconds into the current track
+        progress: Option<f32>,
+    },
+    /// Message 0x8017
+    Mute(FusionMuteState),
+    /// Message 0x801d, volume of zones 1 to 4
+    Volume([u8; 4]),
+    /// Message 0x8020
+    PowerState(FusionPowerState),
+    /// A message this crate does not decode.
+    Unknown { message_id: u16, data: &'a [u8] },
+}
+
+impl<'a> FusionMessage<'a> {
+    pub fn message_id(&self) -> u16 {
+        match self {
+            FusionMessage::SourceName { .. } => SOURCE,
+            FusionMessage::Track { .. } => TRACK_TITLE,
+            FusionMessage::Artist { .. } => TRACK_ARTIST,
+            FusionMessage::Album { .. } => TRACK_ALBUM,
+            FusionMessage::PlayProgress { .. } => TRACK_PROGRESS,
+            FusionMessage::Mute(_) => MUTE,
+            FusionMessage::Volume(_) => VOLUME,
+            FusionMessage::PowerState(_) => POWER,
+            FusionMessage::Unknown { message_id, .. } => *message_id,
+        }
+    }
+
+    fn decode(r: &mut Reader<'a>) -> Result<Self> {
+        let message_id = r.bits(16)? as u16;
+        let message = match message_id {
+            SOURCE => FusionMessage::SourceName {
+                source_id: r.u8()?,
+                current_source_id: r.u8()?,
+                source_type: r.lookup(8)?,
+                flags: r.u8()?,
+                name: r.string_lz()?,
+            },
+            TRACK_TITLE => FusionMessage::Track {
+                source_id: r.u8()?,
+                index: r.bits(32)? as u32,
+                title: r.string_lz()?,
+            },
+            TRACK_ARTIST => FusionMessage::Artist {
+                source_id: r.u8()?,
+                index: r.bits(32)? as u32,
+                artist: r.string_lz()?,
+            },
+            TRACK_ALBUM => FusionMessage::Album {
+                source_id: r.u8()?,
+                index: r.bits(32)? as u32,
+                album: r.string_lz()?,
+            },
+            TRACK_PROGRESS => FusionMessage::PlayProgress {
+                source_id: r.u8()?,
+                progress: r.ufix(24, 0.001)?,
+            },
+            MUTE => FusionMessage::Mute(r.lookup(8)?),
+            VOLUME => FusionMessage::Volume([r.u8()?, r.u8()?, r.u8()?, r.u8()?]),
+            POWER => FusionMessage::PowerState(r.lookup(8)?),
+            _ => FusionMessage::Unknown {
+                message_id,
+                data: r.rest(),
+            },
+        };
+        Ok(message)
+    }
+
+    fn encode(&self, w: &mut Writer) -> Result<()> {
+        w.bits(16, self.message_id() as u64)?;
+        match self {
+            FusionMessage::SourceName {
+                source_id,
+                current_source_id,
+                source_type,
+                flags,
+                name,
+            } => {
+                w.u8(*source_id)?;
+                w.u8(*current_source_id)?;
+                w.lookup(8, *source_type)?;
+                w.u8(*flags)?;
+                w.string_lz(name)
+            }
+            FusionMessage::Track {
+                source_id,
+                index,
+                title: text,
+            }
+            | FusionMessage::Artist {
+                source_id,
+                index,
+                artist: text,
+            }
+            | FusionMessage::Album {
+                source_id,
+                index,
+                album: text,
+            } => {
+                w.u8(*source_id)?;
+                w.bits(32, *index as u64)?;
+                w.string_lz(text)
+            }
+            FusionMessage::PlayProgress {
+                source_id,
+                progress,
+            } => {
+                w.u8(*source_id)?;
+                w.ufix(24, 0.001, *progress)
+            }
+            FusionMessage::Mute(state) => w.lookup(8, *state),
+            FusionMessage::Volume(zones) => w.bytes(zones),
+            FusionMessage::PowerState(state) => w.lookup(8, *state),
+            FusionMessage::Unknown { data, .. } => w.bytes(data),
+        }
+    }
+}
+
+/// PGN 130820 - the manufacturer proprietary fast packet parameter group,
+/// routed to a typed decoder by the manufacturer code in its header.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Proprietary130820<'a> {
+    Fusion(FusionMessage<'a>),
+    /// A manufacturer this crate does not decode, with the payload following
+    /// the proprietary header.
+    Unknown {
+        header: ProprietaryHeader,
+        data: &'a [u8],
+    },
+}
+
+impl<'a> Pgn<'a> for Proprietary130820<'a> {
+    const PGN: u32 = 130820;
+    const PRIORITY: Priority = Priority::Priority7;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let header = ProprietaryHeader::decode(&mut r)?;
+        if header == ProprietaryHeader::marine(FUSION_MANUFACTURER_CODE) {
+            return Ok(Proprietary130820::Fusion(FusionMessage::decode(&mut r)?));
+        }
+        Ok(Proprietary130820::Unknown {
+            header,
+            data: r.rest(),
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        match self {
+            Proprietary130820::Fusion(message) => {
+                ProprietaryHeader::marine(FUSION_MANUFACTURER_CODE).encode(&mut w)?;
+                message.encode(&mut w)?;
+            }
+            Proprietary130820::Unknown { header, data } => {
+                header.encode(&mut w)?;
+                w.bytes(data)?;
+            }
+        }
+        Ok(w.len())
+    }
+}
+
+/// A `ProprietaryDecoder` passing every Fusion message to a handler.
+pub struct FusionDecoder<F> {
+    handler: F,
+}
+
+impl<F> FusionDecoder<F>
+where
+    F: FnMut(FusionMessage),
+{
+    pub fn new(handler: F) -> Self {
+        FusionDecoder { handler }
+    }
+}
+
+impl<F> ProprietaryDecoder for FusionDecoder<F>
+where
+    F: FnMut(FusionMessage),
+{
+    fn manufacturer_code(&self) -> u16 {
+        FUSION_MANUFACTURER_CODE
+    }
+
+    fn handles(&self, pgn: u32) -> bool {
+        pgn == Proprietary130820::PGN
+    }
+
+    fn decode(&mut self, _pgn: u32, header: &ProprietaryHeader, data: &[u8]) -> Result<()> {
+        match Proprietary130820::decode(data)? {
+            Proprietary130820::Fusion(message)
+                if header.industry_group == IndustryGroup::Marine =>
+            {
+                (self.handler)(message);
+                Ok(())
+            }
+            _ => Err(PgnError::UnexpectedMessage),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pgns::{
+        FusionMessage, FusionPowerState, IndustryGroup, Pgn, Proprietary130820, ProprietaryHeader,
+    };
+
+    #[test]
+    fn proprietary_130820_routes_fusion() {
+        struct TestCase {
+            data: &'static [u8],
+            message: Proprietary130820<'static>,
+        }
+        let test_cases = [
+            TestCase {
+                data: &[0xa3, 0x99, 0x20, 0x80, 0x01],
+                message: Proprietary130820::Fusion(FusionMessage::PowerState(FusionPowerState::On)),
+            },
+            TestCase {
+                data: &[
+                    0xa3, 0x99, 0x05, 0x80, 0x05, 0x03, 0x00, 0x00, 0x00, 0x04, b'S', b'o', b'n',
+                    b'g', 0x00,
+                ],
+                message: Proprietary130820::Fusion(FusionMessage::Track {
+                    source_id: 5,
+                    index: 3,
+                    title: "Song",
+                }),
+            },
+            TestCase {
+                data: &[0xa3, 0x99, 0x1d, 0x80, 0x0c, 0x0a, 0x00, 0x00],
+                message: Proprietary130820::Fusion(FusionMessage::Volume([12, 10, 0, 0])),
+            },
+            TestCase {
+                data: &[0xa3, 0x99, 0x63, 0x80, 0x01, 0x02],
+                message: Proprietary130820::Fusion(FusionMessage::Unknown {
+                    message_id: 0x8063,
+                    data: &[0x01, 0x02],
+                }),
+            },
+            TestCase {
+                data: &[0x89, 0x98, 0x01, 0x02],
+                message: Proprietary130820::Unknown {
+                    header: ProprietaryHeader::new(137, IndustryGroup::Marine),
+                    data: &[0x01, 0x02],
+                },
+            },
+        ];
+
+        for case in &test_cases {
+            let message = Proprietary130820::decode(case.data).unwrap();
+            assert_eq!(message, case.message);
+
+            let mut buf = [0; 32];
+            let len = message.encode(&mut buf).unwrap();
+            assert_eq!(&buf[..len], case.data);
+        }
+    }
+}
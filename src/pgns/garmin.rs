@@ -0,0 +1,158 @@
+use crate::pgns::field::{Reader, Writer};
+use crate::pgns::{Pgn, PgnError, ProprietaryDecoder, ProprietaryHeader, Result};
+use crate::Priority;
+
+/// Manufacturer code of Garmin.
+pub const GARMIN_MANUFACTURER_CODE: u16 = 229;
+
+// Display settings messages start with these bytes.
+const DISPLAY_SETTINGS_ID: [u8; 4] = [222, 5, 5, 5];
+
+const DAY_MODE: u8 = 0;
+const NIGHT_MODE: u8 = 1;
+const COLOR_MODE: u8 = 13;
+
+lookup! {
+    pub enum GarminColor: u8 {
+        DayFullColor = 0 => "Day full color",
+        DayHighContrast = 1 => "Day high contrast",
+        NightFullColor = 2 => "Night full color",
+        NightRedBlack = 3 => "Night red/black",
+        NightGreenBlack = 4 => "Night green/black",
+    }
+}
+
+/// PGN 126720 - Garmin: Day Mode, Night Mode and Color mode
+#[derive(Clone, Debug, PartialEq)]
+pub enum GarminColorMode {
+    /// Backlight in tenths of full brightness
+    Day {
+        backlight: u8,
+    },
+    /// Backlight in tenths of full brightness
+    Night {
+        backlight: u8,
+    },
+    Color(GarminColor),
+}
+
+impl<'a> Pgn<'a> for GarminColorMode {
+    const PGN: u32 = 126720;
+    const PRIORITY: Priority = Priority::Priority7;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        ProprietaryHeader::expect(&mut r, GARMIN_MANUFACTURER_CODE)?;
+        if r.bytes(4)? != DISPLAY_SETTINGS_ID {
+            return Err(PgnError::UnexpectedMessage);
+        }
+        r.reserved(16)?; // spare
+        let mode = r.u8()?;
+        r.reserved(8)?; // spare
+        let value = r.u8()?;
+        match mode {
+            DAY_MODE => Ok(GarminColorMode::Day { backlight: value }),
+            NIGHT_MODE => Ok(GarminColorMode::Night { backlight: value }),
+            COLOR_MODE => Ok(GarminColorMode::Color(GarminColor::from(value))),
+            _ => Err(PgnError::UnexpectedMessage),
+        }
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let (mode, value) = match self {
+            GarminColorMode::Day { backlight } => (DAY_MODE, *backlight),
+            GarminColorMode::Night { backlight } => (NIGHT_MODE, *backlight),
+            GarminColorMode::Color(color) => (COLOR_MODE, u8::from(*color)),
+        };
+        let mut w = Writer::new(buf);
+        ProprietaryHeader::marine(GARMIN_MANUFACTURER_CODE).encode(&mut w)?;
+        w.bytes(&DISPLAY_SETTINGS_ID)?;
+        w.bits(16, 0)?; // spare
+        w.u8(mode)?;
+        w.bits(8, 0)?; // spare
+        w.u8(value)?;
+        Ok(w.len())
+    }
+}
+
+/// A `ProprietaryDecoder` passing Garmin display settings to a handler.
+pub struct GarminDecoder<F> {
+    handler: F,
+}
+
+impl<F> GarminDecoder<F>
+where
+    F: FnMut(GarminColorMode),
+{
+    pub fn new(handler: F) -> Self {
+        GarminDecoder { handler }
+    }
+}
+
+impl<F> ProprietaryDecoder for GarminDecoder<F>
+where
+    F: FnMut(GarminColorMode),
+{
+    fn manufacturer_code(&self) -> u16 {
+        GARMIN_MANUFACTURER_CODE
+    }
+
+    fn handles(&self, pgn: u32) -> bool {
+        pgn == GarminColorMode::PGN
+    }
+
+    fn decode(&mut self, _pgn: u32, _header: &ProprietaryHeader, data: &[u8]) -> Result<()> {
+        (self.handler)(GarminColorMode::decode(data)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pgns::{GarminColor, GarminColorMode, Pgn, PgnError};
+
+    #[test]
+    fn garmin_color_mode() {
+        struct TestCase {
+            data: [u8; 11],
+            mode: GarminColorMode,
+        }
+        let test_cases = [
+            TestCase {
+                data: [
+                    0xe5, 0x98, 0xde, 0x05, 0x05, 0x05, 0x00, 0x00, 0x00, 0x00, 0x0a,
+                ],
+                mode: GarminColorMode::Day { backlight: 10 },
+            },
+            TestCase {
+                data: [
+                    0xe5, 0x98, 0xde, 0x05, 0x05, 0x05, 0x00, 0x00, 0x01, 0x00, 0x02,
+                ],
+                mode: GarminColorMode::Night { backlight: 2 },
+            },
+            TestCase {
+                data: [
+                    0xe5, 0x98, 0xde, 0x05, 0x05, 0x05, 0x00, 0x00, 0x0d, 0x00, 0x03,
+                ],
+                mode: GarminColorMode::Color(GarminColor::NightRedBlack),
+            },
+        ];
+        for case in &test_cases {
+            assert_eq!(GarminColorMode::decode(&case.data).unwrap(), case.mode);
+
+            let mut buf = [0; 11];
+            assert_eq!(case.mode.encode(&mut buf).unwrap(), 11);
+            assert_eq!(buf, case.data);
+        }
+
+        // a Garmin message other than display settings
+        let data = [
+            0xe5, 0x98, 0xdf, 0x05, 0x05, 0x05, 0xff, 0xff, 0x00, 0xff, 0x0a,
+        ];
+        assert_eq!(
+            GarminColorMode::decode(&data),
+            Err(PgnError::UnexpectedMessage)
+        );
+    }
+}
@@ -0,0 +1,67 @@
+//! Parameter groups generated at build time from the vendored canboat
+//! database, `canboat/pgns.json`. The `pgns-*` features select which groups
+//! are compiled in.
+
+// a selection of groups may not need every import
+#[allow(unused_imports)]
+use crate::pgns::field::{Reader, Writer};
+#[allow(unused_imports)]
+use crate::pgns::{Field, LauString, Pgn, PgnDescriptor, PgnError, Result, Transport};
+#[allow(unused_imports)]
+use crate::Priority;
+
+include!(concat!(env!("OUT_DIR"), "/canboat.rs"));
+
+#[cfg(all(test, feature = "pgns-navigation"))]
+mod tests {
+    use super::VesselHeading;
+    use crate::pgns::{DirectionReference, Pgn, PgnDescriptor, Transport};
+    use crate::Priority;
+
+    #[test]
+    fn generated_vessel_heading() {
+        let data = [0x07, 0x10, 0x27, 0x9c, 0xff, 0xff, 0x7f, 0xfd];
+        let heading = VesselHeading::decode(&data).unwrap();
+        assert_eq!(heading.sid, Some(7));
+        assert_eq!(heading.heading, Some(1.0));
+        assert_eq!(heading.deviation, Some(-0.01));
+        assert_eq!(heading.variation, None);
+        assert_eq!(heading.reference, DirectionReference::Magnetic);
+        let mut buf = [0; 8];
+        assert_eq!(heading.encode(&mut buf).unwrap(), 8);
+        assert_eq!(buf, data);
+
+        let descriptor = PgnDescriptor::find(VesselHeading::PGN).unwrap();
+        assert_eq!(descriptor.name, "Vessel Heading");
+        assert_eq!(descriptor.priority, Priority::Priority2);
+        assert_eq!(descriptor.interval, Some(100));
+        assert_eq!(descriptor.min_length(), 8);
+        assert_eq!(Transport::select(129025, 8), Transport::Single);
+    }
+}
+
+#[cfg(all(test, feature = "pgns-other"))]
+mod other_tests {
+    use super::SeatalkPilotHeading;
+    use crate::pgns::{self, Pgn, PgnError};
+
+    #[test]
+    fn generated_proprietary_matches_manufacturer() {
+        let data = [0x3b, 0x9f, 0x07, 0x10, 0x27, 0xff, 0xff, 0xff];
+        let heading = SeatalkPilotHeading::decode(&data).unwrap();
+        let expected = pgns::SeatalkPilotHeading::decode(&data).unwrap();
+        assert_eq!(heading.sid, Some(expected.sid));
+        assert_eq!(heading.heading_true, expected.heading_true);
+        assert_eq!(heading.heading_magnetic, expected.heading_magnetic);
+        let mut buf = [0; 8];
+        assert_eq!(heading.encode(&mut buf).unwrap(), 8);
+        assert_eq!(buf, data);
+
+        // Navico
+        let data = [0x89, 0x98, 0x07, 0x10, 0x27, 0xff, 0xff, 0xff];
+        assert_eq!(
+            SeatalkPilotHeading::decode(&data),
+            Err(PgnError::UnexpectedMessage)
+        );
+    }
+}
@@ -0,0 +1,108 @@
+/// Declares an enumeration of the values a lookup field can take.
+///
+/// Values not covered by the listed variants, including the "not available"
+/// encoding, decode to `Unknown` so that no information is lost. A variant
+/// may give its display name after `=>`; otherwise its identifier is used.
+macro_rules! lookup {
+    (@name $variant:ident) => {
+        stringify!($variant)
+    };
+    (@name $variant:ident $display:literal) => {
+        $display
+    };
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident: $repr:ty {
+            $($(#[$vmeta:meta])* $variant:ident = $value:literal $(=> $display:literal)?,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, PartialEq)]
+        pub enum $name {
+            $($(#[$vmeta])* $variant,)*
+            Unknown($repr),
+        }
+
+        impl $name {
+            /// Values and display names of the listed variants.
+            pub const VALUES: &'static [(u32, &'static str)] =
+                &[$(($value, lookup!(@name $variant $($display)?)),)*];
+        }
+
+        impl From<$repr> for $name {
+            fn from(value: $repr) -> Self {
+                match value {
+                    $($value => $name::$variant,)*
+                    other => $name::Unknown(other),
+                }
+            }
+        }
+
+        impl From<$name> for $repr {
+            fn from(value: $name) -> Self {
+                match value {
+                    $($name::$variant => $value,)*
+                    $name::Unknown(other) => other,
+                }
+            }
+        }
+    };
+}
+
+/// Declares a set of bit flags carried in a status field.
+macro_rules! flags {
+    (
+        $(#[$meta:meta])*
+        pub struct $name:ident: $repr:ty {
+            $($(#[$fmeta:meta])* const $flag:ident = $value:expr;)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, Default, PartialEq)]
+        pub struct $name($repr);
+
+        impl $name {
+            $($(#[$fmeta])* pub const $flag: $name = $name($value);)*
+
+            /// Bit values and names of the listed flags.
+            pub const FLAGS: &'static [(u32, &'static str)] =
+                &[$(($name::$flag.0 as u32, stringify!($flag)),)*];
+
+            pub fn empty() -> Self {
+                $name(0)
+            }
+
+            pub fn from_bits(bits: $repr) -> Self {
+                $name(bits)
+            }
+
+            pub fn bits(&self) -> $repr {
+                self.0
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.0 == 0
+            }
+
+            pub fn contains(&self, other: $name) -> bool {
+                self.0 & other.0 == other.0
+            }
+
+            pub fn insert(&mut self, other: $name) {
+                self.0 |= other.0;
+            }
+
+            pub fn remove(&mut self, other: $name) {
+                self.0 &= !other.0;
+            }
+        }
+
+        impl core::ops::BitOr for $name {
+            type Output = $name;
+
+            fn bitor(self, other: $name) -> $name {
+                $name(self.0 | other.0)
+            }
+        }
+    };
+}
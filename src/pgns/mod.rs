@@ -0,0 +1,197 @@
+//! Typed encoders and decoders for NMEA 2000 parameter groups.
+//!
+//! Physical quantities use SI units as transmitted on the bus (Kelvin,
+//! Pascal, radians, metres per second). Fields that the sender marked as
+//! "data not available" decode to `None`.
+
+use crate::Priority;
+
+#[macro_use]
+mod macros;
+
+mod field;
+
+mod ac;
+pub use ac::{
+    AcBasicQuantities, AcBusBasicQuantities, AcInputStatus, AcLine, AcLineStatus,
+    AcOutputLineStatus, AcOutputStatus, AcPower, AcReactivePower, AcTotalEnergy, Acceptability,
+    BusAverageBasicAcQuantities, BusPhaseABasicAcQuantities, BusPhaseBBasicAcQuantities,
+    BusPhaseCBasicAcQuantities, GeneratorAverageBasicAcQuantities, GeneratorPhaseAAcPower,
+    GeneratorPhaseAAcReactivePower, GeneratorPhaseABasicAcQuantities, GeneratorPhaseBAcPower,
+    GeneratorPhaseBAcReactivePower, GeneratorPhaseBBasicAcQuantities, GeneratorPhaseCAcPower,
+    GeneratorPhaseCAcReactivePower, GeneratorPhaseCBasicAcQuantities, GeneratorTotalAcEnergy,
+    GeneratorTotalAcPower, GeneratorTotalAcReactivePower, PowerFactorLagging,
+    UtilityAverageBasicAcQuantities, UtilityPhaseAAcPower, UtilityPhaseAAcReactivePower,
+    UtilityPhaseABasicAcQuantities, UtilityPhaseBAcPower, UtilityPhaseBAcReactivePower,
+    UtilityPhaseBBasicAcQuantities, UtilityPhaseCAcPower, UtilityPhaseCAcReactivePower,
+    UtilityPhaseCBasicAcQuantities, UtilityTotalAcEnergy, UtilityTotalAcPower,
+    UtilityTotalAcReactivePower, Waveform,
+};
+
+mod ais;
+pub use ais::{
+    AisAddressedSafetyMessage, AisAtonReport, AisBand, AisClassAPositionReport,
+    AisClassAStaticData, AisClassBExtendedPositionReport, AisClassBPositionReport,
+    AisClassBStaticDataPartA, AisClassBStaticDataPartB, AisCommunicationState, AisMode,
+    AisPosition, AisSafetyBroadcastMessage, AisSarAircraftPositionReport, AisTransceiver,
+    AisUnitType, AisUtcDateReport, AisVersion, AtonType, GnssType, NavStatus, PositionAccuracy,
+    RaimFlag, RepeatIndicator, ShipType, SpecialManeuver,
+};
+
+mod alert;
+pub use alert::{
+    Alert, AlertCategory, AlertConfiguration, AlertHeader, AlertLanguage, AlertResponse,
+    AlertResponseCommand, AlertState, AlertText, AlertThreshold, AlertThresholdParameter,
+    AlertThresholdStatus, AlertTriggerCondition, AlertType, AlertValue, AlertValueParameter,
+    Alerts, TrackedAlert,
+};
+
+mod catalogue;
+pub use catalogue::{Field, FieldKind, PgnDescriptor, Repeating, Transport, CATALOGUE};
+
+mod common;
+pub use common::{DirectionReference, GoodWarningError, LauString, OffOn, YesNo};
+
+mod charger;
+pub use charger::{
+    ChargerMode, ChargerState, ChargerStatus, ConverterState, ConverterStatus, DcVoltageCurrent,
+    InverterState, InverterStatus,
+};
+
+mod dc;
+pub use dc::{
+    BatteryChemistry, BatteryConfigurationStatus, BatteryStatus, BatteryType, BatteryVoltage,
+    DcDetailedStatus, DcType,
+};
+
+mod dsc;
+pub use dsc::{
+    DscCallInformation, DscCategory, DscExpansion, DscFormat, DscNature, RadioFrequencyModePower,
+    TelephoneMode,
+};
+
+mod dynamic;
+pub use dynamic::{DecodedField, FieldDecoder, FieldValue};
+
+mod entertainment;
+pub use entertainment::{
+    EntertainmentCurrentFileStatus, EntertainmentLibraryDataFile, EntertainmentLibraryDataGroup,
+    EntertainmentLibraryDataSearch, EntertainmentSource, EntertainmentSupportedSourceData,
+    EntertainmentType, EntertainmentZone, LibraryGroupEntry, LikeStatus, PlayStatus, RepeatStatus,
+    ShuffleStatus, SupportedSource,
+};
+
+mod engine;
+pub use engine::{
+    EngineDiscreteStatus1, EngineDiscreteStatus2, EngineDynamicParameters, EngineInstance,
+    EngineRapidUpdate, GearStatus, TransmissionDiscreteStatus, TransmissionParameters,
+    TripParametersEngine, TripParametersVessel,
+};
+
+mod environmental;
+pub use environmental::{
+    ActualPressure, CurrentStationData, EnvironmentalParameters, FloodState, Humidity,
+    HumiditySource, MeteorologicalStationData, OutsideEnvironmentalParameters, PressureSource,
+    ResidualMode, SalinityStationData, SetPressure, Temperature, TemperatureExtendedRange,
+    TemperatureSource, TideStationData, TideTendency, WindReference,
+};
+
+mod fluid;
+pub use fluid::{FluidLevel, FluidTotal, FluidType, TankLevels};
+
+mod fusion;
+pub use fusion::{
+    FusionDecoder, FusionMessage, FusionMuteState, FusionPowerState, FusionSourceType,
+    Proprietary130820, FUSION_MANUFACTURER_CODE,
+};
+
+#[cfg(feature = "codegen")]
+pub mod generated;
+
+mod garmin;
+pub use garmin::{GarminColor, GarminColorMode, GarminDecoder, GARMIN_MANUFACTURER_CODE};
+
+mod motion;
+pub use motion::{DirectionData, Heave, LeewayAngle, SmallCraftStatus, VesselSpeedComponents};
+
+mod navico;
+pub use navico::{
+    SimnetApCommand, SimnetApEvent, SimnetDecoder, SimnetDeviceModel, SimnetDeviceReport,
+    SimnetDeviceStatus, SimnetKeyOperation, SimnetKeyValue, SimnetMessage, SimnetNetworkGroup,
+    SIMRAD_MANUFACTURER_CODE,
+};
+
+mod navigation;
+pub use navigation::{
+    BearingDistanceBetweenMarks, CalculationType, CrossTrackError, MarkType, NavigationData,
+    SetAndDrift, TimeToMark,
+};
+
+mod proprietary;
+pub use proprietary::{
+    is_proprietary, is_proprietary_fast_packet, is_proprietary_single_frame, IndustryGroup,
+    ProprietaryDecoder, ProprietaryHeader, ProprietaryRegistry,
+};
+
+mod raymarine;
+pub use raymarine::{
+    PilotMode, RaymarineDecoder, RaymarineMessage, SeatalkKey, SeatalkKeystroke,
+    SeatalkPilotHeading, SeatalkPilotHeadingCommand, SeatalkPilotLockedHeading, SeatalkPilotMode,
+    SeatalkPilotModeCommand, RAYMARINE_MANUFACTURER_CODE,
+};
+
+mod route;
+pub use route::{
+    RouteBuilder, RouteDirection, RouteInformation, Waypoint, WaypointIter, WaypointList, Waypoints,
+};
+
+mod steering;
+pub use steering::{
+    BatteryLevel, HeadingTrackControl, ManOverboardNotification, MobPositionSource, MobStatus,
+    Rudder, RudderDirection, SteeringMode, TurnMode,
+};
+
+mod switching;
+pub use switching::{BinarySwitchBankStatus, SwitchBank, SwitchBankControl, SwitchState};
+
+mod thruster;
+pub use thruster::{
+    ThrusterControlEvents, ThrusterControlStatus, ThrusterDirection, ThrusterInformation,
+    ThrusterMotorEvents, ThrusterMotorStatus, ThrusterMotorType, ThrusterRetract,
+};
+
+mod windlass;
+pub use windlass::{
+    DockingStatus, RodeType, WindlassControlEvents, WindlassControlStatus, WindlassDirection,
+    WindlassMonitoringEvents, WindlassMonitoringStatus, WindlassMotion, WindlassOperatingEvents,
+    WindlassOperatingStatus, WindlassSpeedType,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PgnError {
+    BufferTooSmall,
+    DataTooShort,
+    InvalidString,
+    /// A proprietary payload of another manufacturer or message type.
+    UnexpectedMessage,
+    /// More entries in a repeating field set than the decoded list can hold.
+    TooManyEntries,
+}
+
+pub type Result<T> = core::result::Result<T, PgnError>;
+
+/// A parameter group with a fixed layout.
+pub trait Pgn<'a>: Sized {
+    /// Parameter group number.
+    const PGN: u32;
+    /// Default transmit priority.
+    const PRIORITY: Priority;
+    /// Whether the parameter group is sent using the fast packet protocol.
+    const FAST_PACKET: bool = false;
+
+    /// Decodes the parameter group from a message payload.
+    fn decode(data: &'a [u8]) -> Result<Self>;
+
+    /// Encodes the parameter group into `buf`, returning the payload length.
+    fn encode(&self, buf: &mut [u8]) -> Result<usize>;
+}
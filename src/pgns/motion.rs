@@ -0,0 +1,272 @@
+use crate::pgns::field::{Reader, Writer};
+use crate::pgns::{DirectionReference, Pgn, ResidualMode, Result};
+use crate::Priority;
+
+/// PGN 127252 - Heave
+#[derive(Clone, Debug, PartialEq)]
+pub struct Heave {
+    pub sid: u8,
+    /// Metres, positive upwards
+    pub heave: Option<f32>,
+    /// Seconds
+    pub delay: Option<f32>,
+    pub delay_source: u8,
+}
+
+impl<'a> Pgn<'a> for Heave {
+    const PGN: u32 = 127252;
+    const PRIORITY: Priority = Priority::Priority3;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(Heave {
+            sid: r.u8()?,
+            heave: r.sfix(16, 0.01)?,
+            delay: r.ufix(16, 0.01)?,
+            delay_source: r.bits(4)? as u8,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.sfix(16, 0.01, self.heave)?;
+        w.ufix(16, 0.01, self.delay)?;
+        w.bits(4, self.delay_source as u64)?;
+        w.reserved(20)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 128000 - Nautical Leeway Angle
+#[derive(Clone, Debug, PartialEq)]
+pub struct LeewayAngle {
+    pub sid: u8,
+    /// Radians, positive when drifting to starboard
+    pub leeway_angle: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for LeewayAngle {
+    const PGN: u32 = 128000;
+    const PRIORITY: Priority = Priority::Priority4;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(LeewayAngle {
+            sid: r.u8()?,
+            leeway_angle: r.sfix(16, 0.0001)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.sfix(16, 0.0001, self.leeway_angle)?;
+        w.reserved(40)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 130576 - Small Craft Status
+#[derive(Clone, Debug, PartialEq)]
+pub struct SmallCraftStatus {
+    /// Percent
+    pub port_trim_tab: Option<i8>,
+    /// Percent
+    pub starboard_trim_tab: Option<i8>,
+}
+
+impl<'a> Pgn<'a> for SmallCraftStatus {
+    const PGN: u32 = 130576;
+    const PRIORITY: Priority = Priority::Priority2;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(SmallCraftStatus {
+            port_trim_tab: r.int(8)?.map(|v| v as i8),
+            starboard_trim_tab: r.int(8)?.map(|v| v as i8),
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.int(8, self.port_trim_tab.map(|v| v as i64))?;
+        w.int(8, self.starboard_trim_tab.map(|v| v as i64))?;
+        w.reserved(48)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 130577 - Direction Data
+#[derive(Clone, Debug, PartialEq)]
+pub struct DirectionData {
+    pub mode: ResidualMode,
+    pub cog_reference: DirectionReference,
+    pub sid: u8,
+    /// Radians
+    pub cog: Option<f32>,
+    /// Metres per second
+    pub sog: Option<f32>,
+    /// Radians
+    pub heading: Option<f32>,
+    /// Metres per second
+    pub speed_through_water: Option<f32>,
+    /// Radians
+    pub set: Option<f32>,
+    /// Metres per second
+    pub drift: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for DirectionData {
+    const PGN: u32 = 130577;
+    const PRIORITY: Priority = Priority::Priority3;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let mode = r.lookup(4)?;
+        let cog_reference = r.lookup(2)?;
+        r.reserved(2)?;
+        Ok(DirectionData {
+            mode,
+            cog_reference,
+            sid: r.u8()?,
+            cog: r.ufix(16, 0.0001)?,
+            sog: r.ufix(16, 0.01)?,
+            heading: r.ufix(16, 0.0001)?,
+            speed_through_water: r.ufix(16, 0.01)?,
+            set: r.ufix(16, 0.0001)?,
+            drift: r.ufix(16, 0.01)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.lookup(4, self.mode)?;
+        w.lookup(2, self.cog_reference)?;
+        w.reserved(2)?;
+        w.u8(self.sid)?;
+        w.ufix(16, 0.0001, self.cog)?;
+        w.ufix(16, 0.01, self.sog)?;
+        w.ufix(16, 0.0001, self.heading)?;
+        w.ufix(16, 0.01, self.speed_through_water)?;
+        w.ufix(16, 0.0001, self.set)?;
+        w.ufix(16, 0.01, self.drift)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 130578 - Vessel Speed Components
+#[derive(Clone, Debug, PartialEq)]
+pub struct VesselSpeedComponents {
+    /// Metres per second, positive forwards
+    pub longitudinal_water: Option<f32>,
+    /// Metres per second, positive to starboard
+    pub transverse_water: Option<f32>,
+    /// Metres per second, positive forwards
+    pub longitudinal_ground: Option<f32>,
+    /// Metres per second, positive to starboard
+    pub transverse_ground: Option<f32>,
+    /// Metres per second, positive to starboard
+    pub stern_water: Option<f32>,
+    /// Metres per second, positive to starboard
+    pub stern_ground: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for VesselSpeedComponents {
+    const PGN: u32 = 130578;
+    const PRIORITY: Priority = Priority::Priority2;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(VesselSpeedComponents {
+            longitudinal_water: r.sfix(16, 0.001)?,
+            transverse_water: r.sfix(16, 0.001)?,
+            longitudinal_ground: r.sfix(16, 0.001)?,
+            transverse_ground: r.sfix(16, 0.001)?,
+            stern_water: r.sfix(16, 0.001)?,
+            stern_ground: r.sfix(16, 0.001)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.sfix(16, 0.001, self.longitudinal_water)?;
+        w.sfix(16, 0.001, self.transverse_water)?;
+        w.sfix(16, 0.001, self.longitudinal_ground)?;
+        w.sfix(16, 0.001, self.transverse_ground)?;
+        w.sfix(16, 0.001, self.stern_water)?;
+        w.sfix(16, 0.001, self.stern_ground)?;
+        Ok(w.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pgns::{
+        DirectionData, DirectionReference, Heave, LeewayAngle, Pgn, ResidualMode, SmallCraftStatus,
+        VesselSpeedComponents,
+    };
+
+    #[test]
+    fn motion_decode() {
+        let data = [0x01, 0x83, 0xff, 0x0a, 0x00, 0xf0, 0xff, 0xff];
+        let heave = Heave::decode(&data).unwrap();
+        assert_eq!(heave.heave, Some(-1.25));
+        assert_eq!(heave.delay, Some(0.1));
+        let mut buf = [0; 8];
+        heave.encode(&mut buf).unwrap();
+        assert_eq!(buf, data);
+
+        let data = [0x02, 0x0c, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff];
+        let leeway = LeewayAngle::decode(&data).unwrap();
+        assert_eq!(leeway.leeway_angle, Some(-0.05));
+        leeway.encode(&mut buf).unwrap();
+        assert_eq!(buf, data);
+
+        let data = [0xf6, 0x7f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff];
+        let status = SmallCraftStatus::decode(&data).unwrap();
+        assert_eq!(status.port_trim_tab, Some(-10));
+        assert_eq!(status.starboard_trim_tab, None);
+        status.encode(&mut buf).unwrap();
+        assert_eq!(buf, data);
+    }
+
+    #[test]
+    fn direction_data_decode() {
+        let data = [
+            0xc4, 0x09, 0x10, 0x27, 0xf4, 0x01, 0x20, 0x4e, 0xc2, 0x01, 0xff, 0xff, 0x32, 0x00,
+        ];
+        let direction = DirectionData::decode(&data).unwrap();
+        assert_eq!(direction.mode, ResidualMode::Manual);
+        assert_eq!(direction.cog_reference, DirectionReference::True);
+        assert_eq!(direction.sid, 9);
+        assert_eq!(direction.cog, Some(1.0));
+        assert_eq!(direction.sog, Some(5.0));
+        assert_eq!(direction.heading, Some(2.0));
+        assert_eq!(direction.speed_through_water, Some(4.5));
+        assert_eq!(direction.set, None);
+        assert_eq!(direction.drift, Some(0.5));
+
+        let mut buf = [0; 14];
+        assert_eq!(direction.encode(&mut buf).unwrap(), 14);
+        assert_eq!(buf, data);
+    }
+
+    #[test]
+    fn vessel_speed_components_roundtrip() {
+        let speeds = VesselSpeedComponents {
+            longitudinal_water: Some(3.25),
+            transverse_water: Some(-0.12),
+            longitudinal_ground: Some(3.5),
+            transverse_ground: None,
+            stern_water: Some(-0.05),
+            stern_ground: None,
+        };
+        let mut buf = [0; 12];
+        assert_eq!(speeds.encode(&mut buf).unwrap(), 12);
+        assert_eq!(&buf[..4], &[0xb2, 0x0c, 0x88, 0xff]);
+        assert_eq!(VesselSpeedComponents::decode(&buf).unwrap(), speeds);
+    }
+}
@@ -0,0 +1,355 @@
+use crate::pgns::field::{Reader, Writer};
+use crate::pgns::{is_proprietary, Pgn, PgnError, ProprietaryDecoder, ProprietaryHeader, Result};
+use crate::Priority;
+
+/// Manufacturer code used by Navico (Simrad, B&G, Lowrance) for Simnet
+/// messages.
+pub const SIMRAD_MANUFACTURER_CODE: u16 = 1857;
+
+const SIMNET_AP_COMMAND: u8 = 10;
+
+lookup! {
+    pub enum SimnetDeviceModel: u8 {
+        Ac = 0 => "AC",
+        OtherDevice = 1 => "Other device",
+        Nac = 100 => "NAC",
+    }
+}
+
+lookup! {
+    pub enum SimnetDeviceReport: u8 {
+        Status = 2,
+        SendStatus = 3 => "Send Status",
+        Mode = 10,
+        SendMode = 11 => "Send Mode",
+        SailingProcessorStatus = 23 => "Sailing Processor Status",
+    }
+}
+
+lookup! {
+    pub enum SimnetNetworkGroup: u8 {
+        None = 0,
+        Default = 1,
+        Group1 = 2 => "Group 1",
+        Group2 = 3 => "Group 2",
+        Group3 = 4 => "Group 3",
+        Group4 = 5 => "Group 4",
+        Group5 = 6 => "Group 5",
+        Group6 = 7 => "Group 6",
+    }
+}
+
+lookup! {
+    pub enum SimnetKeyOperation: u8 {
+        Read = 0,
+        Set = 1,
+        Reply = 2,
+    }
+}
+
+lookup! {
+    pub enum SimnetApEvent: u8 {
+        FollowNonFollow = 2 => "Follow/Non Follow",
+        Standby = 6,
+        AutoMode = 9 => "Heading mode",
+        NavMode = 10 => "Nav mode",
+        NoDriftMode = 12 => "No Drift mode",
+        NonFollowUpMode = 13 => "Non Follow Up mode",
+        FollowUpMode = 14 => "Follow Up mode",
+        WindMode = 15 => "Wind mode",
+        Tack = 17,
+        SquareTurn = 18 => "Square (Turn)",
+        CTurn = 19 => "C-Turn",
+        UTurn = 20 => "U-Turn",
+        SpiralTurn = 21 => "Spiral (Turn)",
+        ZigZagTurn = 22 => "Zig Zag (Turn)",
+        LazySTurn = 23 => "Lazy-S (Turn)",
+        DepthTurn = 24 => "Depth (Turn)",
+        ChangeCourse = 26 => "Change course",
+        TimerSync = 61 => "Timer sync",
+        MobActivated = 107 => "MOB Activated",
+        MobDeactivated = 108 => "MOB Deactivated",
+        PingPortEnd = 112 => "Ping port end",
+        PingStarboardEnd = 113 => "Ping starboard end",
+    }
+}
+
+/// PGN 65305 - Simnet: Device Status
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimnetDeviceStatus {
+    pub model: SimnetDeviceModel,
+    pub report: SimnetDeviceReport,
+    /// Report specific data
+    pub data: [u8; 4],
+}
+
+impl SimnetDeviceStatus {
+    /// The autopilot status of a status report.
+    pub fn pilot_status(&self) -> Option<u8> {
+        match self.report {
+            SimnetDeviceReport::Status => Some(self.data[0]),
+            _ => None,
+        }
+    }
+
+    /// The autopilot mode bits of a mode report.
+    pub fn pilot_mode(&self) -> Option<u16> {
+        match self.report {
+            SimnetDeviceReport::Mode => Some(u16::from_le_bytes([self.data[0], self.data[1]])),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> Pgn<'a> for SimnetDeviceStatus {
+    const PGN: u32 = 65305;
+    const PRIORITY: Priority = Priority::Priority7;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        ProprietaryHeader::expect(&mut r, SIMRAD_MANUFACTURER_CODE)?;
+        Ok(SimnetDeviceStatus {
+            model: r.lookup(8)?,
+            report: r.lookup(8)?,
+            data: [r.u8()?, r.u8()?, r.u8()?, r.u8()?],
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        ProprietaryHeader::marine(SIMRAD_MANUFACTURER_CODE).encode(&mut w)?;
+        w.lookup(8, self.model)?;
+        w.lookup(8, self.report)?;
+        w.bytes(&self.data)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 130845 - Simnet: Key Value
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimnetKeyValue<'a> {
+    pub address: u8,
+    pub instance: u8,
+    pub network_group: SimnetNetworkGroup,
+    pub source: u8,
+    pub key: u32,
+    pub operation: SimnetKeyOperation,
+    /// Key specific value
+    pub value: &'a [u8],
+}
+
+impl<'a> Pgn<'a> for SimnetKeyValue<'a> {
+    const PGN: u32 = 130845;
+    const PRIORITY: Priority = Priority::Priority7;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        ProprietaryHeader::expect(&mut r, SIMRAD_MANUFACTURER_CODE)?;
+        Ok(SimnetKeyValue {
+            address: r.u8()?,
+            instance: r.u8()?,
+            network_group: r.lookup(8)?,
+            source: r.u8()?,
+            key: r.bits(24)? as u32,
+            operation: r.lookup(8)?,
+            value: r.rest(),
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        ProprietaryHeader::marine(SIMRAD_MANUFACTURER_CODE).encode(&mut w)?;
+        w.u8(self.address)?;
+        w.u8(self.instance)?;
+        w.lookup(8, self.network_group)?;
+        w.u8(self.source)?;
+        w.bits(24, self.key as u64)?;
+        w.lookup(8, self.operation)?;
+        w.bytes(self.value)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 130850 - Simnet: AP command
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimnetApCommand {
+    pub address: u8,
+    pub network_group: SimnetNetworkGroup,
+    pub event: SimnetApEvent,
+}
+
+impl<'a> Pgn<'a> for SimnetApCommand {
+    const PGN: u32 = 130850;
+    const PRIORITY: Priority = Priority::Priority2;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        ProprietaryHeader::expect(&mut r, SIMRAD_MANUFACTURER_CODE)?;
+        let address = r.u8()?;
+        r.reserved(8)?;
+        let network_group = r.lookup(8)?;
+        if r.u8()? != SIMNET_AP_COMMAND {
+            return Err(PgnError::UnexpectedMessage);
+        }
+        Ok(SimnetApCommand {
+            address,
+            network_group,
+            event: r.lookup(8)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        ProprietaryHeader::marine(SIMRAD_MANUFACTURER_CODE).encode(&mut w)?;
+        w.u8(self.address)?;
+        w.reserved(8)?;
+        w.lookup(8, self.network_group)?;
+        w.u8(SIMNET_AP_COMMAND)?;
+        w.lookup(8, self.event)?;
+        w.bits(8, 0)?; // spare
+        w.reserved(32)?;
+        Ok(w.len())
+    }
+}
+
+/// A Simnet proprietary message, selected by its parameter group number.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SimnetMessage<'a> {
+    DeviceStatus(SimnetDeviceStatus),
+    KeyValue(SimnetKeyValue<'a>),
+    ApCommand(SimnetApCommand),
+    /// A Simnet message this crate does not decode, with the payload
+    /// following the proprietary header.
+    Unknown {
+        pgn: u32,
+        data: &'a [u8],
+    },
+}
+
+impl<'a> SimnetMessage<'a> {
+    /// Decodes a proprietary payload of parameter group `pgn`, failing with
+    /// `PgnError::UnexpectedMessage` if it is not a Simnet message.
+    pub fn decode(pgn: u32, data: &'a [u8]) -> Result<Self> {
+        let unknown = || -> Result<Self> {
+            let mut r = Reader::new(data);
+            ProprietaryHeader::expect(&mut r, SIMRAD_MANUFACTURER_CODE)?;
+            Ok(SimnetMessage::Unknown {
+                pgn,
+                data: r.rest(),
+            })
+        };
+        let message = match pgn {
+            SimnetDeviceStatus::PGN => {
+                SimnetMessage::DeviceStatus(SimnetDeviceStatus::decode(data)?)
+            }
+            SimnetKeyValue::PGN => SimnetMessage::KeyValue(SimnetKeyValue::decode(data)?),
+            SimnetApCommand::PGN => match SimnetApCommand::decode(data) {
+                Ok(command) => SimnetMessage::ApCommand(command),
+                Err(PgnError::UnexpectedMessage) => unknown()?,
+                Err(error) => return Err(error),
+            },
+            _ => unknown()?,
+        };
+        Ok(message)
+    }
+}
+
+/// A `ProprietaryDecoder` passing every Simnet message to a handler.
+pub struct SimnetDecoder<F> {
+    handler: F,
+}
+
+impl<F> SimnetDecoder<F>
+where
+    F: FnMut(SimnetMessage),
+{
+    pub fn new(handler: F) -> Self {
+        SimnetDecoder { handler }
+    }
+}
+
+impl<F> ProprietaryDecoder for SimnetDecoder<F>
+where
+    F: FnMut(SimnetMessage),
+{
+    fn manufacturer_code(&self) -> u16 {
+        SIMRAD_MANUFACTURER_CODE
+    }
+
+    fn handles(&self, pgn: u32) -> bool {
+        is_proprietary(pgn)
+    }
+
+    fn decode(&mut self, pgn: u32, _header: &ProprietaryHeader, data: &[u8]) -> Result<()> {
+        (self.handler)(SimnetMessage::decode(pgn, data)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pgns::{
+        Pgn, PgnError, SimnetApCommand, SimnetApEvent, SimnetDeviceModel, SimnetDeviceReport,
+        SimnetDeviceStatus, SimnetKeyOperation, SimnetKeyValue, SimnetMessage, SimnetNetworkGroup,
+    };
+
+    #[test]
+    fn simnet_decode() {
+        let data = [0x41, 0x9f, 0x64, 0x0a, 0x04, 0x01, 0xff, 0xff];
+        let status = SimnetDeviceStatus::decode(&data).unwrap();
+        assert_eq!(status.model, SimnetDeviceModel::Nac);
+        assert_eq!(status.report, SimnetDeviceReport::Mode);
+        assert_eq!(status.pilot_mode(), Some(0x0104));
+        assert_eq!(status.pilot_status(), None);
+        let mut buf = [0; 8];
+        status.encode(&mut buf).unwrap();
+        assert_eq!(buf, data);
+
+        let data = [
+            0x41, 0x9f, 0xff, 0x00, 0x01, 0x00, 0x07, 0x12, 0x00, 0x02, 0x01, 0x16,
+        ];
+        let key_value = SimnetKeyValue::decode(&data).unwrap();
+        assert_eq!(key_value.network_group, SimnetNetworkGroup::Default);
+        assert_eq!(key_value.key, 0x1207);
+        assert_eq!(key_value.operation, SimnetKeyOperation::Reply);
+        assert_eq!(key_value.value, &[0x01, 0x16]);
+        assert_eq!(
+            SimnetMessage::decode(130845, &data).unwrap(),
+            SimnetMessage::KeyValue(key_value)
+        );
+
+        // Raymarine
+        assert_eq!(
+            SimnetDeviceStatus::decode(&[0x3b, 0x9f, 0x64, 0x0a, 0x04, 0x01, 0xff, 0xff]),
+            Err(PgnError::UnexpectedMessage)
+        );
+    }
+
+    #[test]
+    fn simnet_ap_command_roundtrip() {
+        let command = SimnetApCommand {
+            address: 0x03,
+            network_group: SimnetNetworkGroup::Default,
+            event: SimnetApEvent::ChangeCourse,
+        };
+        let mut buf = [0; 16];
+        let len = command.encode(&mut buf).unwrap();
+        assert_eq!(
+            &buf[..len],
+            &[0x41, 0x9f, 0x03, 0xff, 0x01, 0x0a, 0x1a, 0x00, 0xff, 0xff, 0xff, 0xff]
+        );
+        assert_eq!(SimnetApCommand::decode(&buf[..len]).unwrap(), command);
+
+        // an alarm event
+        buf[5] = 0xff;
+        assert_eq!(
+            SimnetMessage::decode(130850, &buf[..len]).unwrap(),
+            SimnetMessage::Unknown {
+                pgn: 130850,
+                data: &buf[2..len],
+            }
+        );
+    }
+}
@@ -0,0 +1,363 @@
+use crate::pgns::field::{Reader, Writer};
+use crate::pgns::{DirectionReference, Pgn, ResidualMode, Result, YesNo};
+use crate::Priority;
+
+lookup! {
+    pub enum CalculationType: u8 {
+        GreatCircle = 0 => "Great Circle",
+        RhumbLine = 1 => "Rhumbline",
+    }
+}
+
+lookup! {
+    pub enum MarkType: u8 {
+        Collision = 0,
+        TurningPoint = 1 => "Turning point",
+        Reference = 2,
+        Wheelover = 3,
+        Waypoint = 4,
+    }
+}
+
+/// PGN 129283 - Cross Track Error
+#[derive(Clone, Debug, PartialEq)]
+pub struct CrossTrackError {
+    pub sid: u8,
+    pub mode: ResidualMode,
+    pub navigation_terminated: YesNo,
+    /// Metres
+    pub xte: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for CrossTrackError {
+    const PGN: u32 = 129283;
+    const PRIORITY: Priority = Priority::Priority3;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let sid = r.u8()?;
+        let mode = r.lookup(4)?;
+        r.reserved(2)?;
+        Ok(CrossTrackError {
+            sid,
+            mode,
+            navigation_terminated: r.lookup(2)?,
+            xte: r.sfix(32, 0.01)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.lookup(4, self.mode)?;
+        w.reserved(2)?;
+        w.lookup(2, self.navigation_terminated)?;
+        w.sfix(32, 0.01, self.xte)?;
+        w.reserved(16)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 129284 - Navigation Data
+#[derive(Clone, Debug, PartialEq)]
+pub struct NavigationData {
+    pub sid: u8,
+    /// Metres
+    pub distance_to_waypoint: Option<f64>,
+    pub bearing_reference: DirectionReference,
+    pub perpendicular_crossed: YesNo,
+    pub arrival_circle_entered: YesNo,
+    pub calculation_type: CalculationType,
+    /// Seconds since midnight
+    pub eta_time: Option<f64>,
+    /// Days since 1970-01-01
+    pub eta_date: Option<u16>,
+    /// Radians
+    pub bearing_origin_to_destination: Option<f32>,
+    /// Radians
+    pub bearing_position_to_destination: Option<f32>,
+    pub origin_waypoint_number: Option<u32>,
+    pub destination_waypoint_number: Option<u32>,
+    /// Degrees
+    pub destination_latitude: Option<f64>,
+    /// Degrees
+    pub destination_longitude: Option<f64>,
+    /// Metres per second
+    pub waypoint_closing_velocity: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for NavigationData {
+    const PGN: u32 = 129284;
+    const PRIORITY: Priority = Priority::Priority3;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(NavigationData {
+            sid: r.u8()?,
+            distance_to_waypoint: r.ufix64(32, 0.01)?,
+            bearing_reference: r.lookup(2)?,
+            perpendicular_crossed: r.lookup(2)?,
+            arrival_circle_entered: r.lookup(2)?,
+            calculation_type: r.lookup(2)?,
+            eta_time: r.ufix64(32, 0.0001)?,
+            eta_date: r.uint(16)?.map(|v| v as u16),
+            bearing_origin_to_destination: r.ufix(16, 0.0001)?,
+            bearing_position_to_destination: r.ufix(16, 0.0001)?,
+            origin_waypoint_number: r.uint(32)?.map(|v| v as u32),
+            destination_waypoint_number: r.uint(32)?.map(|v| v as u32),
+            destination_latitude: r.sfix64(32, 1e-7)?,
+            destination_longitude: r.sfix64(32, 1e-7)?,
+            waypoint_closing_velocity: r.sfix(16, 0.01)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.ufix64(32, 0.01, self.distance_to_waypoint)?;
+        w.lookup(2, self.bearing_reference)?;
+        w.lookup(2, self.perpendicular_crossed)?;
+        w.lookup(2, self.arrival_circle_entered)?;
+        w.lookup(2, self.calculation_type)?;
+        w.ufix64(32, 0.0001, self.eta_time)?;
+        w.uint(16, self.eta_date.map(|v| v as u64))?;
+        w.ufix(16, 0.0001, self.bearing_origin_to_destination)?;
+        w.ufix(16, 0.0001, self.bearing_position_to_destination)?;
+        w.uint(32, self.origin_waypoint_number.map(|v| v as u64))?;
+        w.uint(32, self.destination_waypoint_number.map(|v| v as u64))?;
+        w.sfix64(32, 1e-7, self.destination_latitude)?;
+        w.sfix64(32, 1e-7, self.destination_longitude)?;
+        w.sfix(16, 0.01, self.waypoint_closing_velocity)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 129291 - Set & Drift, Rapid Update
+#[derive(Clone, Debug, PartialEq)]
+pub struct SetAndDrift {
+    pub sid: u8,
+    pub set_reference: DirectionReference,
+    /// Radians
+    pub set: Option<f32>,
+    /// Metres per second
+    pub drift: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for SetAndDrift {
+    const PGN: u32 = 129291;
+    const PRIORITY: Priority = Priority::Priority3;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let sid = r.u8()?;
+        let set_reference = r.lookup(2)?;
+        r.reserved(6)?;
+        Ok(SetAndDrift {
+            sid,
+            set_reference,
+            set: r.ufix(16, 0.0001)?,
+            drift: r.ufix(16, 0.01)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.lookup(2, self.set_reference)?;
+        w.reserved(6)?;
+        w.ufix(16, 0.0001, self.set)?;
+        w.ufix(16, 0.01, self.drift)?;
+        w.reserved(16)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 129301 - Time to/from Mark
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeToMark {
+    pub sid: u8,
+    /// Seconds, negative once the mark has been passed
+    pub time_to_mark: Option<f64>,
+    pub mark_type: MarkType,
+    pub mark_id: Option<u32>,
+}
+
+impl<'a> Pgn<'a> for TimeToMark {
+    const PGN: u32 = 129301;
+    const PRIORITY: Priority = Priority::Priority3;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let sid = r.u8()?;
+        let time_to_mark = r.sfix64(32, 0.001)?;
+        let mark_type = r.lookup(4)?;
+        r.reserved(4)?;
+        Ok(TimeToMark {
+            sid,
+            time_to_mark,
+            mark_type,
+            mark_id: r.uint(32)?.map(|v| v as u32),
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.sfix64(32, 0.001, self.time_to_mark)?;
+        w.lookup(4, self.mark_type)?;
+        w.reserved(4)?;
+        w.uint(32, self.mark_id.map(|v| v as u64))?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 129302 - Bearing and Distance between two Marks
+#[derive(Clone, Debug, PartialEq)]
+pub struct BearingDistanceBetweenMarks {
+    pub sid: u8,
+    pub bearing_reference: DirectionReference,
+    pub calculation_type: CalculationType,
+    /// Radians
+    pub bearing_origin_to_destination: Option<f32>,
+    /// Metres
+    pub distance: Option<f64>,
+    pub origin_mark_type: MarkType,
+    pub destination_mark_type: MarkType,
+    pub origin_mark_id: Option<u32>,
+    pub destination_mark_id: Option<u32>,
+}
+
+impl<'a> Pgn<'a> for BearingDistanceBetweenMarks {
+    const PGN: u32 = 129302;
+    const PRIORITY: Priority = Priority::Priority6;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let sid = r.u8()?;
+        let bearing_reference = r.lookup(2)?;
+        let calculation_type = r.lookup(2)?;
+        r.reserved(4)?;
+        Ok(BearingDistanceBetweenMarks {
+            sid,
+            bearing_reference,
+            calculation_type,
+            bearing_origin_to_destination: r.ufix(16, 0.0001)?,
+            distance: r.ufix64(32, 0.01)?,
+            origin_mark_type: r.lookup(4)?,
+            destination_mark_type: r.lookup(4)?,
+            origin_mark_id: r.uint(32)?.map(|v| v as u32),
+            destination_mark_id: r.uint(32)?.map(|v| v as u32),
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.lookup(2, self.bearing_reference)?;
+        w.lookup(2, self.calculation_type)?;
+        w.reserved(4)?;
+        w.ufix(16, 0.0001, self.bearing_origin_to_destination)?;
+        w.ufix64(32, 0.01, self.distance)?;
+        w.lookup(4, self.origin_mark_type)?;
+        w.lookup(4, self.destination_mark_type)?;
+        w.uint(32, self.origin_mark_id.map(|v| v as u64))?;
+        w.uint(32, self.destination_mark_id.map(|v| v as u64))?;
+        Ok(w.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pgns::{
+        BearingDistanceBetweenMarks, CalculationType, CrossTrackError, DirectionReference,
+        MarkType, NavigationData, Pgn, ResidualMode, YesNo,
+    };
+
+    #[test]
+    fn cross_track_error_decode() {
+        struct TestCase {
+            data: [u8; 8],
+            navigation_terminated: YesNo,
+            xte: Option<f32>,
+        }
+        let test_cases = [
+            TestCase {
+                data: [0x00, 0x30, 0xd4, 0xfe, 0xff, 0xff, 0xff, 0xff],
+                navigation_terminated: YesNo::No,
+                xte: Some(-3.0),
+            },
+            TestCase {
+                data: [0x01, 0x70, 0xff, 0xff, 0xff, 0x7f, 0xff, 0xff],
+                navigation_terminated: YesNo::Yes,
+                xte: None,
+            },
+        ];
+        for i in &test_cases {
+            let xte = CrossTrackError::decode(&i.data).unwrap();
+            assert_eq!(xte.mode, ResidualMode::Autonomous);
+            assert_eq!(xte.navigation_terminated, i.navigation_terminated);
+            assert_eq!(xte.xte, i.xte);
+
+            let mut buf = [0; 8];
+            xte.encode(&mut buf).unwrap();
+            assert_eq!(buf, i.data);
+        }
+    }
+
+    #[test]
+    fn navigation_data_roundtrip() {
+        let data = NavigationData {
+            sid: 1,
+            distance_to_waypoint: Some(1852.0),
+            bearing_reference: DirectionReference::True,
+            perpendicular_crossed: YesNo::No,
+            arrival_circle_entered: YesNo::Yes,
+            calculation_type: CalculationType::RhumbLine,
+            eta_time: Some(36000.0),
+            eta_date: Some(19500),
+            bearing_origin_to_destination: Some(1.0),
+            bearing_position_to_destination: Some(1.25),
+            origin_waypoint_number: Some(3),
+            destination_waypoint_number: Some(4),
+            destination_latitude: Some(43.2965),
+            destination_longitude: Some(5.3698),
+            waypoint_closing_velocity: Some(-0.5),
+        };
+        let mut buf = [0; 34];
+        assert_eq!(data.encode(&mut buf).unwrap(), 34);
+        assert_eq!(buf[5], 0x50);
+
+        let decoded = NavigationData::decode(&buf).unwrap();
+        assert_eq!(decoded.arrival_circle_entered, YesNo::Yes);
+        assert_eq!(decoded.calculation_type, CalculationType::RhumbLine);
+        assert_eq!(decoded.destination_waypoint_number, Some(4));
+        assert_eq!(decoded.waypoint_closing_velocity, Some(-0.5));
+        assert!((decoded.destination_latitude.unwrap() - 43.2965).abs() < 1e-7);
+    }
+
+    #[test]
+    fn bearing_distance_between_marks_canboat_layout() {
+        // bearing reference and calculation type share the second byte
+        let data = [
+            0x03, 0xf5, 0x39, 0x30, 0xa2, 0xd3, 0x02, 0x00, 0x12, 0x11, 0x00, 0x00, 0x00, 0x12,
+            0x00, 0x00, 0x00,
+        ];
+        let marks = BearingDistanceBetweenMarks::decode(&data).unwrap();
+        assert_eq!(marks.sid, 3);
+        assert_eq!(marks.bearing_reference, DirectionReference::Magnetic);
+        assert_eq!(marks.calculation_type, CalculationType::RhumbLine);
+        assert_eq!(marks.bearing_origin_to_destination, Some(1.2345));
+        assert_eq!(marks.distance, Some(1852.5));
+        assert_eq!(marks.origin_mark_type, MarkType::Reference);
+        assert_eq!(marks.destination_mark_type, MarkType::TurningPoint);
+        assert_eq!(marks.origin_mark_id, Some(17));
+        assert_eq!(marks.destination_mark_id, Some(18));
+
+        let mut buf = [0; 17];
+        assert_eq!(marks.encode(&mut buf), Ok(17));
+        assert_eq!(buf, data);
+    }
+}
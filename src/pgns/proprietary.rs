@@ -0,0 +1,297 @@
+use heapless::Vec;
+
+use crate::pgns::field::{Reader, Writer};
+use crate::pgns::{PgnError, Result};
+
+lookup! {
+    pub enum IndustryGroup: u8 {
+        Global = 0,
+        Highway = 1,
+        Agriculture = 2,
+        Construction = 3,
+        Marine = 4 => "Marine Industry",
+        Industrial = 5,
+    }
+}
+
+/// Whether `pgn` is a proprietary parameter group sent in a single frame.
+pub fn is_proprietary_single_frame(pgn: u32) -> bool {
+    pgn == 61184 || (65280..=65535).contains(&pgn)
+}
+
+/// Whether `pgn` is a proprietary parameter group sent using the fast
+/// packet protocol.
+pub fn is_proprietary_fast_packet(pgn: u32) -> bool {
+    pgn == 126720 || (130816..=131071).contains(&pgn)
+}
+
+/// Whether `pgn` is a proprietary parameter group, starting with a
+/// `ProprietaryHeader`.
+pub fn is_proprietary(pgn: u32) -> bool {
+    is_proprietary_single_frame(pgn) || is_proprietary_fast_packet(pgn)
+}
+
+/// The manufacturer code and industry group that start every proprietary
+/// parameter group.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ProprietaryHeader {
+    pub manufacturer_code: u16,
+    pub industry_group: IndustryGroup,
+}
+
+impl ProprietaryHeader {
+    /// Length of the header in bytes.
+    pub const LENGTH: usize = 2;
+
+    pub fn new(manufacturer_code: u16, industry_group: IndustryGroup) -> Self {
+        ProprietaryHeader {
+            manufacturer_code,
+            industry_group,
+        }
+    }
+
+    /// A header of a manufacturer in the marine industry group.
+    pub fn marine(manufacturer_code: u16) -> Self {
+        Self::new(manufacturer_code, IndustryGroup::Marine)
+    }
+
+    /// Reads the header from the start of a proprietary payload.
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        Self::decode(&mut Reader::new(data))
+    }
+
+    /// Writes the header to the start of `buf`, returning its length.
+    pub fn write(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        self.encode(&mut w)?;
+        Ok(w.len())
+    }
+
+    pub(crate) fn decode(r: &mut Reader) -> Result<Self> {
+        let manufacturer_code = r.bits(11)? as u16;
+        r.reserved(2)?;
+        Ok(ProprietaryHeader {
+            manufacturer_code,
+            industry_group: r.lookup(3)?,
+        })
+    }
+
+    /// Reads a marine header, failing if it is not from `manufacturer_code`.
+    pub(crate) fn expect(r: &mut Reader, manufacturer_code: u16) -> Result<()> {
+        if Self::decode(r)? != Self::marine(manufacturer_code) {
+            return Err(PgnError::UnexpectedMessage);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn encode(&self, w: &mut Writer) -> Result<()> {
+        w.bits(11, self.manufacturer_code as u64)?;
+        w.reserved(2)?;
+        w.lookup(3, self.industry_group)
+    }
+}
+
+/// A receiver of the proprietary messages of one manufacturer.
+///
+/// The manufacturers this crate knows provide decoders that pass their typed
+/// messages to a handler, such as `RaymarineDecoder` and `FusionDecoder`.
+pub trait ProprietaryDecoder {
+    /// Manufacturer code of the messages this decoder handles.
+    fn manufacturer_code(&self) -> u16;
+
+    /// Whether this decoder handles proprietary parameter group `pgn`.
+    fn handles(&self, pgn: u32) -> bool;
+
+    /// Decodes a message payload, including its proprietary header.
+    fn decode(&mut self, pgn: u32, header: &ProprietaryHeader, data: &[u8]) -> Result<()>;
+}
+
+/// Dispatches proprietary messages to up to `N` registered decoders by
+/// parameter group number and manufacturer code.
+pub struct ProprietaryRegistry<'r, const N: usize> {
+    decoders: Vec<&'r mut dyn ProprietaryDecoder, N>,
+}
+
+impl<'r, const N: usize> ProprietaryRegistry<'r, N> {
+    pub fn new() -> Self {
+        ProprietaryRegistry {
+            decoders: Vec::new(),
+        }
+    }
+
+    /// Adds a decoder, returning false if `N` decoders are already
+    /// registered. Earlier decoders take precedence.
+    pub fn register(&mut self, decoder: &'r mut dyn ProprietaryDecoder) -> bool {
+        self.decoders.push(decoder).is_ok()
+    }
+
+    /// Passes a message to the first decoder registered for its parameter
+    /// group and manufacturer, returning false if there is none or the
+    /// parameter group is not proprietary.
+    pub fn dispatch(&mut self, pgn: u32, data: &[u8]) -> Result<bool> {
+        if !is_proprietary(pgn) {
+            return Ok(false);
+        }
+        let header = ProprietaryHeader::parse(data)?;
+        match self
+            .decoders
+            .iter_mut()
+            .find(|d| d.manufacturer_code() == header.manufacturer_code && d.handles(pgn))
+        {
+            Some(decoder) => decoder.decode(pgn, &header, data).map(|_| true),
+            None => Ok(false),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.decoders.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.decoders.is_empty()
+    }
+}
+
+impl<'r, const N: usize> Default for ProprietaryRegistry<'r, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pgns::{
+        is_proprietary, FusionDecoder, FusionMessage, FusionPowerState, GarminDecoder,
+        IndustryGroup, PgnError, PilotMode, ProprietaryDecoder, ProprietaryHeader,
+        ProprietaryRegistry, RaymarineDecoder, RaymarineMessage, Result, SimnetDecoder,
+    };
+
+    #[test]
+    fn proprietary_header() {
+        let header = ProprietaryHeader::parse(&[0x89, 0x98, 0x01]).unwrap();
+        assert_eq!(header.manufacturer_code, 137);
+        assert_eq!(header.industry_group, IndustryGroup::Marine);
+
+        let mut buf = [0; 2];
+        assert_eq!(header.write(&mut buf).unwrap(), ProprietaryHeader::LENGTH);
+        assert_eq!(buf, [0x89, 0x98]);
+        assert!(ProprietaryHeader::parse(&[0x89]).is_err());
+
+        assert!(is_proprietary(61184));
+        assert!(is_proprietary(65379));
+        assert!(is_proprietary(126720));
+        assert!(is_proprietary(130820));
+        assert!(!is_proprietary(130306));
+    }
+
+    #[test]
+    fn proprietary_registry_dispatch() {
+        struct Counter {
+            manufacturer_code: u16,
+            pgn: u32,
+            received: usize,
+        }
+
+        impl ProprietaryDecoder for Counter {
+            fn manufacturer_code(&self) -> u16 {
+                self.manufacturer_code
+            }
+
+            fn handles(&self, pgn: u32) -> bool {
+                pgn == self.pgn
+            }
+
+            fn decode(
+                &mut self,
+                _pgn: u32,
+                _header: &ProprietaryHeader,
+                _data: &[u8],
+            ) -> Result<()> {
+                self.received += 1;
+                Ok(())
+            }
+        }
+
+        let mut first = Counter {
+            manufacturer_code: 1851,
+            pgn: 126720,
+            received: 0,
+        };
+        let mut second = Counter {
+            manufacturer_code: 137,
+            pgn: 126720,
+            received: 0,
+        };
+        let mut extra = Counter {
+            manufacturer_code: 229,
+            pgn: 65305,
+            received: 0,
+        };
+        {
+            let mut registry = ProprietaryRegistry::<2>::new();
+            assert!(registry.register(&mut first));
+            assert!(registry.register(&mut second));
+            assert!(!registry.register(&mut extra));
+            assert_eq!(registry.len(), 2);
+
+            // Raymarine 1851
+            assert!(registry.dispatch(126720, &[0x3b, 0x9f, 0x01]).unwrap());
+            // Navico 137
+            assert!(registry.dispatch(126720, &[0x89, 0x98, 0x01]).unwrap());
+            assert!(!registry.dispatch(65305, &[0x89, 0x98, 0x01]).unwrap());
+            assert!(!registry.dispatch(130306, &[0x89, 0x98, 0x01]).unwrap());
+            assert!(registry.dispatch(126720, &[0x89]).is_err());
+        }
+        assert_eq!(first.received, 1);
+        assert_eq!(second.received, 1);
+        assert_eq!(extra.received, 0);
+    }
+
+    #[test]
+    fn proprietary_registry_builtin_decoders() {
+        let mut modes = 0;
+        let mut power = None;
+        let mut colors = 0;
+        let mut simnet = 0;
+        {
+            let mut raymarine = RaymarineDecoder::new(|message| {
+                if let RaymarineMessage::PilotMode(mode) = message {
+                    assert_eq!(mode.mode, PilotMode::Auto);
+                    modes += 1;
+                }
+            });
+            let mut fusion = FusionDecoder::new(|message| {
+                if let FusionMessage::PowerState(state) = message {
+                    power = Some(state);
+                }
+            });
+            let mut garmin = GarminDecoder::new(|_| colors += 1);
+            let mut navico = SimnetDecoder::new(|_| simnet += 1);
+            let mut registry = ProprietaryRegistry::<4>::new();
+            assert!(registry.register(&mut raymarine));
+            assert!(registry.register(&mut fusion));
+            assert!(registry.register(&mut garmin));
+            assert!(registry.register(&mut navico));
+
+            let mode = [0x3b, 0x9f, 0x40, 0x00, 0x00, 0x00, 0x00, 0xff];
+            assert!(registry.dispatch(65379, &mode).unwrap());
+            let on = [0xa3, 0x99, 0x20, 0x80, 0x01];
+            assert!(registry.dispatch(130820, &on).unwrap());
+            let night = [
+                0xe5, 0x98, 0xde, 0x05, 0x05, 0x05, 0xff, 0xff, 0x01, 0xff, 0x02,
+            ];
+            assert!(registry.dispatch(126720, &night).unwrap());
+            // Fusion only uses 130820
+            assert!(!registry.dispatch(126720, &on).unwrap());
+            // an unknown Garmin message
+            assert_eq!(
+                registry.dispatch(126720, &[0xe5, 0x98, 0x01, 0x02, 0x03, 0x04]),
+                Err(PgnError::UnexpectedMessage)
+            );
+        }
+        assert_eq!(modes, 1);
+        assert_eq!(power, Some(FusionPowerState::On));
+        assert_eq!(colors, 1);
+        assert_eq!(simnet, 0);
+    }
+}
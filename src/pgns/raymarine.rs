@@ -0,0 +1,525 @@
+use crate::pgns::field::{Reader, Writer};
+use crate::pgns::{is_proprietary, Pgn, PgnError, ProprietaryDecoder, ProprietaryHeader, Result};
+use crate::Priority;
+
+/// Manufacturer code of Raymarine.
+pub const RAYMARINE_MANUFACTURER_CODE: u16 = 1851;
+
+// SeaTalk1 commands tunnelled through PGN 126720 start with this id.
+const SEATALK1_PROPRIETARY_ID: u16 = 0x81f0;
+const SEATALK1_KEYSTROKE: u8 = 0x86;
+
+// Evolution pilots ignore keystrokes without this trailer.
+const KEYSTROKE_TRAILER: [u8; 14] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xc1, 0xc2, 0xcd, 0x66, 0x80, 0xd3, 0x42, 0xb1, 0xc8,
+];
+
+lookup! {
+    pub enum PilotMode: u16 {
+        Standby = 0,
+        /// Steering to a compass heading
+        Auto = 64 => "Auto, compass commanded",
+        Wind = 256 => "Vane, Wind Mode",
+        Track = 384 => "Track Mode",
+        /// Track mode referenced to course over ground
+        NoDrift = 385 => "No Drift, COG referenced (In track, course changes)",
+    }
+}
+
+lookup! {
+    pub enum SeatalkKey: u8 {
+        Auto = 1,
+        Standby = 2,
+        Wind = 3,
+        Minus1 = 5 => "-1",
+        Minus10 = 6 => "-10",
+        Plus1 = 7 => "+1",
+        Plus10 = 8 => "+10",
+        /// Tack to port
+        Minus1Minus10 = 33 => "-1 and -10",
+        /// Tack to starboard
+        Plus1Plus10 = 34 => "+1 and +10",
+        Track = 35,
+    }
+}
+
+fn decode_header(r: &mut Reader) -> Result<()> {
+    ProprietaryHeader::expect(r, RAYMARINE_MANUFACTURER_CODE)
+}
+
+fn encode_header(w: &mut Writer) -> Result<()> {
+    ProprietaryHeader::marine(RAYMARINE_MANUFACTURER_CODE).encode(w)
+}
+
+// Evolution pilots take mode and heading changes as a PGN 126208 command
+// group function addressed to them, matching the Raymarine manufacturer
+// (field 1) and marine industry (field 3) of the commanded group.
+const COMMAND_GROUP_FUNCTION: u8 = 1;
+const COMMAND_KEEP_PRIORITY: u64 = 8;
+const MARINE_INDUSTRY_CODE: u8 = 4;
+
+fn decode_command(r: &mut Reader, pgn: u32, parameters: u8) -> Result<()> {
+    let function = r.u8()?;
+    let commanded = r.bits(24)? as u32;
+    r.bits(4)?;
+    r.reserved(4)?;
+    let count = r.u8()?;
+    let manufacturer = (r.u8()?, r.bits(16)? as u16);
+    let industry = (r.u8()?, r.u8()?);
+    if function != COMMAND_GROUP_FUNCTION
+        || commanded != pgn
+        || count != parameters + 2
+        || manufacturer != (1, RAYMARINE_MANUFACTURER_CODE)
+        || industry != (3, MARINE_INDUSTRY_CODE)
+    {
+        return Err(PgnError::UnexpectedMessage);
+    }
+    Ok(())
+}
+
+fn encode_command(w: &mut Writer, pgn: u32, parameters: u8) -> Result<()> {
+    w.u8(COMMAND_GROUP_FUNCTION)?;
+    w.bits(24, pgn as u64)?;
+    w.bits(4, COMMAND_KEEP_PRIORITY)?;
+    w.reserved(4)?;
+    w.u8(parameters + 2)?;
+    w.u8(1)?;
+    w.bits(16, RAYMARINE_MANUFACTURER_CODE as u64)?;
+    w.u8(3)?;
+    w.u8(MARINE_INDUSTRY_CODE)
+}
+
+/// PGN 65379 - Seatalk: Pilot Mode
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeatalkPilotMode {
+    pub mode: PilotMode,
+    pub sub_mode: u16,
+    pub data: u8,
+}
+
+impl SeatalkPilotMode {
+    /// A report of `mode` without sub mode or mode data.
+    pub fn new(mode: PilotMode) -> Self {
+        SeatalkPilotMode {
+            mode,
+            sub_mode: 0,
+            data: 0,
+        }
+    }
+}
+
+impl<'a> Pgn<'a> for SeatalkPilotMode {
+    const PGN: u32 = 65379;
+    const PRIORITY: Priority = Priority::Priority7;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        decode_header(&mut r)?;
+        Ok(SeatalkPilotMode {
+            mode: PilotMode::from(r.bits(16)? as u16),
+            sub_mode: r.bits(16)? as u16,
+            data: r.u8()?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        encode_header(&mut w)?;
+        w.bits(16, u16::from(self.mode) as u64)?;
+        w.bits(16, self.sub_mode as u64)?;
+        w.u8(self.data)?;
+        w.reserved(8)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 65359 - Seatalk: Pilot Heading
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeatalkPilotHeading {
+    pub sid: u8,
+    /// Radians
+    pub heading_true: Option<f32>,
+    /// Radians
+    pub heading_magnetic: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for SeatalkPilotHeading {
+    const PGN: u32 = 65359;
+    const PRIORITY: Priority = Priority::Priority7;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        decode_header(&mut r)?;
+        Ok(SeatalkPilotHeading {
+            sid: r.u8()?,
+            heading_true: r.ufix(16, 0.0001)?,
+            heading_magnetic: r.ufix(16, 0.0001)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        encode_header(&mut w)?;
+        w.u8(self.sid)?;
+        w.ufix(16, 0.0001, self.heading_true)?;
+        w.ufix(16, 0.0001, self.heading_magnetic)?;
+        w.reserved(8)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 65360 - Seatalk: Pilot Locked Heading
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeatalkPilotLockedHeading {
+    pub sid: u8,
+    /// Radians
+    pub target_heading_true: Option<f32>,
+    /// Radians
+    pub target_heading_magnetic: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for SeatalkPilotLockedHeading {
+    const PGN: u32 = 65360;
+    const PRIORITY: Priority = Priority::Priority7;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        decode_header(&mut r)?;
+        Ok(SeatalkPilotLockedHeading {
+            sid: r.u8()?,
+            target_heading_true: r.ufix(16, 0.0001)?,
+            target_heading_magnetic: r.ufix(16, 0.0001)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        encode_header(&mut w)?;
+        w.u8(self.sid)?;
+        w.ufix(16, 0.0001, self.target_heading_true)?;
+        w.ufix(16, 0.0001, self.target_heading_magnetic)?;
+        w.reserved(8)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 126208 - NMEA Command group function changing the mode of an
+/// Evolution pilot, sent to the pilot's address.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeatalkPilotModeCommand {
+    pub mode: PilotMode,
+}
+
+impl SeatalkPilotModeCommand {
+    pub fn new(mode: PilotMode) -> Self {
+        SeatalkPilotModeCommand { mode }
+    }
+}
+
+impl<'a> Pgn<'a> for SeatalkPilotModeCommand {
+    const PGN: u32 = 126208;
+    const PRIORITY: Priority = Priority::Priority3;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        decode_command(&mut r, SeatalkPilotMode::PGN, 2)?;
+        if r.u8()? != 4 {
+            return Err(PgnError::UnexpectedMessage);
+        }
+        let mode = PilotMode::from(r.bits(16)? as u16);
+        if r.u8()? != 5 {
+            return Err(PgnError::UnexpectedMessage);
+        }
+        r.bits(16)?;
+        Ok(SeatalkPilotModeCommand { mode })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        encode_command(&mut w, SeatalkPilotMode::PGN, 2)?;
+        w.u8(4)?;
+        w.bits(16, u16::from(self.mode) as u64)?;
+        // no sub mode
+        w.u8(5)?;
+        w.bits(16, 0xffff)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 126208 - NMEA Command group function changing the locked heading of
+/// an Evolution pilot in auto mode, sent to the pilot's address.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeatalkPilotHeadingCommand {
+    /// Radians
+    pub target_heading_magnetic: f32,
+}
+
+impl SeatalkPilotHeadingCommand {
+    pub fn new(target_heading_magnetic: f32) -> Self {
+        SeatalkPilotHeadingCommand {
+            target_heading_magnetic,
+        }
+    }
+}
+
+impl<'a> Pgn<'a> for SeatalkPilotHeadingCommand {
+    const PGN: u32 = 126208;
+    const PRIORITY: Priority = Priority::Priority3;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        decode_command(&mut r, SeatalkPilotLockedHeading::PGN, 1)?;
+        if r.u8()? != 6 {
+            return Err(PgnError::UnexpectedMessage);
+        }
+        match r.ufix(16, 0.0001)? {
+            Some(target_heading_magnetic) => Ok(SeatalkPilotHeadingCommand {
+                target_heading_magnetic,
+            }),
+            None => Err(PgnError::UnexpectedMessage),
+        }
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        encode_command(&mut w, SeatalkPilotLockedHeading::PGN, 1)?;
+        w.u8(6)?;
+        w.ufix(16, 0.0001, Some(self.target_heading_magnetic))?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 126720 - Seatalk1: Keystroke
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeatalkKeystroke {
+    /// SeaTalk1 device the key was pressed on, 0x21 for a pilot keypad
+    pub device: u8,
+    pub key: SeatalkKey,
+}
+
+impl SeatalkKeystroke {
+    /// A key press on a pilot keypad.
+    pub fn new(key: SeatalkKey) -> Self {
+        SeatalkKeystroke { device: 0x21, key }
+    }
+}
+
+impl<'a> Pgn<'a> for SeatalkKeystroke {
+    const PGN: u32 = 126720;
+    const PRIORITY: Priority = Priority::Priority7;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        decode_header(&mut r)?;
+        if r.bits(16)? as u16 != SEATALK1_PROPRIETARY_ID || r.u8()? != SEATALK1_KEYSTROKE {
+            return Err(PgnError::UnexpectedMessage);
+        }
+        let device = r.u8()?;
+        let key = r.u8()?;
+        // the key is repeated inverted as a check
+        if r.u8()? != !key {
+            return Err(PgnError::UnexpectedMessage);
+        }
+        Ok(SeatalkKeystroke {
+            device,
+            key: SeatalkKey::from(key),
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        encode_header(&mut w)?;
+        w.bits(16, SEATALK1_PROPRIETARY_ID as u64)?;
+        w.u8(SEATALK1_KEYSTROKE)?;
+        w.u8(self.device)?;
+        let key = u8::from(self.key);
+        w.u8(key)?;
+        w.u8(!key)?;
+        w.bytes(&KEYSTROKE_TRAILER)?;
+        Ok(w.len())
+    }
+}
+
+/// A Raymarine proprietary message, selected by its parameter group number.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RaymarineMessage<'a> {
+    PilotMode(SeatalkPilotMode),
+    PilotHeading(SeatalkPilotHeading),
+    PilotLockedHeading(SeatalkPilotLockedHeading),
+    Keystroke(SeatalkKeystroke),
+    /// A Raymarine message this crate does not decode, with the payload
+    /// following the proprietary header.
+    Unknown {
+        pgn: u32,
+        data: &'a [u8],
+    },
+}
+
+impl<'a> RaymarineMessage<'a> {
+    /// Decodes a proprietary payload of parameter group `pgn`, failing with
+    /// `PgnError::UnexpectedMessage` if it is not from Raymarine.
+    pub fn decode(pgn: u32, data: &'a [u8]) -> Result<Self> {
+        let unknown = || -> Result<Self> {
+            let mut r = Reader::new(data);
+            decode_header(&mut r)?;
+            Ok(RaymarineMessage::Unknown {
+                pgn,
+                data: r.rest(),
+            })
+        };
+        let message = match pgn {
+            SeatalkPilotMode::PGN => RaymarineMessage::PilotMode(SeatalkPilotMode::decode(data)?),
+            SeatalkPilotHeading::PGN => {
+                RaymarineMessage::PilotHeading(SeatalkPilotHeading::decode(data)?)
+            }
+            SeatalkPilotLockedHeading::PGN => {
+                RaymarineMessage::PilotLockedHeading(SeatalkPilotLockedHeading::decode(data)?)
+            }
+            SeatalkKeystroke::PGN => match SeatalkKeystroke::decode(data) {
+                Ok(keystroke) => RaymarineMessage::Keystroke(keystroke),
+                Err(PgnError::UnexpectedMessage) => unknown()?,
+                Err(error) => return Err(error),
+            },
+            _ => unknown()?,
+        };
+        Ok(message)
+    }
+}
+
+/// A `ProprietaryDecoder` passing every Raymarine message to a handler.
+pub struct RaymarineDecoder<F> {
+    handler: F,
+}
+
+impl<F> RaymarineDecoder<F>
+where
+    F: FnMut(RaymarineMessage),
+{
+    pub fn new(handler: F) -> Self {
+        RaymarineDecoder { handler }
+    }
+}
+
+impl<F> ProprietaryDecoder for RaymarineDecoder<F>
+where
+    F: FnMut(RaymarineMessage),
+{
+    fn manufacturer_code(&self) -> u16 {
+        RAYMARINE_MANUFACTURER_CODE
+    }
+
+    fn handles(&self, pgn: u32) -> bool {
+        is_proprietary(pgn)
+    }
+
+    fn decode(&mut self, pgn: u32, _header: &ProprietaryHeader, data: &[u8]) -> Result<()> {
+        (self.handler)(RaymarineMessage::decode(pgn, data)?);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pgns::{
+        Pgn, PgnError, PilotMode, RaymarineMessage, SeatalkKey, SeatalkKeystroke,
+        SeatalkPilotHeading, SeatalkPilotHeadingCommand, SeatalkPilotLockedHeading,
+        SeatalkPilotMode, SeatalkPilotModeCommand,
+    };
+
+    #[test]
+    fn seatalk_pilot_decode() {
+        let data = [0x3b, 0x9f, 0x40, 0x00, 0x00, 0x00, 0x00, 0xff];
+        let mode = SeatalkPilotMode::decode(&data).unwrap();
+        assert_eq!(mode, SeatalkPilotMode::new(PilotMode::Auto));
+        let mut buf = [0; 8];
+        assert_eq!(mode.encode(&mut buf).unwrap(), 8);
+        assert_eq!(buf, data);
+
+        // true heading comes before magnetic heading
+        let data = [0x3b, 0x9f, 0x07, 0x10, 0x27, 0xff, 0xff, 0xff];
+        let heading = SeatalkPilotHeading::decode(&data).unwrap();
+        assert_eq!(heading.sid, 7);
+        assert_eq!(heading.heading_true, Some(1.0));
+        assert_eq!(heading.heading_magnetic, None);
+        heading.encode(&mut buf).unwrap();
+        assert_eq!(buf, data);
+
+        let locked = SeatalkPilotLockedHeading::decode(&data).unwrap();
+        assert_eq!(locked.target_heading_true, Some(1.0));
+        assert_eq!(
+            RaymarineMessage::decode(65360, &data).unwrap(),
+            RaymarineMessage::PilotLockedHeading(locked)
+        );
+
+        // Navico
+        let data = [0x89, 0x98, 0x40, 0x00, 0x00, 0x00, 0x00, 0xff];
+        assert_eq!(
+            SeatalkPilotMode::decode(&data),
+            Err(PgnError::UnexpectedMessage)
+        );
+    }
+
+    #[test]
+    fn seatalk_pilot_commands_encode() {
+        let mut buf = [0; 32];
+        let command = SeatalkPilotModeCommand::new(PilotMode::Auto);
+        let len = command.encode(&mut buf).unwrap();
+        assert_eq!(
+            &buf[..len],
+            &[
+                0x01, 0x63, 0xff, 0x00, 0xf8, 0x04, 0x01, 0x3b, 0x07, 0x03, 0x04, 0x04, 0x40, 0x00,
+                0x05, 0xff, 0xff,
+            ]
+        );
+        assert_eq!(
+            SeatalkPilotModeCommand::decode(&buf[..len]).unwrap(),
+            command
+        );
+
+        let command = SeatalkPilotHeadingCommand::new(1.0);
+        let len = command.encode(&mut buf).unwrap();
+        assert_eq!(
+            &buf[..len],
+            &[0x01, 0x50, 0xff, 0x00, 0xf8, 0x03, 0x01, 0x3b, 0x07, 0x03, 0x04, 0x06, 0x10, 0x27]
+        );
+        assert_eq!(
+            SeatalkPilotHeadingCommand::decode(&buf[..len]).unwrap(),
+            command
+        );
+
+        // a command for another parameter group
+        buf[1] = 0x4f;
+        assert_eq!(
+            SeatalkPilotHeadingCommand::decode(&buf[..len]),
+            Err(PgnError::UnexpectedMessage)
+        );
+    }
+
+    #[test]
+    fn seatalk_keystroke_encode() {
+        let keystroke = SeatalkKeystroke::new(SeatalkKey::Plus10);
+        let mut buf = [0; 32];
+        let len = keystroke.encode(&mut buf).unwrap();
+        assert_eq!(
+            &buf[..len],
+            &[
+                0x3b, 0x9f, 0xf0, 0x81, 0x86, 0x21, 0x08, 0xf7, 0xff, 0xff, 0xff, 0xff, 0xff, 0xc1,
+                0xc2, 0xcd, 0x66, 0x80, 0xd3, 0x42, 0xb1, 0xc8,
+            ]
+        );
+        assert_eq!(SeatalkKeystroke::decode(&buf[..len]).unwrap(), keystroke);
+
+        // a SeaTalk1 message other than a keystroke
+        let data = [0x3b, 0x9f, 0xf0, 0x81, 0x90, 0x00, 0x01];
+        assert_eq!(
+            RaymarineMessage::decode(126720, &data).unwrap(),
+            RaymarineMessage::Unknown {
+                pgn: 126720,
+                data: &[0xf0, 0x81, 0x90, 0x00, 0x01],
+            }
+        );
+    }
+}
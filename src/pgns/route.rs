@@ -0,0 +1,398 @@
+use crate::pgns::field::{Reader, Writer};
+use crate::pgns::{LauString, OffOn, Pgn, PgnError, Result};
+use crate::{Priority, FAST_PACKET_MAX_LENGTH};
+
+lookup! {
+    pub enum RouteDirection: u8 {
+        Forward = 0,
+        Reverse = 1,
+    }
+}
+
+/// An entry of a route or waypoint list.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Waypoint<'a> {
+    pub id: Option<u16>,
+    pub name: LauString<'a>,
+    /// Degrees
+    pub latitude: Option<f64>,
+    /// Degrees
+    pub longitude: Option<f64>,
+}
+
+impl<'a> Waypoint<'a> {
+    fn decode(r: &mut Reader<'a>) -> Result<Self> {
+        Ok(Waypoint {
+            id: r.uint(16)?.map(|v| v as u16),
+            name: r.string_lau()?,
+            latitude: r.sfix64(32, 1e-7)?,
+            longitude: r.sfix64(32, 1e-7)?,
+        })
+    }
+
+    fn encode(&self, w: &mut Writer) -> Result<()> {
+        w.uint(16, self.id.map(|v| v as u64))?;
+        w.string_lau(self.name)?;
+        w.sfix64(32, 1e-7, self.latitude)?;
+        w.sfix64(32, 1e-7, self.longitude)
+    }
+
+    fn encoded_len(&self) -> usize {
+        2 + 2 + self.name.len() + 4 + 4
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+enum Items<'a> {
+    Encoded { data: &'a [u8], count: u16 },
+    Slice(&'a [Waypoint<'a>]),
+}
+
+/// The waypoints of a route or waypoint list, either still encoded in a
+/// received message or borrowed from a slice to be sent.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Waypoints<'a>(Items<'a>);
+
+impl<'a> Waypoints<'a> {
+    fn decode(r: &mut Reader<'a>, count: u16) -> Self {
+        Waypoints(Items::Encoded {
+            data: r.rest(),
+            count,
+        })
+    }
+
+    fn encode(&self, w: &mut Writer) -> Result<()> {
+        for waypoint in self.iter() {
+            waypoint?.encode(w)?;
+        }
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        match self.0 {
+            Items::Encoded { count, .. } => count as usize,
+            Items::Slice(waypoints) => waypoints.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Iterates over the waypoints, decoding them on the fly.
+    pub fn iter(&self) -> WaypointIter<'a> {
+        match self.0 {
+            Items::Encoded { data, count } => WaypointIter(Iter::Encoded {
+                reader: Reader::new(data),
+                remaining: count,
+            }),
+            Items::Slice(waypoints) => WaypointIter(Iter::Slice(waypoints.iter())),
+        }
+    }
+}
+
+impl<'a> From<&'a [Waypoint<'a>]> for Waypoints<'a> {
+    fn from(waypoints: &'a [Waypoint<'a>]) -> Self {
+        Waypoints(Items::Slice(waypoints))
+    }
+}
+
+enum Iter<'a> {
+    Encoded { reader: Reader<'a>, remaining: u16 },
+    Slice(core::slice::Iter<'a, Waypoint<'a>>),
+}
+
+pub struct WaypointIter<'a>(Iter<'a>);
+
+impl<'a> Iterator for WaypointIter<'a> {
+    type Item = Result<Waypoint<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            Iter::Encoded { reader, remaining } => {
+                if *remaining == 0 {
+                    return None;
+                }
+                *remaining -= 1;
+                let waypoint = Waypoint::decode(reader);
+                if waypoint.is_err() {
+                    *remaining = 0;
+                }
+                Some(waypoint)
+            }
+            Iter::Slice(waypoints) => waypoints.next().map(|w| Ok(*w)),
+        }
+    }
+}
+
+/// PGN 129285 - Navigation - Route/WP Information
+#[derive(Clone, Debug, PartialEq)]
+pub struct RouteInformation<'a> {
+    /// Position in the route of the first waypoint in this message
+    pub start_rps: Option<u16>,
+    pub database_id: Option<u16>,
+    pub route_id: Option<u16>,
+    pub direction: RouteDirection,
+    pub supplementary_data_available: OffOn,
+    pub route_name: LauString<'a>,
+    pub waypoints: Waypoints<'a>,
+}
+
+impl<'a> Pgn<'a> for RouteInformation<'a> {
+    const PGN: u32 = 129285;
+    const PRIORITY: Priority = Priority::Priority6;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let start_rps = r.uint(16)?.map(|v| v as u16);
+        let count = r.bits(16)? as u16;
+        let database_id = r.uint(16)?.map(|v| v as u16);
+        let route_id = r.uint(16)?.map(|v| v as u16);
+        let direction = r.lookup(3)?;
+        let supplementary_data_available = r.lookup(2)?;
+        r.reserved(3)?;
+        let route_name = r.string_lau()?;
+        r.reserved(8)?;
+        Ok(RouteInformation {
+            start_rps,
+            database_id,
+            route_id,
+            direction,
+            supplementary_data_available,
+            route_name,
+            waypoints: Waypoints::decode(&mut r, count),
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.uint(16, self.start_rps.map(|v| v as u64))?;
+        w.bits(16, self.waypoints.len() as u64)?;
+        w.uint(16, self.database_id.map(|v| v as u64))?;
+        w.uint(16, self.route_id.map(|v| v as u64))?;
+        w.lookup(3, self.direction)?;
+        w.lookup(2, self.supplementary_data_available)?;
+        w.reserved(3)?;
+        w.string_lau(self.route_name)?;
+        w.reserved(8)?;
+        self.waypoints.encode(&mut w)?;
+        Ok(w.len())
+    }
+}
+
+/// Splits a route into as few PGN 129285 messages as fit the fast packet
+/// size limit, each starting at the next route position.
+///
+/// Yields `PgnError::BufferTooSmall` and stops if the route name and a single
+/// waypoint do not fit in one message.
+pub struct RouteBuilder<'a> {
+    database_id: Option<u16>,
+    route_id: Option<u16>,
+    direction: RouteDirection,
+    route_name: LauString<'a>,
+    waypoints: &'a [Waypoint<'a>],
+    start: usize,
+    done: bool,
+}
+
+impl<'a> RouteBuilder<'a> {
+    pub fn new(route_id: u16, route_name: LauString<'a>, waypoints: &'a [Waypoint<'a>]) -> Self {
+        RouteBuilder {
+            database_id: None,
+            route_id: Some(route_id),
+            direction: RouteDirection::Forward,
+            route_name,
+            waypoints,
+            start: 0,
+            done: false,
+        }
+    }
+
+    pub fn database_id(mut self, database_id: u16) -> Self {
+        self.database_id = Some(database_id);
+        self
+    }
+
+    pub fn direction(mut self, direction: RouteDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+}
+
+impl<'a> Iterator for RouteBuilder<'a> {
+    type Item = Result<RouteInformation<'a>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut length = 12 + self.route_name.len();
+        let mut end = self.start;
+        for waypoint in &self.waypoints[self.start..] {
+            if length + waypoint.encoded_len() > FAST_PACKET_MAX_LENGTH {
+                break;
+            }
+            length += waypoint.encoded_len();
+            end += 1;
+        }
+        self.done = end == self.waypoints.len();
+        if length > FAST_PACKET_MAX_LENGTH || (end == self.start && !self.done) {
+            self.done = true;
+            return Some(Err(PgnError::BufferTooSmall));
+        }
+
+        let information = RouteInformation {
+            start_rps: Some(self.start as u16),
+            database_id: self.database_id,
+            route_id: self.route_id,
+            direction: self.direction,
+            supplementary_data_available: OffOn::Off,
+            route_name: self.route_name,
+            waypoints: Waypoints::from(&self.waypoints[self.start..end]),
+        };
+        self.start = end;
+        Some(Ok(information))
+    }
+}
+
+/// PGN 130074 - Route and WP Service - WP List - WP Name & Position
+#[derive(Clone, Debug, PartialEq)]
+pub struct WaypointList<'a> {
+    pub start_wp_id: Option<u16>,
+    /// Number of valid waypoints in the whole list
+    pub valid_wps: Option<u16>,
+    pub database_id: Option<u16>,
+    pub waypoints: Waypoints<'a>,
+}
+
+impl<'a> Pgn<'a> for WaypointList<'a> {
+    const PGN: u32 = 130074;
+    const PRIORITY: Priority = Priority::Priority7;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let start_wp_id = r.uint(16)?.map(|v| v as u16);
+        let count = r.bits(16)? as u16;
+        let valid_wps = r.uint(16)?.map(|v| v as u16);
+        let database_id = r.uint(16)?.map(|v| v as u16);
+        r.reserved(16)?;
+        Ok(WaypointList {
+            start_wp_id,
+            valid_wps,
+            database_id,
+            waypoints: Waypoints::decode(&mut r, count),
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.uint(16, self.start_wp_id.map(|v| v as u64))?;
+        w.bits(16, self.waypoints.len() as u64)?;
+        w.uint(16, self.valid_wps.map(|v| v as u64))?;
+        w.uint(16, self.database_id.map(|v| v as u64))?;
+        w.reserved(16)?;
+        self.waypoints.encode(&mut w)?;
+        Ok(w.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pgns::{
+        LauString, Pgn, PgnError, RouteBuilder, RouteInformation, Waypoint, WaypointList, Waypoints,
+    };
+    use crate::FAST_PACKET_MAX_LENGTH;
+
+    const WAYPOINTS: [Waypoint; 3] = [
+        Waypoint {
+            id: Some(1),
+            name: LauString::Utf8("START"),
+            latitude: Some(51.5),
+            longitude: Some(-1.25),
+        },
+        Waypoint {
+            id: Some(2),
+            name: LauString::Utf8("BUOY"),
+            latitude: Some(51.75),
+            longitude: Some(-1.5),
+        },
+        Waypoint {
+            id: Some(3),
+            name: LauString::Utf8(""),
+            latitude: None,
+            longitude: None,
+        },
+    ];
+
+    #[test]
+    fn waypoint_list_roundtrip() {
+        let list = WaypointList {
+            start_wp_id: Some(1),
+            valid_wps: Some(3),
+            database_id: Some(0),
+            waypoints: Waypoints::from(&WAYPOINTS[..]),
+        };
+        let mut buf = [0; 64];
+        let len = list.encode(&mut buf).unwrap();
+        assert_eq!(len, 10 + 17 + 16 + 12);
+        // two reserved bytes follow the database ID
+        assert_eq!(&buf[..12], &[1, 0, 3, 0, 3, 0, 0, 0, 0xff, 0xff, 1, 0]);
+
+        let decoded = WaypointList::decode(&buf[..len]).unwrap();
+        assert_eq!(decoded.waypoints.len(), 3);
+        for (a, b) in decoded.waypoints.iter().zip(WAYPOINTS.iter()) {
+            let a = a.unwrap();
+            assert_eq!(a.id, b.id);
+            assert_eq!(a.name, b.name);
+            assert_eq!(a.latitude, b.latitude);
+            assert_eq!(a.longitude, b.longitude);
+        }
+
+        // a truncated message fails on the first incomplete waypoint
+        let decoded = WaypointList::decode(&buf[..len - 1]).unwrap();
+        let mut waypoints = decoded.waypoints.iter();
+        assert!(waypoints.next().unwrap().is_ok());
+        assert!(waypoints.next().unwrap().is_ok());
+        assert_eq!(waypoints.next().unwrap(), Err(PgnError::DataTooShort));
+        assert!(waypoints.next().is_none());
+    }
+
+    #[test]
+    fn route_builder_splits() {
+        let mut waypoints = [WAYPOINTS[0]; 20];
+        for (i, waypoint) in waypoints.iter_mut().enumerate() {
+            waypoint.id = Some(i as u16);
+        }
+
+        let mut next = 0;
+        let mut messages = 0;
+        for information in RouteBuilder::new(7, "HOME".into(), &waypoints) {
+            let information = information.unwrap();
+            assert_eq!(information.start_rps, Some(next));
+            let mut buf = [0; FAST_PACKET_MAX_LENGTH];
+            let len = information.encode(&mut buf).unwrap();
+
+            let decoded = RouteInformation::decode(&buf[..len]).unwrap();
+            assert_eq!(decoded.route_name, "HOME");
+            assert_eq!(decoded.route_id, Some(7));
+            for waypoint in decoded.waypoints.iter() {
+                assert_eq!(waypoint.unwrap().id, Some(next));
+                next += 1;
+            }
+            messages += 1;
+        }
+        assert_eq!(next, 20);
+        assert_eq!(messages, 2);
+
+        // a waypoint that cannot fit in a message ends the route with an error
+        let name = core::str::from_utf8(&[b'W'; 200]).unwrap();
+        waypoints[1].name = name.into();
+        let mut builder = RouteBuilder::new(7, "HOME".into(), &waypoints);
+        assert_eq!(builder.next().unwrap().unwrap().waypoints.len(), 1);
+        assert_eq!(builder.next(), Some(Err(PgnError::BufferTooSmall)));
+        assert_eq!(builder.next(), None);
+    }
+}
@@ -0,0 +1,358 @@
+use crate::pgns::field::{Reader, Writer};
+use crate::pgns::{DirectionReference, Pgn, Result, YesNo};
+use crate::Priority;
+
+lookup! {
+    pub enum SteeringMode: u8 {
+        MainSteering = 0 => "Main Steering",
+        NonFollowUpDevice = 1 => "Non-Follow-Up Device",
+        FollowUpDevice = 2 => "Follow-Up Device",
+        HeadingControlStandalone = 3 => "Heading Control Standalone",
+        HeadingControl = 4 => "Heading Control",
+        TrackControl = 5 => "Track Control",
+    }
+}
+
+lookup! {
+    pub enum TurnMode: u8 {
+        RudderLimitControlled = 0 => "Rudder limit controlled",
+        TurnRateControlled = 1 => "Turn rate controlled",
+        RadiusControlled = 2 => "Radius controlled",
+    }
+}
+
+lookup! {
+    pub enum RudderDirection: u8 {
+        NoOrder = 0 => "No Order",
+        MoveToStarboard = 1 => "Move to starboard",
+        MoveToPort = 2 => "Move to port",
+    }
+}
+
+lookup! {
+    pub enum MobStatus: u8 {
+        EmitterActivated = 0 => "MOB Emitter Activated",
+        ManualButtonActivation = 1 => "Manual on-board MOB Button Activation",
+        TestMode = 2 => "Test mode",
+        NotActive = 3 => "MOB Not Active",
+    }
+}
+
+lookup! {
+    pub enum MobPositionSource: u8 {
+        EstimatedByVessel = 0 => "Position estimated by the vessel",
+        ReportedByEmitter = 1 => "Position reported by MOB emitter",
+    }
+}
+
+lookup! {
+    pub enum BatteryLevel: u8 {
+        Good = 0,
+        Low = 1,
+    }
+}
+
+/// PGN 127237 - Heading/Track Control
+#[derive(Clone, Debug, PartialEq)]
+pub struct HeadingTrackControl {
+    pub rudder_limit_exceeded: YesNo,
+    pub off_heading_limit_exceeded: YesNo,
+    pub off_track_limit_exceeded: YesNo,
+    pub overridden: YesNo,
+    pub steering_mode: SteeringMode,
+    pub turn_mode: TurnMode,
+    pub heading_reference: DirectionReference,
+    pub commanded_rudder_direction: RudderDirection,
+    /// Radians, positive to starboard
+    pub commanded_rudder_angle: Option<f32>,
+    /// Radians
+    pub heading_to_steer: Option<f32>,
+    /// Radians
+    pub track: Option<f32>,
+    /// Radians
+    pub rudder_limit: Option<f32>,
+    /// Radians
+    pub off_heading_limit: Option<f32>,
+    /// Metres
+    pub radius_of_turn_order: Option<f32>,
+    /// Radians per second
+    pub rate_of_turn_order: Option<f32>,
+    /// Metres
+    pub off_track_limit: Option<f32>,
+    /// Radians
+    pub vessel_heading: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for HeadingTrackControl {
+    const PGN: u32 = 127237;
+    const PRIORITY: Priority = Priority::Priority2;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let rudder_limit_exceeded = r.lookup(2)?;
+        let off_heading_limit_exceeded = r.lookup(2)?;
+        let off_track_limit_exceeded = r.lookup(2)?;
+        let overridden = r.lookup(2)?;
+        let steering_mode = r.lookup(3)?;
+        let turn_mode = r.lookup(3)?;
+        let heading_reference = r.lookup(2)?;
+        r.reserved(5)?;
+        Ok(HeadingTrackControl {
+            rudder_limit_exceeded,
+            off_heading_limit_exceeded,
+            off_track_limit_exceeded,
+            overridden,
+            steering_mode,
+            turn_mode,
+            heading_reference,
+            commanded_rudder_direction: r.lookup(3)?,
+            commanded_rudder_angle: r.sfix(16, 0.0001)?,
+            heading_to_steer: r.ufix(16, 0.0001)?,
+            track: r.ufix(16, 0.0001)?,
+            rudder_limit: r.ufix(16, 0.0001)?,
+            off_heading_limit: r.ufix(16, 0.0001)?,
+            radius_of_turn_order: r.sfix(16, 1.0)?,
+            rate_of_turn_order: r.sfix(16, 3.125e-5)?,
+            off_track_limit: r.sfix(16, 1.0)?,
+            vessel_heading: r.ufix(16, 0.0001)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.lookup(2, self.rudder_limit_exceeded)?;
+        w.lookup(2, self.off_heading_limit_exceeded)?;
+        w.lookup(2, self.off_track_limit_exceeded)?;
+        w.lookup(2, self.overridden)?;
+        w.lookup(3, self.steering_mode)?;
+        w.lookup(3, self.turn_mode)?;
+        w.lookup(2, self.heading_reference)?;
+        w.reserved(5)?;
+        w.lookup(3, self.commanded_rudder_direction)?;
+        w.sfix(16, 0.0001, self.commanded_rudder_angle)?;
+        w.ufix(16, 0.0001, self.heading_to_steer)?;
+        w.ufix(16, 0.0001, self.track)?;
+        w.ufix(16, 0.0001, self.rudder_limit)?;
+        w.ufix(16, 0.0001, self.off_heading_limit)?;
+        w.sfix(16, 1.0, self.radius_of_turn_order)?;
+        w.sfix(16, 3.125e-5, self.rate_of_turn_order)?;
+        w.sfix(16, 1.0, self.off_track_limit)?;
+        w.ufix(16, 0.0001, self.vessel_heading)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 127245 - Rudder
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rudder {
+    pub instance: u8,
+    pub direction_order: RudderDirection,
+    /// Radians, positive to starboard
+    pub angle_order: Option<f32>,
+    /// Radians, positive to starboard
+    pub position: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for Rudder {
+    const PGN: u32 = 127245;
+    const PRIORITY: Priority = Priority::Priority2;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let instance = r.u8()?;
+        let direction_order = r.lookup(3)?;
+        r.reserved(5)?;
+        Ok(Rudder {
+            instance,
+            direction_order,
+            angle_order: r.sfix(16, 0.0001)?,
+            position: r.sfix(16, 0.0001)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.instance)?;
+        w.lookup(3, self.direction_order)?;
+        w.reserved(5)?;
+        w.sfix(16, 0.0001, self.angle_order)?;
+        w.sfix(16, 0.0001, self.position)?;
+        w.reserved(16)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 127233 - Man Overboard Notification (MOB)
+#[derive(Clone, Debug, PartialEq)]
+pub struct ManOverboardNotification {
+    pub sid: u8,
+    pub emitter_id: u32,
+    pub status: MobStatus,
+    /// Seconds since midnight
+    pub activation_time: Option<f64>,
+    pub position_source: MobPositionSource,
+    /// Days since 1970-01-01
+    pub position_date: Option<u16>,
+    /// Seconds since midnight
+    pub position_time: Option<f64>,
+    /// Degrees
+    pub latitude: Option<f64>,
+    /// Degrees
+    pub longitude: Option<f64>,
+    pub cog_reference: DirectionReference,
+    /// Radians
+    pub cog: Option<f32>,
+    /// Metres per second
+    pub sog: Option<f32>,
+    pub mmsi: u32,
+    pub emitter_battery: BatteryLevel,
+}
+
+impl<'a> Pgn<'a> for ManOverboardNotification {
+    const PGN: u32 = 127233;
+    const PRIORITY: Priority = Priority::Priority3;
+    const FAST_PACKET: bool = true;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let sid = r.u8()?;
+        let emitter_id = r.bits(32)? as u32;
+        let status = r.lookup(3)?;
+        r.reserved(5)?;
+        let activation_time = r.ufix64(32, 0.0001)?;
+        let position_source = r.lookup(3)?;
+        r.reserved(5)?;
+        let position_date = r.uint(16)?.map(|v| v as u16);
+        let position_time = r.ufix64(32, 0.0001)?;
+        let latitude = r.sfix64(32, 1e-7)?;
+        let longitude = r.sfix64(32, 1e-7)?;
+        let cog_reference = r.lookup(2)?;
+        r.reserved(6)?;
+        Ok(ManOverboardNotification {
+            sid,
+            emitter_id,
+            status,
+            activation_time,
+            position_source,
+            position_date,
+            position_time,
+            latitude,
+            longitude,
+            cog_reference,
+            cog: r.ufix(16, 0.0001)?,
+            sog: r.ufix(16, 0.01)?,
+            mmsi: r.bits(32)? as u32,
+            emitter_battery: r.lookup(3)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.bits(32, self.emitter_id as u64)?;
+        w.lookup(3, self.status)?;
+        w.reserved(5)?;
+        w.ufix64(32, 0.0001, self.activation_time)?;
+        w.lookup(3, self.position_source)?;
+        w.reserved(5)?;
+        w.uint(16, self.position_date.map(|v| v as u64))?;
+        w.ufix64(32, 0.0001, self.position_time)?;
+        w.sfix64(32, 1e-7, self.latitude)?;
+        w.sfix64(32, 1e-7, self.longitude)?;
+        w.lookup(2, self.cog_reference)?;
+        w.reserved(6)?;
+        w.ufix(16, 0.0001, self.cog)?;
+        w.ufix(16, 0.01, self.sog)?;
+        w.bits(32, self.mmsi as u64)?;
+        w.lookup(3, self.emitter_battery)?;
+        w.reserved(5)?;
+        Ok(w.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pgns::{
+        BatteryLevel, DirectionReference, HeadingTrackControl, ManOverboardNotification,
+        MobPositionSource, MobStatus, Pgn, Rudder, RudderDirection, SteeringMode, TurnMode, YesNo,
+    };
+
+    #[test]
+    fn heading_track_control_decode() {
+        let data = [
+            0x01, // rudder limit exceeded
+            0x44, // heading control, rudder limit controlled, magnetic
+            0x3f, // move to starboard
+            0x88, 0x13, // commanded rudder angle 0.5
+            0x10, 0x27, // heading to steer 1.0
+            0xff, 0xff, // track
+            0x70, 0x17, // rudder limit 0.6
+            0xe8, 0x03, // off heading limit 0.1
+            0xff, 0x7f, // radius of turn order
+            0x40, 0xff, // rate of turn order -0.006
+            0xff, 0x7f, // off track limit
+            0x20, 0x4e, // vessel heading 2.0
+        ];
+        let control = HeadingTrackControl::decode(&data).unwrap();
+        assert_eq!(control.rudder_limit_exceeded, YesNo::Yes);
+        assert_eq!(control.off_heading_limit_exceeded, YesNo::No);
+        assert_eq!(control.steering_mode, SteeringMode::HeadingControl);
+        assert_eq!(control.turn_mode, TurnMode::RudderLimitControlled);
+        assert_eq!(control.heading_reference, DirectionReference::Magnetic);
+        assert_eq!(
+            control.commanded_rudder_direction,
+            RudderDirection::MoveToStarboard
+        );
+        assert_eq!(control.commanded_rudder_angle, Some(0.5));
+        assert_eq!(control.heading_to_steer, Some(1.0));
+        assert_eq!(control.track, None);
+        assert_eq!(control.rudder_limit, Some(0.6));
+        assert_eq!(control.radius_of_turn_order, None);
+        assert!((control.rate_of_turn_order.unwrap() + 0.006).abs() < 1e-6);
+        assert_eq!(control.vessel_heading, Some(2.0));
+
+        let mut buf = [0; 21];
+        assert_eq!(control.encode(&mut buf).unwrap(), 21);
+        assert_eq!(buf, data);
+    }
+
+    #[test]
+    fn rudder_decode() {
+        let data = [0x00, 0xfa, 0x78, 0xec, 0x30, 0xf8, 0xff, 0xff];
+        let rudder = Rudder::decode(&data).unwrap();
+        assert_eq!(rudder.instance, 0);
+        assert_eq!(rudder.direction_order, RudderDirection::MoveToPort);
+        assert_eq!(rudder.angle_order, Some(-0.5));
+        assert_eq!(rudder.position, Some(-0.2));
+
+        let mut buf = [0; 8];
+        rudder.encode(&mut buf).unwrap();
+        assert_eq!(buf, data);
+    }
+
+    #[test]
+    fn man_overboard_notification_roundtrip() {
+        let mob = ManOverboardNotification {
+            sid: 7,
+            emitter_id: 0x0102_0304,
+            status: MobStatus::EmitterActivated,
+            activation_time: Some(45296.5),
+            position_source: MobPositionSource::ReportedByEmitter,
+            position_date: Some(19500),
+            position_time: Some(45300.0),
+            latitude: Some(52.0),
+            longitude: Some(4.5),
+            cog_reference: DirectionReference::True,
+            cog: None,
+            sog: Some(0.5),
+            mmsi: 972123456,
+            emitter_battery: BatteryLevel::Good,
+        };
+        let mut buf = [0; 40];
+        assert_eq!(mob.encode(&mut buf).unwrap(), 35);
+        assert_eq!(buf[5], 0xf8);
+        assert_eq!(buf[10], 0xf9);
+        assert_eq!(ManOverboardNotification::decode(&buf[..35]).unwrap(), mob);
+    }
+}
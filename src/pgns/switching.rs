@@ -0,0 +1,171 @@
+use crate::pgns::field::{Reader, Writer};
+use crate::pgns::{Pgn, Result};
+use crate::Priority;
+
+lookup! {
+    pub enum SwitchState: u8 {
+        Off = 0,
+        On = 1,
+        Error = 2,
+        Unavailable = 3,
+    }
+}
+
+/// The two-bit states of the 28 channels of a switch bank.
+///
+/// Channels are numbered from 1 as on the bus. A new bank has every channel
+/// unavailable, which a switch bank control message uses to mean "leave as is".
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SwitchBank(u64);
+
+impl SwitchBank {
+    pub const CHANNELS: usize = 28;
+
+    pub fn new() -> Self {
+        SwitchBank((1 << (2 * Self::CHANNELS)) - 1)
+    }
+
+    /// Returns the state of `channel`, or `None` if there is no such channel.
+    pub fn get(&self, channel: usize) -> Option<SwitchState> {
+        if !(1..=Self::CHANNELS).contains(&channel) {
+            return None;
+        }
+        Some(SwitchState::from(
+            (self.0 >> (2 * (channel - 1)) & 0x3) as u8,
+        ))
+    }
+
+    /// Sets the state of `channel`, returning false if there is no such
+    /// channel.
+    pub fn set(&mut self, channel: usize, state: SwitchState) -> bool {
+        if !(1..=Self::CHANNELS).contains(&channel) {
+            return false;
+        }
+        let shift = 2 * (channel - 1);
+        let value: u8 = state.into();
+        self.0 = self.0 & !(0x3 << shift) | ((value as u64 & 0x3) << shift);
+        true
+    }
+
+    fn decode(r: &mut Reader) -> Result<Self> {
+        Ok(SwitchBank(r.bits(2 * Self::CHANNELS)?))
+    }
+
+    fn encode(&self, w: &mut Writer) -> Result<()> {
+        w.bits(2 * Self::CHANNELS, self.0)
+    }
+}
+
+impl Default for SwitchBank {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// PGN 127501 - Binary Switch Bank Status
+#[derive(Clone, Debug, PartialEq)]
+pub struct BinarySwitchBankStatus {
+    pub instance: u8,
+    pub indicators: SwitchBank,
+}
+
+impl<'a> Pgn<'a> for BinarySwitchBankStatus {
+    const PGN: u32 = 127501;
+    const PRIORITY: Priority = Priority::Priority3;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(BinarySwitchBankStatus {
+            instance: r.u8()?,
+            indicators: SwitchBank::decode(&mut r)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.instance)?;
+        self.indicators.encode(&mut w)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 127502 - Switch Bank Control
+#[derive(Clone, Debug, PartialEq)]
+pub struct SwitchBankControl {
+    pub instance: u8,
+    pub switches: SwitchBank,
+}
+
+impl SwitchBankControl {
+    /// Builds a command that changes the state of a single channel and
+    /// leaves the other channels of the bank untouched. Returns `None` if
+    /// there is no such channel.
+    pub fn single(instance: u8, channel: usize, state: SwitchState) -> Option<Self> {
+        let mut switches = SwitchBank::new();
+        if !switches.set(channel, state) {
+            return None;
+        }
+        Some(SwitchBankControl { instance, switches })
+    }
+}
+
+impl<'a> Pgn<'a> for SwitchBankControl {
+    const PGN: u32 = 127502;
+    const PRIORITY: Priority = Priority::Priority3;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(SwitchBankControl {
+            instance: r.u8()?,
+            switches: SwitchBank::decode(&mut r)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.instance)?;
+        self.switches.encode(&mut w)?;
+        Ok(w.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pgns::{BinarySwitchBankStatus, Pgn, SwitchBank, SwitchBankControl, SwitchState};
+
+    #[test]
+    fn binary_switch_bank_status_decode() {
+        let data = [0x02, 0x24, 0xff, 0xff, 0xff, 0xff, 0xff, 0x3f];
+        let status = BinarySwitchBankStatus::decode(&data).unwrap();
+        assert_eq!(status.instance, 2);
+        assert_eq!(status.indicators.get(1), Some(SwitchState::Off));
+        assert_eq!(status.indicators.get(2), Some(SwitchState::On));
+        assert_eq!(status.indicators.get(3), Some(SwitchState::Error));
+        assert_eq!(status.indicators.get(4), Some(SwitchState::Off));
+        assert_eq!(status.indicators.get(27), Some(SwitchState::Unavailable));
+        assert_eq!(status.indicators.get(28), Some(SwitchState::Off));
+        assert_eq!(status.indicators.get(0), None);
+        assert_eq!(status.indicators.get(29), None);
+
+        let mut buf = [0; 8];
+        assert_eq!(status.encode(&mut buf).unwrap(), 8);
+        assert_eq!(buf, data);
+    }
+
+    #[test]
+    fn switch_bank_control_single() {
+        let control = SwitchBankControl::single(1, 5, SwitchState::On).unwrap();
+        let mut buf = [0; 8];
+        assert_eq!(control.encode(&mut buf).unwrap(), 8);
+        assert_eq!(buf, [0x01, 0xff, 0xfd, 0xff, 0xff, 0xff, 0xff, 0xff]);
+        assert_eq!(SwitchBankControl::decode(&buf).unwrap(), control);
+
+        assert_eq!(SwitchBankControl::single(1, 29, SwitchState::On), None);
+
+        let mut bank = SwitchBank::new();
+        assert!(bank.set(28, SwitchState::Off));
+        assert!(!bank.set(0, SwitchState::Off));
+        assert_eq!(bank.get(28), Some(SwitchState::Off));
+        assert_eq!(bank.get(1), Some(SwitchState::Unavailable));
+    }
+}
@@ -0,0 +1,231 @@
+use crate::pgns::field::{Reader, Writer};
+use crate::pgns::{OffOn, Pgn, Result};
+use crate::Priority;
+
+lookup! {
+    pub enum ThrusterDirection: u8 {
+        Off = 0,
+        Ready = 1,
+        ToPort = 2 => "To Port",
+        ToStarboard = 3 => "To Starboard",
+    }
+}
+
+lookup! {
+    pub enum ThrusterRetract: u8 {
+        Off = 0,
+        Extend = 1,
+        Retract = 2,
+    }
+}
+
+lookup! {
+    pub enum ThrusterMotorType: u8 {
+        Dc12V = 0 => "12VDC",
+        Dc24V = 1 => "24VDC",
+        Dc48V = 2 => "48VDC",
+        Ac24V = 3 => "24VAC",
+        Hydraulic = 4,
+    }
+}
+
+flags! {
+    pub struct ThrusterControlEvents: u8 {
+        const ANOTHER_DEVICE_CONTROLLING = 1 << 0;
+        const BOAT_SPEED_TOO_FAST = 1 << 1;
+    }
+}
+
+flags! {
+    pub struct ThrusterMotorEvents: u8 {
+        const MOTOR_OVER_TEMPERATURE_CUTOUT = 1 << 0;
+        const MOTOR_OVER_CURRENT_CUTOUT = 1 << 1;
+        const LOW_OIL_LEVEL_WARNING = 1 << 2;
+        const OIL_OVER_TEMPERATURE_WARNING = 1 << 3;
+        const CONTROLLER_UNDER_VOLTAGE_CUTOUT = 1 << 4;
+        const MANUFACTURER_DEFINED = 1 << 5;
+    }
+}
+
+/// PGN 128006 - Thruster Control Status
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThrusterControlStatus {
+    pub sid: u8,
+    pub identifier: u8,
+    pub direction: ThrusterDirection,
+    pub power_enabled: OffOn,
+    pub retract: ThrusterRetract,
+    /// Percent
+    pub speed: Option<u8>,
+    pub events: ThrusterControlEvents,
+    /// Seconds
+    pub command_timeout: Option<f32>,
+    /// Radians
+    pub azimuth: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for ThrusterControlStatus {
+    const PGN: u32 = 128006;
+    const PRIORITY: Priority = Priority::Priority2;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(ThrusterControlStatus {
+            sid: r.u8()?,
+            identifier: r.u8()?,
+            direction: r.lookup(4)?,
+            power_enabled: r.lookup(2)?,
+            retract: r.lookup(2)?,
+            speed: r.uint(8)?.map(|v| v as u8),
+            events: ThrusterControlEvents::from_bits(r.u8()?),
+            command_timeout: r.ufix(8, 0.005)?,
+            azimuth: r.ufix(16, 0.0001)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.u8(self.identifier)?;
+        w.lookup(4, self.direction)?;
+        w.lookup(2, self.power_enabled)?;
+        w.lookup(2, self.retract)?;
+        w.uint(8, self.speed.map(|v| v as u64))?;
+        w.u8(self.events.bits())?;
+        w.ufix(8, 0.005, self.command_timeout)?;
+        w.ufix(16, 0.0001, self.azimuth)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 128007 - Thruster Information
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThrusterInformation {
+    pub identifier: u8,
+    pub motor_type: ThrusterMotorType,
+    /// Watts
+    pub power_rating: Option<u16>,
+    /// Kelvin
+    pub maximum_temperature: Option<f32>,
+    /// Revolutions per minute
+    pub maximum_rotational_speed: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for ThrusterInformation {
+    const PGN: u32 = 128007;
+    const PRIORITY: Priority = Priority::Priority6;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let identifier = r.u8()?;
+        let motor_type = r.lookup(4)?;
+        r.reserved(4)?;
+        Ok(ThrusterInformation {
+            identifier,
+            motor_type,
+            power_rating: r.uint(16)?.map(|v| v as u16),
+            maximum_temperature: r.ufix(16, 0.01)?,
+            maximum_rotational_speed: r.ufix(16, 0.25)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.identifier)?;
+        w.lookup(4, self.motor_type)?;
+        w.reserved(4)?;
+        w.uint(16, self.power_rating.map(|v| v as u64))?;
+        w.ufix(16, 0.01, self.maximum_temperature)?;
+        w.ufix(16, 0.25, self.maximum_rotational_speed)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 128008 - Thruster Motor Status
+#[derive(Clone, Debug, PartialEq)]
+pub struct ThrusterMotorStatus {
+    pub sid: u8,
+    pub identifier: u8,
+    pub events: ThrusterMotorEvents,
+    /// Amperes
+    pub current: Option<u8>,
+    /// Kelvin
+    pub temperature: Option<f32>,
+    /// Seconds
+    pub operating_time: Option<u16>,
+}
+
+impl<'a> Pgn<'a> for ThrusterMotorStatus {
+    const PGN: u32 = 128008;
+    const PRIORITY: Priority = Priority::Priority6;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(ThrusterMotorStatus {
+            sid: r.u8()?,
+            identifier: r.u8()?,
+            events: ThrusterMotorEvents::from_bits(r.u8()?),
+            current: r.uint(8)?.map(|v| v as u8),
+            temperature: r.ufix(16, 0.01)?,
+            operating_time: r.uint(16)?.map(|v| v as u16),
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.u8(self.identifier)?;
+        w.u8(self.events.bits())?;
+        w.uint(8, self.current.map(|v| v as u64))?;
+        w.ufix(16, 0.01, self.temperature)?;
+        w.uint(16, self.operating_time.map(|v| v as u64))?;
+        Ok(w.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pgns::{
+        OffOn, Pgn, ThrusterControlEvents, ThrusterControlStatus, ThrusterDirection,
+        ThrusterMotorEvents, ThrusterMotorStatus, ThrusterRetract,
+    };
+
+    #[test]
+    fn thruster_control_status_decode() {
+        let data = [0x03, 0x00, 0x53, 0x32, 0x02, 0xc8, 0xff, 0xff];
+        let status = ThrusterControlStatus::decode(&data).unwrap();
+        assert_eq!(status.sid, 3);
+        assert_eq!(status.identifier, 0);
+        assert_eq!(status.direction, ThrusterDirection::ToStarboard);
+        assert_eq!(status.power_enabled, OffOn::On);
+        assert_eq!(status.retract, ThrusterRetract::Extend);
+        assert_eq!(status.speed, Some(50));
+        assert!(status
+            .events
+            .contains(ThrusterControlEvents::BOAT_SPEED_TOO_FAST));
+        assert_eq!(status.command_timeout, Some(1.0));
+        assert_eq!(status.azimuth, None);
+
+        let mut buf = [0; 8];
+        assert_eq!(status.encode(&mut buf).unwrap(), 8);
+        assert_eq!(buf, data);
+    }
+
+    #[test]
+    fn thruster_motor_status_decode() {
+        let data = [0x01, 0x00, 0x11, 0x96, 0xa3, 0x8e, 0x10, 0x0e];
+        let status = ThrusterMotorStatus::decode(&data).unwrap();
+        assert_eq!(
+            status.events,
+            ThrusterMotorEvents::MOTOR_OVER_TEMPERATURE_CUTOUT
+                | ThrusterMotorEvents::CONTROLLER_UNDER_VOLTAGE_CUTOUT
+        );
+        assert_eq!(status.current, Some(150));
+        assert_eq!(status.temperature, Some(365.15));
+        assert_eq!(status.operating_time, Some(3600));
+
+        let mut buf = [0; 8];
+        status.encode(&mut buf).unwrap();
+        assert_eq!(buf, data);
+    }
+}
@@ -0,0 +1,281 @@
+use crate::pgns::field::{Reader, Writer};
+use crate::pgns::{OffOn, Pgn, Result};
+use crate::Priority;
+
+lookup! {
+    pub enum WindlassDirection: u8 {
+        Off = 0,
+        Down = 1,
+        Up = 2,
+    }
+}
+
+lookup! {
+    pub enum WindlassSpeedType: u8 {
+        SingleSpeed = 0 => "Single speed",
+        DualSpeed = 1 => "Dual speed",
+        ProportionalSpeed = 2 => "Proportional speed",
+    }
+}
+
+lookup! {
+    pub enum WindlassMotion: u8 {
+        Stopped = 0 => "Windlass stopped",
+        Deploying = 1 => "Deployment occurring",
+        Retrieving = 2 => "Retrieval occurring",
+    }
+}
+
+lookup! {
+    pub enum RodeType: u8 {
+        Chain = 0 => "Chain presently detected",
+        Rope = 1 => "Rope presently detected",
+    }
+}
+
+lookup! {
+    pub enum DockingStatus: u8 {
+        NotDocked = 0 => "Not docked",
+        FullyDocked = 1 => "Fully docked",
+    }
+}
+
+flags! {
+    pub struct WindlassControlEvents: u8 {
+        const ANOTHER_DEVICE_CONTROLLING = 1 << 0;
+    }
+}
+
+flags! {
+    pub struct WindlassOperatingEvents: u8 {
+        const SYSTEM_ERROR = 1 << 0;
+        const SENSOR_ERROR = 1 << 1;
+        const NO_MOTION_DETECTED = 1 << 2;
+        const RETRIEVAL_DOCKING_DISTANCE_REACHED = 1 << 3;
+        const END_OF_RODE_REACHED = 1 << 4;
+    }
+}
+
+flags! {
+    pub struct WindlassMonitoringEvents: u8 {
+        const CONTROLLER_UNDER_VOLTAGE_CUTOUT = 1 << 0;
+        const CONTROLLER_OVER_CURRENT_CUTOUT = 1 << 1;
+        const CONTROLLER_OVER_TEMPERATURE_CUTOUT = 1 << 2;
+        const MANUFACTURER_DEFINED = 1 << 3;
+    }
+}
+
+/// PGN 128776 - Anchor Windlass Control Status
+#[derive(Clone, Debug, PartialEq)]
+pub struct WindlassControlStatus {
+    pub sid: u8,
+    pub windlass_id: u8,
+    pub direction: WindlassDirection,
+    pub anchor_docking: OffOn,
+    pub speed_type: WindlassSpeedType,
+    /// Percent
+    pub speed: Option<u8>,
+    pub power_enabled: OffOn,
+    pub mechanical_lock: OffOn,
+    pub deck_and_anchor_wash: OffOn,
+    pub anchor_light: OffOn,
+    /// Seconds
+    pub command_timeout: Option<f32>,
+    pub events: WindlassControlEvents,
+}
+
+impl<'a> Pgn<'a> for WindlassControlStatus {
+    const PGN: u32 = 128776;
+    const PRIORITY: Priority = Priority::Priority2;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let sid = r.u8()?;
+        let windlass_id = r.u8()?;
+        let direction = r.lookup(2)?;
+        let anchor_docking = r.lookup(2)?;
+        let speed_type = r.lookup(2)?;
+        r.reserved(2)?;
+        Ok(WindlassControlStatus {
+            sid,
+            windlass_id,
+            direction,
+            anchor_docking,
+            speed_type,
+            speed: r.uint(8)?.map(|v| v as u8),
+            power_enabled: r.lookup(2)?,
+            mechanical_lock: r.lookup(2)?,
+            deck_and_anchor_wash: r.lookup(2)?,
+            anchor_light: r.lookup(2)?,
+            command_timeout: r.ufix(8, 0.005)?,
+            events: WindlassControlEvents::from_bits(r.bits(4)? as u8),
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.u8(self.windlass_id)?;
+        w.lookup(2, self.direction)?;
+        w.lookup(2, self.anchor_docking)?;
+        w.lookup(2, self.speed_type)?;
+        w.reserved(2)?;
+        w.uint(8, self.speed.map(|v| v as u64))?;
+        w.lookup(2, self.power_enabled)?;
+        w.lookup(2, self.mechanical_lock)?;
+        w.lookup(2, self.deck_and_anchor_wash)?;
+        w.lookup(2, self.anchor_light)?;
+        w.ufix(8, 0.005, self.command_timeout)?;
+        w.bits(4, self.events.bits() as u64)?;
+        w.reserved(12)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 128777 - Anchor Windlass Operating Status
+#[derive(Clone, Debug, PartialEq)]
+pub struct WindlassOperatingStatus {
+    pub sid: u8,
+    pub windlass_id: u8,
+    pub direction: WindlassDirection,
+    pub motion: WindlassMotion,
+    pub rode_type: RodeType,
+    /// Metres
+    pub rode_counter: Option<f32>,
+    /// Metres per second
+    pub line_speed: Option<f32>,
+    pub anchor_docking: DockingStatus,
+    pub events: WindlassOperatingEvents,
+}
+
+impl<'a> Pgn<'a> for WindlassOperatingStatus {
+    const PGN: u32 = 128777;
+    const PRIORITY: Priority = Priority::Priority2;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        let sid = r.u8()?;
+        let windlass_id = r.u8()?;
+        let direction = r.lookup(2)?;
+        let motion = r.lookup(2)?;
+        let rode_type = r.lookup(2)?;
+        r.reserved(2)?;
+        Ok(WindlassOperatingStatus {
+            sid,
+            windlass_id,
+            direction,
+            motion,
+            rode_type,
+            rode_counter: r.ufix(16, 0.1)?,
+            line_speed: r.ufix(16, 0.01)?,
+            anchor_docking: r.lookup(2)?,
+            events: WindlassOperatingEvents::from_bits(r.bits(6)? as u8),
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.u8(self.windlass_id)?;
+        w.lookup(2, self.direction)?;
+        w.lookup(2, self.motion)?;
+        w.lookup(2, self.rode_type)?;
+        w.reserved(2)?;
+        w.ufix(16, 0.1, self.rode_counter)?;
+        w.ufix(16, 0.01, self.line_speed)?;
+        w.lookup(2, self.anchor_docking)?;
+        w.bits(6, self.events.bits() as u64)?;
+        Ok(w.len())
+    }
+}
+
+/// PGN 128778 - Anchor Windlass Monitoring Status
+#[derive(Clone, Debug, PartialEq)]
+pub struct WindlassMonitoringStatus {
+    pub sid: u8,
+    pub windlass_id: u8,
+    pub events: WindlassMonitoringEvents,
+    /// Volts
+    pub controller_voltage: Option<f32>,
+    /// Amperes
+    pub motor_current: Option<u8>,
+    /// Seconds
+    pub total_motor_time: Option<f32>,
+}
+
+impl<'a> Pgn<'a> for WindlassMonitoringStatus {
+    const PGN: u32 = 128778;
+    const PRIORITY: Priority = Priority::Priority2;
+
+    fn decode(data: &'a [u8]) -> Result<Self> {
+        let mut r = Reader::new(data);
+        Ok(WindlassMonitoringStatus {
+            sid: r.u8()?,
+            windlass_id: r.u8()?,
+            events: WindlassMonitoringEvents::from_bits(r.u8()?),
+            controller_voltage: r.ufix(8, 0.2)?,
+            motor_current: r.uint(8)?.map(|v| v as u8),
+            total_motor_time: r.ufix(16, 60.0)?,
+        })
+    }
+
+    fn encode(&self, buf: &mut [u8]) -> Result<usize> {
+        let mut w = Writer::new(buf);
+        w.u8(self.sid)?;
+        w.u8(self.windlass_id)?;
+        w.u8(self.events.bits())?;
+        w.ufix(8, 0.2, self.controller_voltage)?;
+        w.uint(8, self.motor_current.map(|v| v as u64))?;
+        w.ufix(16, 60.0, self.total_motor_time)?;
+        w.reserved(8)?;
+        Ok(w.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::pgns::{
+        DockingStatus, OffOn, Pgn, RodeType, WindlassControlEvents, WindlassControlStatus,
+        WindlassDirection, WindlassMotion, WindlassOperatingEvents, WindlassOperatingStatus,
+        WindlassSpeedType,
+    };
+
+    #[test]
+    fn windlass_control_status_roundtrip() {
+        let control = WindlassControlStatus {
+            sid: 0,
+            windlass_id: 1,
+            direction: WindlassDirection::Down,
+            anchor_docking: OffOn::Off,
+            speed_type: WindlassSpeedType::SingleSpeed,
+            speed: Some(100),
+            power_enabled: OffOn::On,
+            mechanical_lock: OffOn::Off,
+            deck_and_anchor_wash: OffOn::On,
+            anchor_light: OffOn::Off,
+            command_timeout: Some(0.5),
+            events: WindlassControlEvents::empty(),
+        };
+        let mut buf = [0; 8];
+        assert_eq!(control.encode(&mut buf).unwrap(), 8);
+        assert_eq!(buf, [0x00, 0x01, 0xc1, 0x64, 0x11, 0x64, 0xf0, 0xff]);
+        assert_eq!(WindlassControlStatus::decode(&buf).unwrap(), control);
+    }
+
+    #[test]
+    fn windlass_operating_status_decode() {
+        let data = [0x05, 0x01, 0xda, 0x2c, 0x01, 0x32, 0x00, 0x40];
+        let status = WindlassOperatingStatus::decode(&data).unwrap();
+        assert_eq!(status.direction, WindlassDirection::Up);
+        assert_eq!(status.motion, WindlassMotion::Retrieving);
+        assert_eq!(status.rode_type, RodeType::Rope);
+        assert_eq!(status.rode_counter, Some(30.0));
+        assert_eq!(status.line_speed, Some(0.5));
+        assert_eq!(status.anchor_docking, DockingStatus::NotDocked);
+        assert_eq!(status.events, WindlassOperatingEvents::END_OF_RODE_REACHED);
+
+        let mut buf = [0; 8];
+        assert_eq!(status.encode(&mut buf).unwrap(), 8);
+        assert_eq!(buf, data);
+    }
+}
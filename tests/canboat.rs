@@ -0,0 +1,454 @@
+//! Checks the hand-written catalogue and typed parameter groups against the
+//! vendored canboat database, `canboat/pgns.json`.
+
+use std::fs;
+
+use serde_json::Value;
+
+use n2k::pgns::*;
+use n2k::Priority;
+
+/// Catalogue entries laid out differently from every canboat entry of their
+/// PGN, and why.
+const CATALOGUE_EXCEPTIONS: &[(u32, &str)] = &[
+    // canboat gives the reactive power of these two 16 bits, and of the
+    // other J1939 reactive power PGNs 32 bits
+    (65006, "Utility #1 Phase C AC Reactive Power"),
+    (65009, "Utility #1 Phase B AC Reactive Power"),
+    // the parameters of a group function depend on the PGN it addresses
+    (126208, "NMEA Request/Command/Acknowledge group function"),
+    // canboat leaves the delay and its source reserved
+    (127252, "Heave"),
+    // canboat lists the 28 indicators, the catalogue repeats one
+    (127501, "Binary Switch Bank Status"),
+    (127502, "Switch Bank Control"),
+    // the catalogue cannot hold the artist after the repeating set
+    (130571, "Entertainment - Library Data Group"),
+    // one entry covers the messages of every manufacturer
+    (130820, "Manufacturer Proprietary fast-packet non-addressed"),
+];
+
+fn database() -> Value {
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/canboat/pgns.json");
+    let json = fs::read_to_string(path).expect("failed to read the canboat database");
+    serde_json::from_str(&json).expect("invalid canboat database")
+}
+
+fn pgns(database: &Value) -> impl Iterator<Item = &Value> {
+    let pgns = database["PGNs"].as_array().unwrap().iter();
+    pgns.filter(|pgn| !pgn["Fallback"].as_bool().unwrap_or(false))
+}
+
+fn fields(pgn: &Value) -> &[Value] {
+    pgn["Fields"].as_array().unwrap()
+}
+
+fn number(value: &Value) -> Option<u64> {
+    value.as_u64()
+}
+
+fn priority(priority: Priority) -> u64 {
+    Priority::Priority0 as u64 + priority as u64
+}
+
+fn transport(pgn: &Value) -> Transport {
+    match pgn["Type"].as_str().unwrap() {
+        "Single" => Transport::Single,
+        "Fast" => Transport::Fast,
+        _ => Transport::IsoTp,
+    }
+}
+
+/// A field reduced to what both layers agree on: the bit offset, while it is
+/// known, the length, 0 if variable, whether it is signed and its text
+/// encoding, if any.
+#[derive(Debug, PartialEq)]
+struct Slot {
+    offset: Option<usize>,
+    bits: usize,
+    signed: bool,
+    text: Option<&'static str>,
+}
+
+fn catalogue_slots(descriptor: &PgnDescriptor) -> Vec<Slot> {
+    let mut slots = Vec::new();
+    let mut offset = Some(0);
+    for field in descriptor.fields {
+        let text = match field.kind {
+            FieldKind::StringFix => Some("STRING_FIX"),
+            FieldKind::StringLau => Some("STRING_LAU"),
+            FieldKind::StringLz => Some("STRING_LZ"),
+            _ => None,
+        };
+        if field.kind != FieldKind::Reserved {
+            slots.push(Slot {
+                offset,
+                bits: field.bits as usize,
+                signed: matches!(field.kind, FieldKind::Signed { .. } | FieldKind::Float),
+                text,
+            });
+        }
+        offset = match field.is_variable() {
+            true => None,
+            false => offset.map(|offset| offset + field.bits as usize),
+        };
+    }
+    slots
+}
+
+fn canboat_slots(pgn: &Value) -> Vec<Slot> {
+    let mut slots = Vec::new();
+    let mut variable = false;
+    for field in fields(pgn) {
+        let kind = field["FieldType"].as_str().unwrap();
+        let bits = number(&field["BitLength"]);
+        if kind != "RESERVED" && kind != "SPARE" {
+            slots.push(Slot {
+                offset: number(&field["BitOffset"])
+                    .filter(|_| !variable)
+                    .map(|offset| offset as usize),
+                bits: bits.unwrap_or(0) as usize,
+                // an offset field is unsigned on the bus (J1939 excess-K)
+                signed: field["Signed"].as_bool().unwrap_or(false) && field["Offset"].is_null(),
+                text: match kind {
+                    "STRING_FIX" => Some("STRING_FIX"),
+                    "STRING_LAU" => Some("STRING_LAU"),
+                    "STRING_LZ" => Some("STRING_LZ"),
+                    _ => None,
+                },
+            });
+        }
+        variable |= bits.is_none() || field["BitLengthVariable"].as_bool().unwrap_or(false);
+    }
+    slots
+}
+
+fn manufacturer(pgn: &Value) -> Option<u64> {
+    let mut fields = fields(pgn).iter();
+    let field = fields.find(|field| field["Id"] == "manufacturerCode")?;
+    number(&field["Match"])
+}
+
+/// Differences between a catalogue entry and a canboat entry.
+fn differences(descriptor: &PgnDescriptor, pgn: &Value) -> Vec<String> {
+    let mut differences = Vec::new();
+    if descriptor.transport != transport(pgn) {
+        differences.push(format!("transport {:?}", descriptor.transport));
+    }
+    if let Some(expected) = number(&pgn["Priority"]) {
+        if priority(descriptor.priority) != expected {
+            differences.push(format!("priority {:?}", descriptor.priority));
+        }
+    }
+    let ours = catalogue_slots(descriptor);
+    let theirs = canboat_slots(pgn);
+    if ours.len() != theirs.len() {
+        differences.push(format!("{} fields, not {}", ours.len(), theirs.len()));
+    }
+    for (index, (ours, theirs)) in ours.iter().zip(&theirs).enumerate() {
+        if ours != theirs {
+            differences.push(format!("field {}: {:?}, not {:?}", index, ours, theirs));
+            break;
+        }
+    }
+    differences
+}
+
+#[test]
+fn catalogue_follows_canboat() {
+    let database = database();
+    let mut failures = Vec::new();
+    for descriptor in CATALOGUE {
+        let candidates: Vec<&Value> = pgns(&database)
+            .filter(|pgn| number(&pgn["PGN"]) == Some(descriptor.pgn as u64))
+            .filter(|pgn| match descriptor.manufacturer_code {
+                Some(code) => manufacturer(pgn) == Some(code as u64),
+                None => true,
+            })
+            .collect();
+        let excepted = CATALOGUE_EXCEPTIONS
+            .iter()
+            .any(|&(pgn, name)| pgn == descriptor.pgn && name == descriptor.name);
+        let matched = candidates
+            .iter()
+            .any(|pgn| differences(descriptor, pgn).is_empty());
+        if matched == excepted {
+            let mut failure = format!("{} {}:", descriptor.pgn, descriptor.name);
+            for pgn in &candidates {
+                let id = pgn["Id"].as_str().unwrap();
+                failure += &format!(" {} {:?};", id, differences(descriptor, pgn));
+            }
+            failures.push(failure);
+        }
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+/// Bits written least significant first, as on the bus.
+#[derive(Default)]
+struct Bits {
+    data: Vec<u8>,
+    len: usize,
+}
+
+impl Bits {
+    fn push(&mut self, bits: usize, value: u64) {
+        for bit in 0..bits {
+            if self.len % 8 == 0 {
+                self.data.push(0);
+            }
+            if bit < 64 && value >> bit & 1 == 1 {
+                *self.data.last_mut().unwrap() |= 1 << (self.len % 8);
+            }
+            self.len += 1;
+        }
+    }
+
+    fn bytes(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.push(8, *byte as u64);
+        }
+    }
+}
+
+/// Writes a value different from the others into field `index`, avoiding
+/// the values reserved for "not available" and errors.
+fn write_field(w: &mut Bits, database: &Value, field: &Value, index: usize) {
+    let bits = number(&field["BitLength"]).map(|bits| bits as usize);
+    let seed = index as u64 * 37 + 5;
+    let kind = field["FieldType"].as_str().unwrap();
+    if let Some(value) = number(&field["Match"]) {
+        return w.push(bits.unwrap(), value);
+    }
+    match (kind, bits) {
+        ("RESERVED", Some(bits)) => w.push(bits, u64::MAX),
+        ("SPARE", Some(bits)) => w.push(bits, 0),
+        ("STRING_FIX", Some(bits)) => {
+            let text: Vec<u8> = (0..bits / 8).map(|i| b'A' + i as u8 % 26).collect();
+            w.bytes(&text);
+        }
+        ("STRING_LAU", None) => w.bytes(&[4, 1, b'A', b'B']),
+        ("STRING_LZ", None) => w.bytes(&[2, b'A', b'B', 0]),
+        ("FLOAT", Some(bits)) => w.push(bits, 1.5f32.to_bits() as u64),
+        ("DECIMAL", Some(bits)) => {
+            for i in 0..bits / 8 {
+                w.push(8, (seed + i as u64) % 100);
+            }
+        }
+        ("BINARY", None) | ("DYNAMIC_FIELD_VALUE", None) => w.bytes(&[1, 2, 3]),
+        ("BINARY", Some(bits)) if bits > 64 => {
+            for i in 0..bits / 8 {
+                w.push(8, i as u64);
+            }
+        }
+        ("LOOKUP", Some(bits)) => {
+            let name = field["LookupEnumeration"].as_str().unwrap();
+            let lookups = database["LookupEnumerations"].as_array().unwrap();
+            let lookup = lookups
+                .iter()
+                .find(|lookup| lookup["Name"] == name)
+                .unwrap();
+            let values = lookup["EnumValues"].as_array().unwrap();
+            let values: Vec<u64> = values
+                .iter()
+                .filter_map(|value| number(&value["Value"]))
+                .filter(|&value| bits > 2 && value < (1 << bits) - 2 || value < 2)
+                .collect();
+            w.push(bits, values[seed as usize % values.len()]);
+        }
+        (_, Some(bits)) if bits <= 2 => w.push(bits, seed % 2),
+        (_, Some(bits)) => w.push(bits, seed % ((1 << bits.min(12)) - 3)),
+        _ => panic!("cannot write a {} field", kind),
+    }
+}
+
+/// Writes a payload following a canboat entry, with two repetitions of a
+/// repeating set.
+fn synthesize(database: &Value, pgn: &Value) -> Vec<u8> {
+    const REPETITIONS: u64 = 2;
+    let size = number(&pgn["RepeatingFieldSet1Size"]).unwrap_or(0) as usize;
+    let start = number(&pgn["RepeatingFieldSet1StartField"]).unwrap_or(0) as usize;
+    let count = number(&pgn["RepeatingFieldSet1CountField"]).map(|order| order as usize);
+    let fields = fields(pgn);
+    let mut w = Bits::default();
+    let fixed = if size > 0 { start - 1 } else { fields.len() };
+    for (index, field) in fields[..fixed].iter().enumerate() {
+        if Some(index + 1) == count {
+            let bits = number(&field["BitLength"]).unwrap() as usize;
+            w.push(bits, REPETITIONS);
+        } else {
+            write_field(&mut w, database, field, index);
+        }
+    }
+    if size > 0 {
+        for _ in 0..REPETITIONS {
+            for (index, field) in fields[fixed..fixed + size].iter().enumerate() {
+                write_field(&mut w, database, field, fixed + index);
+            }
+        }
+        for (index, field) in fields.iter().enumerate().skip(fixed + size) {
+            write_field(&mut w, database, field, index);
+        }
+    }
+    w.data
+}
+
+fn roundtrip<'a, P: Pgn<'a>>(data: &'a [u8]) -> Result<Vec<u8>> {
+    let mut buf = [0; 1785];
+    let len = P::decode(data)?.encode(&mut buf)?;
+    Ok(buf[..len].to_vec())
+}
+
+#[test]
+fn typed_pgns_follow_canboat() {
+    let database = database();
+    let mut failures = Vec::new();
+    macro_rules! check {
+        ($($pgn:ty => $($id:literal),+;)*) => {$($(
+            let pgn = pgns(&database)
+                .find(|pgn| pgn["Id"] == $id)
+                .unwrap_or_else(|| panic!("no {} in the canboat database", $id));
+            let data = synthesize(&database, pgn);
+            let mut failure = Vec::new();
+            if number(&pgn["PGN"]) != Some(<$pgn>::PGN as u64) {
+                failure.push(format!("PGN {}", <$pgn>::PGN));
+            }
+            if <$pgn>::FAST_PACKET != (transport(pgn) == Transport::Fast) {
+                failure.push(format!("fast packet {}", <$pgn>::FAST_PACKET));
+            }
+            if let Some(expected) = number(&pgn["Priority"]) {
+                if priority(<$pgn>::PRIORITY) != expected {
+                    failure.push(format!("priority {:?}", <$pgn>::PRIORITY));
+                }
+            }
+            match roundtrip::<$pgn>(&data) {
+                Ok(encoded) if encoded == data => {}
+                Ok(encoded) => failure.push(format!("{:02x?}\n    not {:02x?}", encoded, data)),
+                Err(e) => failure.push(format!("{:?} decoding {:02x?}", e, data)),
+            }
+            if !failure.is_empty() {
+                failures.push(format!("{} ({}): {}", stringify!($pgn), $id, failure.join(", ")));
+            }
+        )+)*};
+    }
+    check! {
+        ManOverboardNotification => "manOverboardNotification";
+        HeadingTrackControl => "headingTrackControl";
+        Rudder => "rudder";
+        Heave => "heave";
+        EngineRapidUpdate => "engineParametersRapidUpdate";
+        EngineDynamicParameters => "engineParametersDynamic";
+        TransmissionParameters => "transmissionParametersDynamic";
+        TripParametersVessel => "tripParametersVessel";
+        TripParametersEngine => "tripParametersEngine";
+        BinarySwitchBankStatus => "binarySwitchBankStatus";
+        SwitchBankControl => "switchBankControl";
+        AcInputStatus => "acInputStatus";
+        AcOutputStatus => "acOutputStatus";
+        FluidLevel => "fluidLevel";
+        DcDetailedStatus => "dcDetailedStatus";
+        ChargerStatus => "chargerStatus";
+        BatteryStatus => "batteryStatus";
+        InverterStatus => "inverterStatus";
+        BatteryConfigurationStatus => "batteryConfigurationStatus";
+        ConverterStatus => "converterStatus";
+        DcVoltageCurrent => "dcVoltageCurrent";
+        LeewayAngle => "leewayAngle";
+        ThrusterControlStatus => "thrusterControlStatus";
+        ThrusterInformation => "thrusterInformation";
+        ThrusterMotorStatus => "thrusterMotorStatus";
+        WindlassControlStatus => "windlassControlStatus";
+        WindlassOperatingStatus => "anchorWindlassOperatingStatus";
+        WindlassMonitoringStatus => "anchorWindlassMonitoringStatus";
+        AisClassAPositionReport => "aisClassAPositionReport";
+        AisClassBPositionReport => "aisClassBPositionReport";
+        AisClassBExtendedPositionReport => "aisClassBExtendedPositionReport";
+        AisAtonReport => "aisAidsToNavigationAtonReport";
+        CrossTrackError => "crossTrackError";
+        NavigationData => "navigationData";
+        RouteInformation => "navigationRouteWpInformation";
+        SetAndDrift => "setDriftRapidUpdate";
+        TimeToMark => "navigationRouteTimeToFromMark";
+        BearingDistanceBetweenMarks => "bearingAndDistanceBetweenTwoMarks";
+        AisUtcDateReport => "aisUtcAndDateReport";
+        AisClassAStaticData => "aisClassAStaticAndVoyageRelatedData";
+        AisSarAircraftPositionReport => "aisSarAircraftPositionReport";
+        RadioFrequencyModePower => "radioFrequencyModePower";
+        AisAddressedSafetyMessage => "aisAddressedSafetyRelatedMessage";
+        AisSafetyBroadcastMessage => "aisSafetyRelatedBroadcastMessage";
+        DscCallInformation => "dscCallInformation";
+        AisClassBStaticDataPartA => "aisClassBStaticDataMsg24PartA";
+        AisClassBStaticDataPartB => "aisClassBStaticDataMsg24PartB";
+        WaypointList => "routeAndWpServiceWpListWpNamePosition";
+        OutsideEnvironmentalParameters => "environmentalParametersObsolete";
+        EnvironmentalParameters => "environmentalParameters";
+        Temperature => "temperature";
+        Humidity => "humidity";
+        ActualPressure => "actualPressure";
+        SetPressure => "setPressure";
+        TemperatureExtendedRange => "temperatureExtendedRange";
+        TideStationData => "tideStationData";
+        SalinityStationData => "salinityStationData";
+        CurrentStationData => "currentStationData";
+        MeteorologicalStationData => "meteorologicalStationData";
+        EntertainmentCurrentFileStatus => "currentStatusAndFile";
+        EntertainmentLibraryDataFile => "libraryDataFile";
+        EntertainmentLibraryDataGroup => "libraryDataGroup";
+        EntertainmentLibraryDataSearch => "libraryDataSearch";
+        EntertainmentSupportedSourceData => "supportedSourceData";
+        SmallCraftStatus => "smallCraftStatus";
+        DirectionData => "directionData";
+        VesselSpeedComponents => "vesselSpeedComponents";
+        Alert => "alert";
+        AlertResponse => "alertResponse";
+        AlertText => "alertText";
+        AlertConfiguration => "alertConfiguration";
+        AlertThreshold => "alertThreshold";
+        AlertValue => "alertValue";
+        BusPhaseCBasicAcQuantities => "bus1PhaseCBasicAcQuantities";
+        BusPhaseBBasicAcQuantities => "bus1PhaseBBasicAcQuantities";
+        BusPhaseABasicAcQuantities => "bus1PhaseABasicAcQuantities";
+        BusAverageBasicAcQuantities => "bus1AverageBasicAcQuantities";
+        UtilityTotalAcEnergy => "utilityTotalAcEnergy";
+        UtilityPhaseCAcReactivePower => "utilityPhaseCAcReactivePower";
+        UtilityPhaseCAcPower => "utilityPhaseCAcPower";
+        UtilityPhaseCBasicAcQuantities => "utilityPhaseCBasicAcQuantities";
+        UtilityPhaseBAcReactivePower => "utilityPhaseBAcReactivePower";
+        UtilityPhaseBAcPower => "utilityPhaseBAcPower";
+        UtilityPhaseBBasicAcQuantities => "utilityPhaseBBasicAcQuantities";
+        UtilityPhaseAAcReactivePower => "utilityPhaseAAcReactivePower";
+        UtilityPhaseAAcPower => "utilityPhaseAAcPower";
+        UtilityPhaseABasicAcQuantities => "utilityPhaseABasicAcQuantities";
+        UtilityTotalAcReactivePower => "utilityTotalAcReactivePower";
+        UtilityTotalAcPower => "utilityTotalAcPower";
+        UtilityAverageBasicAcQuantities => "utilityAverageBasicAcQuantities";
+        GeneratorTotalAcEnergy => "generatorTotalAcEnergy";
+        GeneratorPhaseCAcReactivePower => "generatorPhaseCAcReactivePower";
+        GeneratorPhaseCAcPower => "generatorPhaseCAcPower";
+        GeneratorPhaseCBasicAcQuantities => "generatorPhaseCBasicAcQuantities";
+        GeneratorPhaseBAcReactivePower => "generatorPhaseBAcReactivePower";
+        GeneratorPhaseBAcPower => "generatorPhaseBAcPower";
+        GeneratorPhaseBBasicAcQuantities => "generatorPhaseBBasicAcQuantities";
+        GeneratorPhaseAAcReactivePower => "generatorPhaseAAcReactivePower";
+        GeneratorPhaseAAcPower => "generatorPhaseAAcPower";
+        GeneratorPhaseABasicAcQuantities => "generatorPhaseABasicAcQuantities";
+        GeneratorTotalAcReactivePower => "generatorTotalAcReactivePower";
+        GeneratorTotalAcPower => "generatorTotalAcPower";
+        GeneratorAverageBasicAcQuantities => "generatorAverageBasicAcQuantities";
+        SimnetDeviceStatus => "simnetDeviceStatus";
+        SeatalkPilotHeading => "seatalkPilotHeading";
+        SeatalkPilotLockedHeading => "seatalkPilotLockedHeading";
+        SeatalkPilotMode => "seatalkPilotMode";
+        GarminColorMode => "garminDayMode", "garminNightMode", "garminColorMode";
+        // Left out: canboat has no layout for the parameters of the 126208
+        // Seatalk pilot commands, and SeatalkKeystroke checks that the key
+        // is repeated inverted and writes a fixed trailer, so an arbitrary
+        // canboat payload does not round trip.
+        Proprietary130820 => "fusionSource", "fusionTrackName", "fusionArtistName",
+            "fusionAlbumName", "fusionTrackPosition", "fusionMute", "fusionVolumes",
+            "fusionPowerState";
+        SimnetKeyValue => "simnetKeyValue";
+        SimnetApCommand => "simnetApCommand";
+    }
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}